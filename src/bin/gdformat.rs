@@ -1,15 +1,16 @@
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use clap::Parser;
 use ignore::WalkBuilder;
 use miette::{miette, IntoDiagnostic, Result};
 
-use gdtools::config::load_config;
+use gdtools::config::{load_config, load_config_from, FormatConfig, IndentKind};
 use gdtools::format::{
-    compare_ast_with_source, reorder_source, run_formatter, AstCheckResult, FormatOptions,
-    IndentStyle,
+    check_order_with_options, compare_ast_with_source, reorder_source_with_options, run_formatter,
+    run_formatter_range, sort_enum_members, unified_diff, AstCheckResult, FormatError,
+    FormatOptions,
 };
 use gdtools::parser;
 
@@ -36,13 +37,26 @@ struct Cli {
     #[arg(long)]
     stdin: bool,
 
+    /// Path of the file passed on stdin, used for messages and to find the nearest config
+    #[arg(long, requires = "stdin")]
+    stdin_filename: Option<PathBuf>,
+
+    /// With --stdin, only reformat the statements overlapping these lines (`start:end`, 1-indexed)
+    #[arg(
+        long,
+        requires = "stdin",
+        conflicts_with_all = ["check", "diff"],
+        value_parser = parse_line_range
+    )]
+    range: Option<(usize, usize)>,
+
     /// Write formatted output to stdout instead of modifying files
     #[arg(long)]
     stdout: bool,
 
-    /// Maximum line length
-    #[arg(short = 'l', long, default_value = "100")]
-    line_length: usize,
+    /// Maximum line length (default: 100, or `line_length` from config)
+    #[arg(short = 'l', long)]
+    line_length: Option<usize>,
 
     /// Use spaces instead of tabs (specify number of spaces)
     #[arg(short = 's', long)]
@@ -80,27 +94,49 @@ fn main() -> ExitCode {
 fn run() -> Result<bool> {
     let cli = Cli::parse();
 
-    // Build format options from CLI or config
-    let options = build_options(&cli)?;
+    // Load config for format options and exclude patterns. For stdin with a
+    // filename, discover the config next to that file instead of the CWD.
+    let config = match cli.stdin_filename.as_deref().and_then(Path::parent) {
+        Some(parent) => {
+            let start = std::env::current_dir().into_diagnostic()?.join(parent);
+            load_config_from(cli.config.as_deref(), &start)
+        }
+        None => load_config(cli.config.as_deref()),
+    }
+    .map_err(|e| miette!(e))?;
+
+    // Merge CLI flags into the [format] settings; they take precedence over
+    // the config file, which takes precedence over .editorconfig
+    let format_config = merge_cli_options(&cli, &config.format);
 
     let check = cli.check;
     let run_safety_checks = !cli.unsafe_skip_checks;
 
     // Handle stdin mode
     if cli.stdin {
-        return format_stdin(&options, check, cli.diff, run_safety_checks);
+        let filename = cli
+            .stdin_filename
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "<stdin>".to_string());
+        let mut options = match &cli.stdin_filename {
+            Some(path) => FormatOptions::from_config_for_file(&format_config, path),
+            None => FormatOptions::from_config(&format_config),
+        };
+        if let Some((start, end)) = cli.range {
+            return format_stdin_range(&filename, &options, start, end, run_safety_checks);
+        }
+        options.verify_ast = run_safety_checks;
+        return format_stdin(&filename, &options, check, cli.diff, run_safety_checks);
     }
 
-    // Load config for exclude patterns
-    let config = load_config(cli.config.as_deref()).map_err(|e| miette!(e))?;
-
     let mut any_changes = false;
 
     for path in &cli.paths {
         if path.is_file() {
             if process_file(
                 path,
-                &options,
+                &format_config,
                 check,
                 cli.diff,
                 cli.stdout,
@@ -112,7 +148,7 @@ fn run() -> Result<bool> {
         } else if path.is_dir()
             && process_directory(
                 path,
-                &options,
+                &format_config,
                 check,
                 cli.diff,
                 cli.stdout,
@@ -127,22 +163,67 @@ fn run() -> Result<bool> {
     Ok(any_changes)
 }
 
-fn build_options(cli: &Cli) -> Result<FormatOptions> {
-    let indent_style = if let Some(spaces) = cli.use_spaces {
-        IndentStyle::Spaces(spaces)
-    } else {
-        IndentStyle::Tabs
-    };
+fn merge_cli_options(cli: &Cli, format_config: &FormatConfig) -> FormatConfig {
+    let mut format_config = format_config.clone();
+
+    if let Some(spaces) = cli.use_spaces {
+        format_config.indent_style = Some(IndentKind::Spaces);
+        format_config.indent_size = Some(spaces);
+    }
+    if let Some(line_length) = cli.line_length {
+        format_config.line_length = Some(line_length);
+    }
+    if cli.reorder {
+        format_config.reorder = Some(true);
+    }
+
+    format_config
+}
 
-    Ok(FormatOptions {
-        indent_style,
-        max_line_length: cli.line_length,
-        trailing_newline: true,
-        reorder: cli.reorder,
-    })
+/// Parse a `--range` value such as `10:20`.
+fn parse_line_range(value: &str) -> std::result::Result<(usize, usize), String> {
+    let (start, end) = value
+        .split_once(':')
+        .ok_or("expected a line range like 10:20")?;
+    let start: usize = start
+        .parse()
+        .map_err(|_| format!("invalid start line '{}'", start))?;
+    let end: usize = end
+        .parse()
+        .map_err(|_| format!("invalid end line '{}'", end))?;
+    if start == 0 || end < start {
+        return Err("line ranges start at 1 and must not end before they start".to_string());
+    }
+    Ok((start, end))
+}
+
+/// Format part of stdin and print the whole file. Reordering doesn't apply to
+/// ranges, and the idempotence check is skipped since the rest of the file is
+/// left unformatted.
+fn format_stdin_range(
+    filename: &str,
+    options: &FormatOptions,
+    start: usize,
+    end: usize,
+    run_safety_checks: bool,
+) -> Result<bool> {
+    let mut source = String::new();
+    io::stdin().read_to_string(&mut source).into_diagnostic()?;
+
+    let formatted =
+        run_formatter_range(&source, options, start, end).map_err(|e| miette!("{}", e))?;
+    if run_safety_checks && formatted != source {
+        verify_ast_equivalence(filename, &source, &formatted)?;
+    }
+
+    io::stdout()
+        .write_all(formatted.as_bytes())
+        .into_diagnostic()?;
+    Ok(false)
 }
 
 fn format_stdin(
+    filename: &str,
     options: &FormatOptions,
     check: bool,
     diff: bool,
@@ -151,22 +232,22 @@ fn format_stdin(
     let mut source = String::new();
     io::stdin().read_to_string(&mut source).into_diagnostic()?;
 
-    // Step 1: Format
-    let formatted = run_formatter(&source, options).map_err(|e| miette!("{}", e))?;
+    // Step 1: Format (with safety checks, `options.verify_ast` rejects output whose AST changed)
+    let formatted = run_formatter(&source, options).map_err(|e| miette!("{}: {}", filename, e))?;
 
-    // Step 2: Run safety checks on formatted output
-    if run_safety_checks {
-        verify_ast_equivalence("<stdin>", &source, &formatted)?;
-        verify_idempotent("<stdin>", &formatted, options)?;
+    // Step 2: Run safety checks on formatted output. Unchanged output is trivially
+    // idempotent, so already-formatted input skips the extra parse.
+    if run_safety_checks && formatted != source {
+        verify_idempotent(filename, &formatted, options)?;
     }
 
     // Step 3: Apply reordering if enabled
     let final_output = if options.reorder {
-        let reordered = reorder_source(&formatted).map_err(|e| miette!("{}", e))?;
+        let reordered =
+            reorder_source_with_options(&formatted, options).map_err(|e| miette!("{}", e))?;
         // Step 4: Check reordering invariants
         if run_safety_checks {
-            verify_reorder_line_invariant("<stdin>", &formatted, &reordered)?;
-            verify_reorder_idempotent("<stdin>", &reordered)?;
+            verify_reorder(filename, &formatted, &reordered, options)?;
         }
         reordered
     } else {
@@ -178,7 +259,7 @@ fn format_stdin(
     }
 
     if diff {
-        print_diff("<stdin>", &source, &final_output);
+        print_diff(filename, &source, &final_output);
         return Ok(source != final_output);
     }
 
@@ -191,7 +272,7 @@ fn format_stdin(
 
 fn process_file(
     path: &PathBuf,
-    options: &FormatOptions,
+    format_config: &FormatConfig,
     check: bool,
     diff: bool,
     stdout: bool,
@@ -208,22 +289,26 @@ fn process_file(
 
     let source = std::fs::read_to_string(path).into_diagnostic()?;
     let filename = path.display().to_string();
+    let options = &FormatOptions {
+        verify_ast: run_safety_checks,
+        ..FormatOptions::from_config_for_file(format_config, path)
+    };
 
-    // Step 1: Format the source
+    // Step 1: Format the source. A changed AST leaves the file untouched.
     let formatted = match run_formatter(&source, options) {
         Ok(f) => f,
+        Err(e @ FormatError::AstChanged { .. }) => {
+            eprintln!("Warning: skipping {} - {}", filename, e);
+            return Ok(false);
+        }
         Err(e) => {
             eprintln!("Error formatting {:?}: {}", path, e);
             return Ok(false);
         }
     };
 
-    // Step 2: Run safety checks on formatted output
-    if run_safety_checks {
-        if let Err(e) = verify_ast_equivalence(&filename, &source, &formatted) {
-            eprintln!("Warning: skipping {} - {}", filename, e);
-            return Ok(false);
-        }
+    // Step 2: Run safety checks on formatted output (skipped when nothing changed, as above)
+    if run_safety_checks && formatted != source {
         if let Err(e) = verify_idempotent(&filename, &formatted, options) {
             eprintln!("Warning: skipping {} - {}", filename, e);
             return Ok(false);
@@ -232,16 +317,11 @@ fn process_file(
 
     // Step 3: Apply reordering if enabled
     let final_output = if options.reorder {
-        match reorder_source(&formatted) {
+        match reorder_source_with_options(&formatted, options) {
             Ok(reordered) => {
                 // Step 4: Check reordering invariants
                 if run_safety_checks {
-                    if let Err(e) = verify_reorder_line_invariant(&filename, &formatted, &reordered)
-                    {
-                        eprintln!("Warning: skipping {} - {}", filename, e);
-                        return Ok(false);
-                    }
-                    if let Err(e) = verify_reorder_idempotent(&filename, &reordered) {
+                    if let Err(e) = verify_reorder(&filename, &formatted, &reordered, options) {
                         eprintln!("Warning: skipping {} - {}", filename, e);
                         return Ok(false);
                     }
@@ -263,6 +343,9 @@ fn process_file(
         if changed {
             println!("Would reformat: {}", path.display());
         }
+        if options.reorder {
+            print_order_violations(&filename, &source, options);
+        }
         return Ok(changed);
     }
 
@@ -291,7 +374,7 @@ fn process_file(
 
 fn process_directory(
     path: &PathBuf,
-    options: &FormatOptions,
+    format_config: &FormatConfig,
     check: bool,
     diff: bool,
     stdout: bool,
@@ -309,7 +392,7 @@ fn process_directory(
         if file_path.extension().map(|e| e == "gd").unwrap_or(false)
             && process_file(
                 &file_path.to_path_buf(),
-                options,
+                format_config,
                 check,
                 diff,
                 stdout,
@@ -325,29 +408,7 @@ fn process_directory(
 }
 
 fn print_diff(filename: &str, original: &str, formatted: &str) {
-    use similar::{ChangeTag, TextDiff};
-
-    println!("--- {}", filename);
-    println!("+++ {}", filename);
-
-    let diff = TextDiff::from_lines(original, formatted);
-
-    for (idx, group) in diff.grouped_ops(3).iter().enumerate() {
-        if idx > 0 {
-            println!("...");
-        }
-
-        for op in group {
-            for change in diff.iter_changes(op) {
-                let sign = match change.tag() {
-                    ChangeTag::Delete => "-",
-                    ChangeTag::Insert => "+",
-                    ChangeTag::Equal => " ",
-                };
-                print!("{}{}", sign, change);
-            }
-        }
-    }
+    print!("{}", unified_diff(filename, original, formatted));
 }
 
 fn verify_ast_equivalence(filename: &str, original: &str, formatted: &str) -> Result<()> {
@@ -378,8 +439,50 @@ fn verify_idempotent(filename: &str, formatted: &str, options: &FormatOptions) -
     }
 }
 
-fn verify_reorder_idempotent(filename: &str, reordered: &str) -> Result<()> {
-    let reordered_twice = reorder_source(reordered).map_err(|e| miette!("{}", e))?;
+/// List the members `--reorder` would move, with their current lines.
+fn print_order_violations(filename: &str, source: &str, options: &FormatOptions) {
+    let Ok(violations) = check_order_with_options(source, options) else {
+        return;
+    };
+    for violation in violations {
+        match violation.expected_after {
+            Some(previous) => println!(
+                "{}:{}: {} should come after {}",
+                filename, violation.line, violation.member, previous
+            ),
+            None => println!(
+                "{}:{}: {} should come first",
+                filename, violation.line, violation.member
+            ),
+        }
+    }
+}
+
+/// Check that reordering only moved lines and that reordering again changes nothing.
+fn verify_reorder(
+    filename: &str,
+    formatted: &str,
+    reordered: &str,
+    options: &FormatOptions,
+) -> Result<()> {
+    // Sorting enum members rewrites single-line enums, so compare lines against
+    // the enum-sorted text rather than the formatter output
+    let baseline = if options.sort_enum_members {
+        sort_enum_members(formatted).map_err(|e| miette!("{}", e))?
+    } else {
+        formatted.to_string()
+    };
+    verify_reorder_line_invariant(filename, &baseline, reordered)?;
+    verify_reorder_idempotent(filename, reordered, options)
+}
+
+fn verify_reorder_idempotent(
+    filename: &str,
+    reordered: &str,
+    options: &FormatOptions,
+) -> Result<()> {
+    let reordered_twice =
+        reorder_source_with_options(reordered, options).map_err(|e| miette!("{}", e))?;
 
     if reordered == reordered_twice {
         Ok(())
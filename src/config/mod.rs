@@ -1,25 +1,128 @@
+mod presets;
 mod types;
 
-pub use types::{Config, RuleConfig, RulesConfig};
+pub use presets::{ruleset_source, RULESET_NAMES};
+pub use types::{Config, FormatConfig, IndentKind, RegexRuleConfig, RuleConfig, RulesConfig};
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+/// Load the config from `path`, or discover it from the current directory.
 pub fn load_config(path: Option<&Path>) -> Result<Config, String> {
-    if let Some(p) = path {
-        let content =
-            std::fs::read_to_string(p).map_err(|e| format!("Failed to read config file: {}", e))?;
-        toml::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))
-    } else if let Some(found) = find_config_file() {
-        let content = std::fs::read_to_string(&found)
-            .map_err(|e| format!("Failed to read config file: {}", e))?;
-        toml::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))
-    } else {
-        Ok(Config::default())
+    load_config_with_ruleset(path, None)
+}
+
+/// Like `load_config`, with `ruleset` (from the command line) taking the place of
+/// the config's own `ruleset` key.
+pub fn load_config_with_ruleset(
+    path: Option<&Path>,
+    ruleset: Option<&str>,
+) -> Result<Config, String> {
+    let cwd =
+        std::env::current_dir().map_err(|e| format!("Failed to read current directory: {}", e))?;
+    resolve_config(path, &cwd, ruleset)
+}
+
+/// Load the config from `path`, or discover the nearest `gdtools.toml` walking up from `start`.
+pub fn load_config_from(path: Option<&Path>, start: &Path) -> Result<Config, String> {
+    resolve_config(path, start, None)
+}
+
+/// Read the config file (resolving its `extends` chain) and merge it over the
+/// selected ruleset preset, if any.
+fn resolve_config(
+    path: Option<&Path>,
+    start: &Path,
+    ruleset: Option<&str>,
+) -> Result<Config, String> {
+    let mut table = match path
+        .map(Path::to_path_buf)
+        .or_else(|| find_config_file(start))
+    {
+        Some(file) => read_config_table(&file, &mut Vec::new())?,
+        None => toml::Table::new(),
+    };
+
+    let configured = table.remove("ruleset");
+    let ruleset = match (ruleset, &configured) {
+        (Some(name), _) => Some(name),
+        (None, Some(value)) => Some(
+            value
+                .as_str()
+                .ok_or("Failed to parse config: 'ruleset' must be a string")?,
+        ),
+        (None, None) => None,
+    };
+    if let Some(name) = ruleset {
+        let mut preset = presets::ruleset_table(name)?;
+        merge_config_tables(&mut preset, table);
+        table = preset;
+    }
+
+    toml::Value::Table(table)
+        .try_into()
+        .map_err(|e| format!("Failed to parse config: {}", e))
+}
+
+/// Read a config file as TOML, with the file named by its `extends` key (resolved
+/// against this file's directory) merged underneath. `chain` holds the files
+/// currently being read, to detect cycles.
+fn read_config_table(path: &Path, chain: &mut Vec<PathBuf>) -> Result<toml::Table, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read config file: {}", e))?;
+    let mut table: toml::Table =
+        toml::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))?;
+
+    let Some(extends) = table.remove("extends") else {
+        return Ok(table);
+    };
+    let extends = extends
+        .as_str()
+        .ok_or("Failed to parse config: 'extends' must be a path")?;
+
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to read config file: {}", e))?;
+    if chain.contains(&canonical) {
+        return Err(format!("Config extends cycle at {}", path.display()));
+    }
+    chain.push(canonical);
+
+    let parent_path = path.parent().unwrap_or(Path::new(".")).join(extends);
+    let mut base = read_config_table(&parent_path, chain)?;
+    chain.pop();
+
+    // Exclude patterns accumulate down the chain instead of being replaced
+    if let Some(exclude) = table.remove("exclude") {
+        match (base.get_mut("exclude"), exclude) {
+            (Some(toml::Value::Array(base_exclude)), toml::Value::Array(exclude)) => {
+                base_exclude.extend(exclude);
+            }
+            (_, exclude) => {
+                base.insert("exclude".to_string(), exclude);
+            }
+        }
+    }
+    merge_config_tables(&mut base, table);
+    Ok(base)
+}
+
+/// Merge `child` over `base`: tables merge key by key and any other child value
+/// replaces the base value.
+fn merge_config_tables(base: &mut toml::Table, child: toml::Table) {
+    for (key, value) in child {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(child_table)) => {
+                merge_config_tables(base_table, child_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
     }
 }
 
-fn find_config_file() -> Option<std::path::PathBuf> {
-    let mut current = std::env::current_dir().ok()?;
+fn find_config_file(start: &Path) -> Option<std::path::PathBuf> {
+    let mut current = start.to_path_buf();
 
     loop {
         let config_path = current.join("gdtools.toml");
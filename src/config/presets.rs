@@ -0,0 +1,79 @@
+//! Built-in rule presets, selected with `ruleset = "<name>"` in the config or
+//! `gdlint --ruleset <name>`. A preset is a config merged underneath the user's.
+
+/// Names of the built-in presets.
+pub const RULESET_NAMES: &[&str] = &["minimal", "recommended", "strict", "godot-official"];
+
+const MINIMAL: &str = r#"# Only the basic checks, which catch likely bugs.
+[categories]
+naming = false
+format = false
+style = false
+design = false
+"#;
+
+const RECOMMENDED: &str = r#"# The built-in defaults: every rule that is on by default, at its default severity.
+"#;
+
+const STRICT: &str = r#"# Every rule, including the opt-in ones, with typing and formatting checks as warnings.
+[rules.no-todo-without-owner]
+enabled = true
+
+[rules.string-should-be-stringname]
+enabled = true
+
+[rules.no-print-without-context]
+enabled = true
+
+[rules.no-camelcase-anywhere]
+enabled = true
+
+[rules.no-untyped-signal-parameters]
+enabled = true
+
+[rules.return-type-annotation-required]
+severity = "warning"
+
+[rules.typed-parameters-required]
+severity = "warning"
+
+[rules.trailing-comma-required-in-multiline]
+severity = "warning"
+
+[rules.operator-spacing]
+severity = "warning"
+"#;
+
+const GODOT_OFFICIAL: &str = r#"# The official GDScript style guide: naming, formatting and member order.
+[categories]
+design = false
+
+[rules.max-line-length]
+max = 100
+
+[rules.max-file-lines]
+enabled = false
+"#;
+
+/// The TOML source of a built-in preset, or `None` for an unknown name.
+pub fn ruleset_source(name: &str) -> Option<&'static str> {
+    match name {
+        "minimal" => Some(MINIMAL),
+        "recommended" => Some(RECOMMENDED),
+        "strict" => Some(STRICT),
+        "godot-official" => Some(GODOT_OFFICIAL),
+        _ => None,
+    }
+}
+
+/// Parse a built-in preset as a config table.
+pub(crate) fn ruleset_table(name: &str) -> Result<toml::Table, String> {
+    let source = ruleset_source(name).ok_or_else(|| {
+        format!(
+            "Unknown ruleset '{}' (expected one of: {})",
+            name,
+            RULESET_NAMES.join(", ")
+        )
+    })?;
+    toml::from_str(source).map_err(|e| format!("Failed to parse ruleset '{}': {}", name, e))
+}
@@ -1,14 +1,46 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
 
+use ignore::overrides::{Override, OverrideBuilder};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
-use crate::lint::Severity;
+use crate::format::{FormatOptions, MAX_BLANK_LINES};
+use crate::lint::{RuleCategory, RuleMetadata, Severity, CLASS_NAME_COLLISION_RULE_ID};
+
+/// Categories accepted in `[categories]`.
+const CATEGORIES: [RuleCategory; 6] = [
+    RuleCategory::Naming,
+    RuleCategory::Format,
+    RuleCategory::Basic,
+    RuleCategory::Design,
+    RuleCategory::Style,
+    RuleCategory::Custom,
+];
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct Config {
     pub exclude: Vec<String>,
+    /// When non-empty, only files matching one of these globs are linted. Globs use
+    /// gitignore syntax relative to the directory being walked.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Base URL for rule documentation, used to build per-rule help links.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs_base_url: Option<String>,
+    /// Merge diagnostics at the same position with near-identical messages, as
+    /// overlapping rules can report. Off by default.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dedupe_diagnostics: bool,
     pub rules: RulesConfig,
+    /// Whole-category toggles such as `naming = false`, keyed by category name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub categories: BTreeMap<String, bool>,
+    pub format: FormatConfig,
+    /// Project-specific rules defined by a regex, keyed by rule id.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub regex_rules: BTreeMap<String, RegexRuleConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -23,26 +55,124 @@ pub struct RulesConfig {
 #[serde(default)]
 pub struct RuleConfig {
     pub severity: Option<Severity>,
-    #[serde(default = "default_true")]
-    pub enabled: bool,
+    /// Explicit on/off switch; a section without it enables the rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
     #[serde(flatten)]
     pub options: HashMap<String, toml::Value>,
 }
 
-fn default_true() -> bool {
-    true
+/// Formatter settings from the `[format]` section. Unset fields keep the
+/// formatter defaults; command-line flags take precedence over both.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(default)]
+pub struct FormatConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_style: Option<IndentKind>,
+    /// Spaces per indent level when `indent_style = "spaces"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_size: Option<usize>,
+    /// Columns a tab counts for when measuring line length (default: 4).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tab_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reorder: Option<bool>,
+    /// With `reorder`, sort enum members alphabetically (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_enum_members: Option<bool>,
+    /// With `reorder`, sort constants alphabetically by name (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_constants: Option<bool>,
+    /// Spaces inside single-line dictionary braces (default: true).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_inside_braces: Option<bool>,
+    /// Spaces inside single-line array brackets (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_inside_brackets: Option<bool>,
+    /// Align the `=` of consecutive single-line assignments (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_consecutive_assignments: Option<bool>,
+    /// Wrap standalone comments longer than `line_length` (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reflow_comments: Option<bool>,
+    /// With `reflow_comments`, also wrap `##` doc comments (default: false).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reflow_doc_comments: Option<bool>,
+    /// Project-specific lifecycle methods that `reorder` and the
+    /// `class-definitions-order` rule group with engine virtual methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_virtual_methods: Option<Vec<String>>,
+    /// Blank lines around top-level functions and classes (default: 2). Capped at
+    /// `max_consecutive_blank_lines`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_lines_top_level_functions: Option<usize>,
+    /// Blank lines around functions and classes nested in a class (default: 1).
+    /// Capped at `max_consecutive_blank_lines`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blank_lines_class_members: Option<usize>,
+    /// Most blank lines kept in a row (default: 2). Clamped to `1..=4`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_blank_lines: Option<usize>,
+    /// Take indentation from `.editorconfig` when `indent_style` is unset (default: true).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editorconfig: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentKind {
+    Tabs,
+    Spaces,
+}
+
+/// A custom rule that reports wherever `pattern` matches a source line, or the
+/// text of a `node_kind` node when one is given.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RegexRuleConfig {
+    pub pattern: String,
+    pub message: String,
+    #[serde(default)]
+    pub severity: Severity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_kind: Option<String>,
 }
 
 impl Config {
     pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        self.is_rule_enabled_or(rule_id, true)
+    }
+
+    /// Like `is_rule_enabled`, for rules that are off unless given a `[rules.<id>]` section.
+    pub fn is_rule_enabled_or(&self, rule_id: &str, default: bool) -> bool {
         if self.rules.disable.contains(&rule_id.to_string()) {
             return false;
         }
         self.rules
             .options
             .get(rule_id)
-            .map(|c| c.enabled)
-            .unwrap_or(true)
+            .map(|c| c.enabled.unwrap_or(true))
+            .unwrap_or(default)
+    }
+
+    /// Like `is_rule_enabled_or`, also honouring the `[categories]` toggles.
+    ///
+    /// Precedence: `rules.disable` and an explicit `enabled` in `[rules.<id>]`, then
+    /// the rule's category toggle, then the presence of a `[rules.<id>]` section,
+    /// then `default`.
+    pub fn is_rule_enabled_in(&self, rule_id: &str, category: RuleCategory, default: bool) -> bool {
+        if self.rules.disable.contains(&rule_id.to_string()) {
+            return false;
+        }
+        let rule_config = self.rules.options.get(rule_id);
+        if let Some(enabled) = rule_config.and_then(|c| c.enabled) {
+            return enabled;
+        }
+        if let Some(&enabled) = self.categories.get(&category.to_string()) {
+            return enabled;
+        }
+        rule_config.is_some() || default
     }
 
     pub fn get_rule_severity(&self, rule_id: &str, default: Severity) -> Severity {
@@ -56,4 +186,123 @@ impl Config {
     pub fn get_rule_config(&self, rule_id: &str) -> Option<&RuleConfig> {
         self.rules.options.get(rule_id)
     }
+
+    /// Build a matcher for the `include` globs, anchored at the directory being walked.
+    ///
+    /// Globs follow gitignore syntax: one containing a `/` matches from `root`, and
+    /// `**/` matches zero or more directories, so `src/**/*.gd` covers `src/player.gd`
+    /// but not `addons/x/src/a.gd`. Invalid globs are skipped (`validate` reports them).
+    /// Files the matcher marks as ignored are left out; an empty matcher ignores nothing.
+    pub fn include_matcher(&self, root: &Path) -> Override {
+        let mut builder = OverrideBuilder::new(root);
+        for glob in &self.include {
+            let _ = builder.add(glob);
+        }
+        builder.build().unwrap_or_else(|_| Override::empty())
+    }
+
+    /// Whether a file found while walking `root` passes the `include` globs.
+    /// Everything is included when no globs are set.
+    pub fn is_included(&self, path: &Path, root: &Path) -> bool {
+        !self.include_matcher(root).matched(path, false).is_ignore()
+    }
+
+    /// Check the config against the rules it may refer to, collecting every problem:
+    /// unknown rule ids and categories, regex rule patterns that don't compile or
+    /// shadow a built-in rule, invalid `include` globs, and `[format]` blank-line
+    /// counts outside the range the formatter applies. Severities are already
+    /// checked when the config is parsed; rule options are checked by each rule's
+    /// `configure`.
+    pub fn validate(&self, known_rules: &[RuleMetadata]) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        let is_builtin = |id: &str| known_rules.iter().any(|rule| rule.id == id);
+        let is_known = |id: &str| {
+            is_builtin(id)
+                || self.regex_rules.contains_key(id)
+                || id == CLASS_NAME_COLLISION_RULE_ID
+        };
+
+        let mut configured: Vec<&String> = self.rules.options.keys().collect();
+        configured.sort();
+        for id in configured.into_iter().filter(|id| !is_known(id.as_str())) {
+            problems.push(format!("Unknown rule '{}' in [rules.{}]", id, id));
+        }
+        for id in self
+            .rules
+            .disable
+            .iter()
+            .filter(|id| !is_known(id.as_str()))
+        {
+            problems.push(format!("Unknown rule '{}' in rules.disable", id));
+        }
+
+        for name in self.categories.keys() {
+            if !CATEGORIES
+                .iter()
+                .any(|category| category.to_string() == *name)
+            {
+                problems.push(format!("Unknown category '{}' in [categories]", name));
+            }
+        }
+
+        for (id, rule) in &self.regex_rules {
+            if is_builtin(id) {
+                problems.push(format!(
+                    "Regex rule '{}' conflicts with a built-in rule",
+                    id
+                ));
+            }
+            if let Err(e) = Regex::new(&rule.pattern) {
+                problems.push(format!("Invalid pattern for regex rule '{}': {}", id, e));
+            }
+        }
+
+        let mut include = OverrideBuilder::new(".");
+        for glob in &self.include {
+            if let Err(e) = include.add(glob) {
+                problems.push(format!("Invalid include glob '{}': {}", glob, e));
+            }
+        }
+
+        // The formatter clamps blank-line counts; report values it would change
+        if let Some(max) = self.format.max_consecutive_blank_lines {
+            if !(1..=MAX_BLANK_LINES).contains(&max) {
+                problems.push(format!(
+                    "format.max_consecutive_blank_lines must be between 1 and {} (got {})",
+                    MAX_BLANK_LINES, max
+                ));
+            }
+        }
+        let max_blank_lines = FormatOptions::from_config(&self.format).max_blank_lines();
+        for (key, value) in [
+            (
+                "blank_lines_top_level_functions",
+                self.format.blank_lines_top_level_functions,
+            ),
+            (
+                "blank_lines_class_members",
+                self.format.blank_lines_class_members,
+            ),
+        ] {
+            if let Some(value) = value.filter(|&value| value > max_blank_lines) {
+                problems.push(format!(
+                    "format.{} is {} but at most {} blank lines are kept in a row",
+                    key, value, max_blank_lines
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Documentation link for a rule (`<docs_base_url>/rules/<id>`), if a base URL is set.
+    pub fn help_uri(&self, rule_id: &str) -> Option<String> {
+        self.docs_base_url
+            .as_deref()
+            .map(|base| format!("{}/rules/{}", base.trim_end_matches('/'), rule_id))
+    }
 }
@@ -44,6 +44,16 @@ fn is_value_node(kind: &str) -> bool {
     )
 }
 
+/// Named children of a node, without backslash line continuations.
+///
+/// The formatter is free to add or remove continuations when wrapping lines.
+fn significant_children(node: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|c| c.kind() != "line_continuation")
+        .collect()
+}
+
 /// Compare two ASTs with access to their source strings.
 pub fn compare_ast_with_source(
     original_tree: &Tree,
@@ -93,25 +103,21 @@ fn compare_nodes_with_source(
         }
     }
 
-    // Compare named child count
-    if orig.named_child_count() != fmt.named_child_count() {
+    // Compare named children, skipping layout-only nodes
+    let orig_children = significant_children(orig);
+    let fmt_children = significant_children(fmt);
+
+    if orig_children.len() != fmt_children.len() {
         return AstCheckResult::Different {
             path,
             difference: format!(
                 "named child count differs: {} vs {}",
-                orig.named_child_count(),
-                fmt.named_child_count()
+                orig_children.len(),
+                fmt_children.len()
             ),
         };
     }
 
-    // Compare named children recursively
-    let mut orig_cursor = orig.walk();
-    let mut fmt_cursor = fmt.walk();
-
-    let orig_children: Vec<_> = orig.named_children(&mut orig_cursor).collect();
-    let fmt_children: Vec<_> = fmt.named_children(&mut fmt_cursor).collect();
-
     for (i, (orig_child, fmt_child)) in orig_children.iter().zip(fmt_children.iter()).enumerate() {
         let child_path = if path.is_empty() {
             format!("{}[{}]", orig_child.kind(), i)
@@ -220,6 +226,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_line_continuation_ignored() {
+        let source1 = "func f():\n\ta.b(1).c(2)\n";
+        let source2 = "func f():\n\ta.b(1) \\\n\t\t.c(2)\n";
+        let tree1 = parse(source1);
+        let tree2 = parse(source2);
+        assert_eq!(
+            compare_ast_with_source(&tree1, source1, &tree2, source2),
+            AstCheckResult::Equivalent
+        );
+    }
+
     #[test]
     fn test_function_with_different_spacing() {
         let source1 = "func foo(a:int,b:String)->void:\n\tpass\n";
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use super::context::visual_width;
+use super::options::FormatOptions;
+
 /// Extracted comments from source code.
 ///
 /// Comments are not part of the tree-sitter AST, so we extract them
@@ -52,6 +55,58 @@ impl Comments {
     }
 }
 
+/// Wrap a standalone comment line that is longer than `max_line_length` into
+/// several lines with the same indentation and `# ` prefix.
+///
+/// `##` doc comments are only wrapped with `reflow_doc_comments`. Comments that
+/// look like code (containing `=`, `(` or a backtick) are left alone so
+/// commented-out code is never rewrapped. Returns `None` when the line is kept as is.
+pub(crate) fn reflow_comment(line: &str, options: &FormatOptions) -> Option<Vec<String>> {
+    let max = options.max_line_length;
+    if visual_width(line, options.tab_width) <= max {
+        return None;
+    }
+
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let (marker, text) = if let Some(text) = trimmed.strip_prefix("## ") {
+        if !options.reflow_doc_comments {
+            return None;
+        }
+        ("##", text)
+    } else if let Some(text) = trimmed.strip_prefix("# ") {
+        ("#", text)
+    } else {
+        return None;
+    };
+    if text.contains(['=', '(', '`']) {
+        return None;
+    }
+
+    let prefix = format!("{}{} ", indent, marker);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            format!("{}{}", prefix, word)
+        } else {
+            format!("{} {}", current, word)
+        };
+        if !current.is_empty() && visual_width(&candidate, options.tab_width) > max {
+            lines.push(std::mem::take(&mut current));
+            current = format!("{}{}", prefix, word);
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    // A single word too long for the line can't be wrapped
+    (lines.len() > 1).then_some(lines)
+}
+
 /// Find the start of a comment in a line, handling strings.
 fn find_comment_start(line: &str) -> Option<usize> {
     let mut in_string = false;
@@ -116,6 +171,26 @@ mod tests {
         assert!(comments.get_inline(1).is_none());
     }
 
+    #[test]
+    fn test_reflow_comment() {
+        let options = FormatOptions {
+            max_line_length: 20,
+            reflow_comments: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            reflow_comment("\t# one two three four five", &options),
+            Some(vec![
+                "\t# one two three".to_string(),
+                "\t# four five".to_string()
+            ])
+        );
+        assert_eq!(reflow_comment("# short", &options), None);
+        assert_eq!(reflow_comment("# var speed = compute(10)", &options), None);
+        assert_eq!(reflow_comment("## one two three four five", &options), None);
+        assert_eq!(reflow_comment("# averyveryverylongword", &options), None);
+    }
+
     #[test]
     fn test_indented_standalone_comment() {
         let source = "func foo():\n\t# indented comment\n\tpass";
@@ -37,7 +37,7 @@ impl<'a> FormatContext<'a> {
             options,
             indent_level: 0,
             skip_regions,
-            output: FormattedOutput::new(),
+            output: FormattedOutput::with_max_blank_lines(options.max_blank_lines()),
         }
     }
 
@@ -61,6 +61,12 @@ impl<'a> FormatContext<'a> {
         self.skip_regions.is_skipped(line)
     }
 
+    /// Check if any line of a node is in a skip region.
+    pub fn is_node_skipped(&self, node: Node<'_>) -> bool {
+        self.skip_regions
+            .overlaps(node.start_position().row + 1, node.end_position().row + 1)
+    }
+
     /// Get a line from the original source (1-indexed).
     pub fn get_source_line(&self, line: usize) -> Option<&'a str> {
         if line == 0 || line > self.lines.len() {
@@ -70,12 +76,9 @@ impl<'a> FormatContext<'a> {
         }
     }
 
-    /// Calculate the visual width of a string (tabs count as indent width).
+    /// Calculate the visual width of a string, expanding tabs to `tab_width` stops.
     pub fn visual_width(&self, s: &str) -> usize {
-        let tab_width = self.options.indent_style.width();
-        s.chars()
-            .map(|c| if c == '\t' { tab_width } else { 1 })
-            .sum()
+        visual_width(s, self.options.tab_width)
     }
 
     /// Get the source text for a node.
@@ -83,3 +86,18 @@ impl<'a> FormatContext<'a> {
         &self.source[node.start_byte()..node.end_byte()]
     }
 }
+
+/// Visual width of a line, with each tab advancing to the next multiple of `tab_width`.
+///
+/// Shared by the formatter's wrapping decisions and the `max-line-length` rule
+/// so both measure lines the way an editor displays them.
+pub fn visual_width(line: &str, tab_width: usize) -> usize {
+    let tab_width = tab_width.max(1);
+    line.chars().fold(0, |width, c| {
+        if c == '\t' {
+            width + tab_width - width % tab_width
+        } else {
+            width + 1
+        }
+    })
+}
@@ -0,0 +1,15 @@
+use similar::TextDiff;
+
+/// Unified diff (`---`/`+++` headers, `@@` hunks with 3 lines of context) from
+/// `original` to `formatted`, labelled with `path`. Empty when nothing changed.
+pub fn unified_diff(path: &str, original: &str, formatted: &str) -> String {
+    if original == formatted {
+        return String::new();
+    }
+
+    TextDiff::from_lines(original, formatted)
+        .unified_diff()
+        .context_radius(3)
+        .header(path, path)
+        .to_string()
+}
@@ -0,0 +1,263 @@
+//! Minimal `.editorconfig` support: just enough to pick up `indent_style`
+//! and `indent_size` for a `.gd` file.
+
+use std::path::Path;
+
+use regex::Regex;
+
+use super::options::IndentStyle;
+
+/// Indentation declared by `.editorconfig` files for `path`, if any.
+///
+/// Files are read from the file's directory upward until one declares
+/// `root = true`; closer files and later sections take precedence.
+pub fn indent_style_for(path: &Path) -> Option<IndentStyle> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(path)
+    };
+
+    // Collect files nearest-first, then apply them farthest-first
+    let mut files = Vec::new();
+    for dir in path.ancestors().skip(1) {
+        let Ok(content) = std::fs::read_to_string(dir.join(".editorconfig")) else {
+            continue;
+        };
+        let file = EditorConfigFile::parse(&content);
+        let is_root = file.root;
+        files.push((dir.to_path_buf(), file));
+        if is_root {
+            break;
+        }
+    }
+
+    let mut props = IndentProps::default();
+    for (dir, file) in files.iter().rev() {
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        for section in &file.sections {
+            if section.matches(&relative) {
+                props.merge(&section.props);
+            }
+        }
+    }
+
+    props.resolve()
+}
+
+#[derive(Debug, Default, Clone)]
+struct IndentProps {
+    indent_style: Option<String>,
+    indent_size: Option<String>,
+    tab_width: Option<String>,
+}
+
+impl IndentProps {
+    fn merge(&mut self, other: &IndentProps) {
+        if other.indent_style.is_some() {
+            self.indent_style = other.indent_style.clone();
+        }
+        if other.indent_size.is_some() {
+            self.indent_size = other.indent_size.clone();
+        }
+        if other.tab_width.is_some() {
+            self.tab_width = other.tab_width.clone();
+        }
+    }
+
+    fn resolve(&self) -> Option<IndentStyle> {
+        match self.indent_style.as_deref()? {
+            "tab" => Some(IndentStyle::Tabs),
+            "space" => {
+                // `indent_size = tab` defers to `tab_width`
+                let size = self
+                    .indent_size
+                    .as_deref()
+                    .filter(|s| *s != "tab")
+                    .or(self.tab_width.as_deref())
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(4);
+                Some(IndentStyle::Spaces(size))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Section {
+    pattern: Option<Regex>,
+    props: IndentProps,
+}
+
+impl Section {
+    fn matches(&self, relative_path: &str) -> bool {
+        self.pattern
+            .as_ref()
+            .is_some_and(|re| re.is_match(relative_path))
+    }
+}
+
+#[derive(Debug, Default)]
+struct EditorConfigFile {
+    root: bool,
+    sections: Vec<Section>,
+}
+
+impl EditorConfigFile {
+    fn parse(content: &str) -> Self {
+        let mut file = EditorConfigFile::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(glob) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                file.sections.push(Section {
+                    pattern: glob_to_regex(glob),
+                    props: IndentProps::default(),
+                });
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+
+            match file.sections.last_mut() {
+                // Preamble: only `root` is meaningful
+                None => {
+                    if key == "root" {
+                        file.root = value == "true";
+                    }
+                }
+                Some(section) => match key.as_str() {
+                    "indent_style" => section.props.indent_style = Some(value),
+                    "indent_size" => section.props.indent_size = Some(value),
+                    "tab_width" => section.props.tab_width = Some(value),
+                    _ => {}
+                },
+            }
+        }
+
+        file
+    }
+}
+
+/// Translate an editorconfig section glob into an anchored regex over a
+/// `/`-separated path relative to the `.editorconfig` directory.
+pub(crate) fn glob_to_regex(glob: &str) -> Option<Regex> {
+    // Globs without a slash match the file name at any depth
+    let (prefix, glob) = match glob.strip_prefix('/') {
+        Some(rest) => ("", rest),
+        None if glob.contains('/') => ("", glob),
+        None => ("(?:.*/)?", glob),
+    };
+
+    let mut pattern = format!("^{}", prefix);
+    let mut chars = glob.chars().peekable();
+    let mut brace_depth = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                pattern.push_str(".*");
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            '[' => {
+                pattern.push('[');
+                if chars.peek() == Some(&'!') {
+                    chars.next();
+                    pattern.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' || c == '[' {
+                        pattern.push('\\');
+                    }
+                    pattern.push(c);
+                }
+                pattern.push(']');
+            }
+            '{' => {
+                brace_depth += 1;
+                pattern.push_str("(?:");
+            }
+            '}' if brace_depth > 0 => {
+                brace_depth -= 1;
+                pattern.push(')');
+            }
+            ',' if brace_depth > 0 => pattern.push('|'),
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    pattern.push_str(&regex::escape(&escaped.to_string()));
+                }
+            }
+            _ => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+
+    Regex::new(&pattern).ok()
+}
+
+/// Match a glob against a `/`-separated path, or any of its trailing
+/// components, so `ui/**` applies whether the path is relative or absolute.
+pub(crate) fn glob_matches_path(glob: &Regex, path: &str) -> bool {
+    let path = path.trim_start_matches("./");
+    glob.is_match(path)
+        || path
+            .match_indices('/')
+            .any(|(i, _)| glob.is_match(&path[i + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_to_regex() {
+        let re = glob_to_regex("*.gd").unwrap();
+        assert!(re.is_match("player.gd"));
+        assert!(re.is_match("scripts/player.gd"));
+        assert!(!re.is_match("player.gdshader"));
+
+        let re = glob_to_regex("*.{gd,tres}").unwrap();
+        assert!(re.is_match("a.tres"));
+
+        let re = glob_to_regex("/scripts/*.gd").unwrap();
+        assert!(re.is_match("scripts/a.gd"));
+        assert!(!re.is_match("scripts/sub/a.gd"));
+        assert!(!re.is_match("other/scripts/a.gd"));
+
+        let re = glob_to_regex("addons/**.gd").unwrap();
+        assert!(re.is_match("addons/x/y.gd"));
+    }
+
+    #[test]
+    fn test_later_sections_override() {
+        let file = EditorConfigFile::parse(
+            "root = true\n\n[*]\nindent_style = tab\n\n[*.gd]\nindent_style = space\nindent_size = 2\n",
+        );
+        assert!(file.root);
+
+        let mut props = IndentProps::default();
+        for section in &file.sections {
+            if section.matches("a.gd") {
+                props.merge(&section.props);
+            }
+        }
+        assert_eq!(props.resolve(), Some(IndentStyle::Spaces(2)));
+    }
+}
@@ -0,0 +1,420 @@
+//! Classification of class members by their place in the style guide order.
+//!
+//! Both `gdformat --reorder` and the `class-definitions-order` lint rule sort
+//! members by [`MemberKind`], so they always agree on the expected order.
+
+use tree_sitter::Node;
+
+/// Classification of class members, shared by the reorderer and the order lint.
+/// The order of variants determines sort priority (lower = earlier in file).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MemberKind {
+    // 01. File-level annotations
+    Tool,
+    Icon,
+    StaticUnload,
+
+    // 02. class_name
+    ClassName,
+
+    // 03. extends
+    Extends,
+
+    // 04. signals
+    Signal,
+
+    // 05. enums
+    Enum,
+
+    // 06. constants
+    Const,
+
+    // 07. static variables
+    StaticVar,
+
+    // 08. @export variables
+    ExportVar,
+
+    // 09. regular variables
+    Var,
+
+    // 10. @onready variables
+    OnreadyVar,
+
+    // 11. _static_init()
+    StaticInit,
+
+    // 12. remaining static methods
+    StaticMethod,
+
+    // 14. Virtual methods in specific order
+    VirtualInit,
+    VirtualEnterTree,
+    VirtualReady,
+    VirtualProcess,
+    VirtualPhysicsProcess,
+    VirtualOther,
+
+    // 15. Overridden custom methods (private methods not in virtual list)
+    OverriddenCustomMethod,
+
+    // 16. Regular methods
+    Method,
+
+    // 17. Inner classes
+    InnerClass,
+}
+
+impl MemberKind {
+    /// Human-readable name of this kind, for messages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MemberKind::Tool => "@tool",
+            MemberKind::Icon => "@icon",
+            MemberKind::StaticUnload => "@static_unload",
+            MemberKind::ClassName => "class_name",
+            MemberKind::Extends => "extends",
+            MemberKind::Signal => "signal",
+            MemberKind::Enum => "enum",
+            MemberKind::Const => "const",
+            MemberKind::StaticVar => "static var",
+            MemberKind::ExportVar => "@export var",
+            MemberKind::Var => "var",
+            MemberKind::OnreadyVar => "@onready var",
+            MemberKind::StaticInit => "_static_init",
+            MemberKind::StaticMethod => "static method",
+            MemberKind::VirtualInit => "_init",
+            MemberKind::VirtualEnterTree => "_enter_tree",
+            MemberKind::VirtualReady => "_ready",
+            MemberKind::VirtualProcess => "_process",
+            MemberKind::VirtualPhysicsProcess => "_physics_process",
+            MemberKind::VirtualOther => "virtual method",
+            MemberKind::OverriddenCustomMethod => "overridden method",
+            MemberKind::Method => "method",
+            MemberKind::InnerClass => "inner class",
+        }
+    }
+
+    /// Check if this is an engine virtual callback such as `_ready` or `_input`.
+    pub fn is_virtual(&self) -> bool {
+        matches!(
+            self,
+            MemberKind::VirtualInit
+                | MemberKind::VirtualEnterTree
+                | MemberKind::VirtualReady
+                | MemberKind::VirtualProcess
+                | MemberKind::VirtualPhysicsProcess
+                | MemberKind::VirtualOther
+        )
+    }
+
+    /// Kind of a standalone file-level annotation (`@tool`, `@icon`, `@static_unload`).
+    pub fn for_standalone_annotation(name: &str) -> Option<Self> {
+        match name {
+            "tool" => Some(MemberKind::Tool),
+            "icon" => Some(MemberKind::Icon),
+            "static_unload" => Some(MemberKind::StaticUnload),
+            _ => None,
+        }
+    }
+
+    /// Check if this is a header kind (no blank lines between these).
+    pub(crate) fn is_header(&self) -> bool {
+        matches!(
+            self,
+            MemberKind::Tool
+                | MemberKind::Icon
+                | MemberKind::StaticUnload
+                | MemberKind::ClassName
+                | MemberKind::Extends
+        )
+    }
+
+    /// Check if this is a function-like kind (2 blank lines around these).
+    pub(crate) fn is_function_like(&self) -> bool {
+        matches!(
+            self,
+            MemberKind::StaticInit
+                | MemberKind::StaticMethod
+                | MemberKind::VirtualInit
+                | MemberKind::VirtualEnterTree
+                | MemberKind::VirtualReady
+                | MemberKind::VirtualProcess
+                | MemberKind::VirtualPhysicsProcess
+                | MemberKind::VirtualOther
+                | MemberKind::OverriddenCustomMethod
+                | MemberKind::Method
+                | MemberKind::InnerClass
+        )
+    }
+}
+
+/// Classify a class-body node, or `None` if it is not a member declaration.
+///
+/// Standalone annotations and comments are handled by the caller, since they
+/// attach to neighbouring declarations. Methods named in `extra_virtuals` are
+/// grouped with the engine virtual methods.
+pub(crate) fn classify(
+    node: Node<'_>,
+    source: &str,
+    extra_virtuals: &[String],
+) -> Option<MemberKind> {
+    match node.kind() {
+        "class_name_statement" => Some(MemberKind::ClassName),
+        "extends_statement" => Some(MemberKind::Extends),
+        "signal_statement" => Some(MemberKind::Signal),
+        "enum_definition" => Some(MemberKind::Enum),
+        "const_statement" => Some(MemberKind::Const),
+        "variable_statement" => {
+            // Get annotations and modifiers from inside the node (tree-sitter puts them as children)
+            let node_modifiers = get_node_modifiers(node, source);
+
+            // Check modifiers (priority: onready > export > static > regular)
+            if node_modifiers.iter().any(|m| m == "onready") {
+                Some(MemberKind::OnreadyVar)
+            } else if node_modifiers.iter().any(|m| is_export_annotation(m)) {
+                Some(MemberKind::ExportVar)
+            } else if node_modifiers.iter().any(|m| m == "static") {
+                Some(MemberKind::StaticVar)
+            } else {
+                Some(MemberKind::Var)
+            }
+        }
+        "function_definition" => {
+            let name = node
+                .child_by_field_name("name")
+                .and_then(|n| node_text(n, source))
+                .unwrap_or("");
+
+            if is_static_function(node) {
+                if name == "_static_init" {
+                    Some(MemberKind::StaticInit)
+                } else {
+                    Some(MemberKind::StaticMethod)
+                }
+            } else {
+                Some(classify_virtual_method(name, extra_virtuals))
+            }
+        }
+        // _init() is parsed as constructor_definition, not function_definition
+        "constructor_definition" => Some(MemberKind::VirtualInit),
+        "class_definition" => Some(MemberKind::InnerClass),
+        // Comments (including ## doc comments) are not standalone declarations.
+        // They are included with the following declaration they document.
+        _ => None,
+    }
+}
+
+/// Extract the annotation name from an annotation node.
+pub(crate) fn get_annotation_name<'a>(node: Node<'a>, source: &'a str) -> Option<&'a str> {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "identifier" {
+            return node_text(child, source);
+        }
+    }
+    None
+}
+
+/// Get the text of a node.
+pub(crate) fn node_text<'a>(node: Node<'a>, source: &'a str) -> Option<&'a str> {
+    source.get(node.start_byte()..node.end_byte())
+}
+
+/// Check if a function is static by looking for static keyword.
+fn is_static_function(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "static_keyword" {
+            return true;
+        }
+    }
+    false
+}
+
+/// Classify a function as a virtual method kind. Names in `extra_virtuals` are
+/// project-specific lifecycle methods, ordered like `_input` and `_draw`.
+pub(crate) fn classify_virtual_method(name: &str, extra_virtuals: &[String]) -> MemberKind {
+    match name {
+        "_init" => MemberKind::VirtualInit,
+        "_enter_tree" => MemberKind::VirtualEnterTree,
+        "_ready" => MemberKind::VirtualReady,
+        "_process" => MemberKind::VirtualProcess,
+        "_physics_process" => MemberKind::VirtualPhysicsProcess,
+        "_exit_tree"
+        | "_input"
+        | "_unhandled_input"
+        | "_notification"
+        | "_draw"
+        | "_gui_input"
+        | "_unhandled_key_input"
+        | "_shortcut_input"
+        | "_get_configuration_warnings"
+        | "_get_configuration_warning" => MemberKind::VirtualOther,
+        name if extra_virtuals.iter().any(|v| v == name) => MemberKind::VirtualOther,
+        name if name.starts_with('_') => MemberKind::OverriddenCustomMethod,
+        _ => MemberKind::Method,
+    }
+}
+
+/// Check if an annotation is an export variant.
+fn is_export_annotation(name: &str) -> bool {
+    name == "export" || name.starts_with("export_")
+}
+
+/// Check if an annotation is standalone (not attached to a declaration).
+pub(crate) fn is_standalone_annotation(name: &str) -> bool {
+    matches!(name, "tool" | "icon" | "static_unload")
+}
+
+/// Check if an annotation is a section marker (export_category, export_group, export_subgroup).
+pub(crate) fn is_section_annotation(name: &str) -> bool {
+    matches!(name, "export_category" | "export_group" | "export_subgroup")
+}
+
+/// Whether a variable carries an `@export...` annotation inside its node.
+pub(crate) fn has_export_modifier(node: Node<'_>, source: &str) -> bool {
+    get_node_modifiers(node, source)
+        .iter()
+        .any(|m| is_export_annotation(m))
+}
+
+/// Whether a variable carries a section marker (`@export_group` etc.) inside its node.
+pub(crate) fn has_section_modifier(node: Node<'_>, source: &str) -> bool {
+    get_node_modifiers(node, source)
+        .iter()
+        .any(|m| is_section_annotation(m))
+}
+
+/// Get annotations and modifiers from inside a node.
+/// Returns annotations (like @export, @onready) and modifiers (like static).
+fn get_node_modifiers(node: Node<'_>, source: &str) -> Vec<String> {
+    let mut modifiers = Vec::new();
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        if child.kind() == "annotations" {
+            // Found annotations wrapper - extract individual annotations
+            let mut ann_cursor = child.walk();
+            for ann in child.children(&mut ann_cursor) {
+                if ann.kind() == "annotation" {
+                    if let Some(name) = get_annotation_name(ann, source) {
+                        modifiers.push(name.to_string());
+                    }
+                }
+            }
+        } else if child.kind() == "annotation" {
+            // Direct annotation child
+            if let Some(name) = get_annotation_name(child, source) {
+                modifiers.push(name.to_string());
+            }
+        } else if child.kind() == "static_keyword" {
+            // Static keyword as direct child
+            modifiers.push("static".to_string());
+        }
+    }
+
+    modifiers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_member_kind_ordering() {
+        assert!(MemberKind::Tool < MemberKind::ClassName);
+        assert!(MemberKind::ClassName < MemberKind::Extends);
+        assert!(MemberKind::Extends < MemberKind::Signal);
+        assert!(MemberKind::Signal < MemberKind::Enum);
+        assert!(MemberKind::Enum < MemberKind::Const);
+        assert!(MemberKind::Const < MemberKind::StaticVar);
+        assert!(MemberKind::StaticVar < MemberKind::ExportVar);
+        assert!(MemberKind::ExportVar < MemberKind::Var);
+        assert!(MemberKind::Var < MemberKind::OnreadyVar);
+        assert!(MemberKind::OnreadyVar < MemberKind::StaticInit);
+        assert!(MemberKind::StaticInit < MemberKind::StaticMethod);
+        assert!(MemberKind::StaticMethod < MemberKind::VirtualInit);
+        assert!(MemberKind::VirtualInit < MemberKind::VirtualEnterTree);
+        assert!(MemberKind::VirtualEnterTree < MemberKind::VirtualReady);
+        assert!(MemberKind::VirtualReady < MemberKind::VirtualProcess);
+        assert!(MemberKind::VirtualProcess < MemberKind::VirtualPhysicsProcess);
+        assert!(MemberKind::VirtualPhysicsProcess < MemberKind::VirtualOther);
+        assert!(MemberKind::VirtualOther < MemberKind::OverriddenCustomMethod);
+        assert!(MemberKind::OverriddenCustomMethod < MemberKind::Method);
+        assert!(MemberKind::Method < MemberKind::InnerClass);
+    }
+
+    #[test]
+    fn test_is_export_annotation() {
+        assert!(is_export_annotation("export"));
+        assert!(is_export_annotation("export_range"));
+        assert!(is_export_annotation("export_enum"));
+        assert!(!is_export_annotation("onready"));
+        assert!(!is_export_annotation("tool"));
+    }
+
+    #[test]
+    fn test_classify_virtual_method() {
+        assert_eq!(
+            classify_virtual_method("_init", &[]),
+            MemberKind::VirtualInit
+        );
+        assert_eq!(
+            classify_virtual_method("_ready", &[]),
+            MemberKind::VirtualReady
+        );
+        assert_eq!(
+            classify_virtual_method("_enter_tree", &[]),
+            MemberKind::VirtualEnterTree
+        );
+        assert_eq!(
+            classify_virtual_method("_process", &[]),
+            MemberKind::VirtualProcess
+        );
+        assert_eq!(
+            classify_virtual_method("_physics_process", &[]),
+            MemberKind::VirtualPhysicsProcess
+        );
+        assert_eq!(
+            classify_virtual_method("_exit_tree", &[]),
+            MemberKind::VirtualOther
+        );
+        assert_eq!(
+            classify_virtual_method("_custom", &[]),
+            MemberKind::OverriddenCustomMethod
+        );
+        assert_eq!(classify_virtual_method("foo", &[]), MemberKind::Method);
+
+        let extra = vec!["_on_state_entered".to_string(), "tick".to_string()];
+        assert_eq!(
+            classify_virtual_method("_on_state_entered", &extra),
+            MemberKind::VirtualOther
+        );
+        assert_eq!(
+            classify_virtual_method("tick", &extra),
+            MemberKind::VirtualOther
+        );
+        assert_eq!(
+            classify_virtual_method("_ready", &extra),
+            MemberKind::VirtualReady
+        );
+    }
+
+    #[test]
+    fn test_is_standalone_annotation() {
+        assert!(is_standalone_annotation("tool"));
+        assert!(is_standalone_annotation("icon"));
+        assert!(is_standalone_annotation("static_unload"));
+        assert!(!is_standalone_annotation("export"));
+        assert!(!is_standalone_annotation("onready"));
+    }
+
+    #[test]
+    fn test_is_virtual() {
+        assert!(classify_virtual_method("_ready", &[]).is_virtual());
+        assert!(classify_virtual_method("_input", &[]).is_virtual());
+        assert!(!classify_virtual_method("_custom", &[]).is_virtual());
+        assert!(!classify_virtual_method("foo", &[]).is_virtual());
+    }
+}
@@ -1,25 +1,79 @@
 pub mod ast_check;
 mod comments;
 mod context;
+mod diff;
+mod editorconfig;
+pub mod member_kind;
 mod nodes;
 mod options;
 mod output;
+mod range;
 pub mod reorder;
 mod skip_regions;
 
 pub use ast_check::{compare_ast_with_source, AstCheckResult};
-pub use context::FormatContext;
-pub use options::{FormatOptions, IndentStyle};
+pub use context::{visual_width, FormatContext};
+pub use diff::unified_diff;
+pub(crate) use editorconfig::{glob_matches_path, glob_to_regex};
+pub(crate) use nodes::{has_trailing_comma, inline_body};
+pub(crate) use options::MAX_BLANK_LINES;
+pub use options::{FormatOptions, FormatOptionsBuilder, IndentStyle};
 pub use output::{FormattedLine, FormattedOutput};
-pub use reorder::reorder_source;
+pub use range::run_formatter_range;
+pub use reorder::{
+    check_order, check_order_with_options, reorder_source, reorder_source_with_options,
+    sort_enum_members, OrderViolation,
+};
+pub(crate) use skip_regions::SkipRegions;
 
 use crate::parser;
 use comments::Comments;
-use skip_regions::SkipRegions;
 
 /// Format GDScript source code according to the official style guide.
 /// Note: This does NOT reorder - call `reorder_source` separately if needed.
+///
+/// With `options.verify_ast`, the output is re-parsed and compared with the
+/// source; a mismatch returns `FormatError::AstChanged` instead of the output.
 pub fn run_formatter(source: &str, options: &FormatOptions) -> Result<String, FormatError> {
+    let output = format_output(source, options)?;
+    let formatted = output.to_string(options);
+    verify_output(source, &formatted, options)?;
+    Ok(formatted)
+}
+
+/// Like `run_formatter`, but also returns the source line (1-indexed) each output
+/// line came from, or `None` for lines the formatter inserted.
+pub fn run_formatter_mapped(
+    source: &str,
+    options: &FormatOptions,
+) -> Result<(String, Vec<Option<usize>>), FormatError> {
+    let output = format_output(source, options)?;
+    let formatted = output.to_string(options);
+    verify_output(source, &formatted, options)?;
+    Ok((formatted, output.line_mapping()))
+}
+
+/// With `options.verify_ast`, check that `formatted` parses to the same AST as `source`.
+/// Unchanged output is trivially equivalent and skips the extra parses.
+fn verify_output(
+    source: &str,
+    formatted: &str,
+    options: &FormatOptions,
+) -> Result<(), FormatError> {
+    if !options.verify_ast || formatted == source {
+        return Ok(());
+    }
+    let original_tree = parser::parse(source).map_err(FormatError::Parse)?;
+    let formatted_tree = parser::parse(formatted).map_err(FormatError::Parse)?;
+    match compare_ast_with_source(&original_tree, source, &formatted_tree, formatted) {
+        AstCheckResult::Equivalent => Ok(()),
+        AstCheckResult::Different { path, difference } => {
+            Err(FormatError::AstChanged { path, difference })
+        }
+    }
+}
+
+fn format_output(source: &str, options: &FormatOptions) -> Result<FormattedOutput, FormatError> {
     // Parse the source
     let tree = parser::parse(source).map_err(FormatError::Parse)?;
 
@@ -38,20 +92,33 @@ pub fn run_formatter(source: &str, options: &FormatOptions) -> Result<String, Fo
 
     // Inject comments back
     ctx.output.inject_comments(&comments, source);
+    if options.reflow_comments {
+        ctx.output
+            .reflow_comments(&comments, &ctx.skip_regions, options);
+    }
 
-    // Build final output
-    Ok(ctx.output.to_string(options))
+    Ok(ctx.output)
 }
 
 #[derive(Debug)]
 pub enum FormatError {
     Parse(String),
+    /// The formatted output no longer parses to the same AST (see `FormatOptions::verify_ast`).
+    AstChanged {
+        path: String,
+        difference: String,
+    },
 }
 
 impl std::fmt::Display for FormatError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             FormatError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            FormatError::AstChanged { path, difference } => write!(
+                f,
+                "AST changed after formatting\nPath: {}\nDifference: {}",
+                path, difference
+            ),
         }
     }
 }
@@ -4,11 +4,20 @@ use super::expressions::format_expression;
 use super::format_block;
 use crate::format::context::FormatContext;
 
+/// The body of an `if`, `elif`, `else`, `for` or `while` when it starts on the
+/// header's line, as in `if ready: start()`.
+pub(crate) fn inline_body(node: Node<'_>) -> Option<Node<'_>> {
+    let body = node
+        .child_by_field_name("consequence")
+        .or_else(|| node.child_by_field_name("body"))?;
+    (body.start_position().row == node.start_position().row).then_some(body)
+}
+
 /// Format if statement.
 pub fn format_if_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     // Check if this is a single-line if statement (body on same line as condition)
     // If so, output verbatim to preserve the structure
-    if node.start_position().row == node.end_position().row {
+    if node.start_position().row == node.end_position().row && inline_body(node).is_some() {
         let start = node.start_position();
         let line_num = start.row + 1;
         if let Some(line_content) = ctx.get_source_line(line_num) {
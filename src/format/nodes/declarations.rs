@@ -1,6 +1,7 @@
 use tree_sitter::Node;
 
 use super::expressions::{format_expression, has_trailing_comma};
+use super::statements::format_extends_clause;
 use super::{format_block, format_node};
 use crate::format::context::FormatContext;
 
@@ -18,7 +19,7 @@ pub fn format_class_definition(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     // Get extends clause if present
     let extends = node
         .child_by_field_name("extends")
-        .map(|n| format!(" extends {}", ctx.node_text(n)))
+        .map(|n| format!(" {}", format_extends_clause(n, ctx)))
         .unwrap_or_default();
 
     ctx.output
@@ -43,7 +44,8 @@ fn format_class_body(node: Node<'_>, ctx: &mut FormatContext<'_>) {
         // Add blank line between methods
         if let Some(prev) = prev_kind {
             if needs_blank_line(prev, child.kind()) {
-                ctx.output.push_blank_lines(1);
+                ctx.output
+                    .push_blank_lines(ctx.options.class_member_blank_lines());
             }
         }
 
@@ -437,7 +439,7 @@ fn format_signal_parameters(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
 ///
 /// Trailing comma determines format:
 /// - With trailing comma → multiline (one member per line)
-/// - Without trailing comma → single line
+/// - Without trailing comma → single line, unless that overflows `max_line_length`
 pub fn format_enum_definition(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let line = node.start_position().row + 1;
     let indent = ctx.indent_str();
@@ -457,10 +459,15 @@ pub fn format_enum_definition(node: Node<'_>, ctx: &mut FormatContext<'_>) {
             ctx.output
                 .push_mapped(format!("{}enum{} {{}}", indent, name), line);
         } else {
-            // Check if enum body has trailing comma using AST inspection
-            let trailing_comma = has_trailing_comma(body_node);
+            let single_line = format!("{}enum{} {{ {} }}", indent, name, members.join(", "));
+
+            // A trailing comma keeps the enum multiline; otherwise expand only when
+            // the single-line form would overflow. The expanded form gains a trailing
+            // comma, so it stays expanded on the next run.
+            let multiline = has_trailing_comma(body_node)
+                || ctx.visual_width(&single_line) > ctx.options.max_line_length;
 
-            if trailing_comma {
+            if multiline {
                 // Multiline format
                 let single_indent = ctx.options.indent_style.as_str();
                 let inner_indent = format!("{}{}", indent, single_indent);
@@ -473,10 +480,7 @@ pub fn format_enum_definition(node: Node<'_>, ctx: &mut FormatContext<'_>) {
                 ctx.output.push_line(format!("{}}}", indent));
             } else {
                 // Single-line format
-                ctx.output.push_mapped(
-                    format!("{}enum{} {{ {} }}", indent, name, members.join(", ")),
-                    line,
-                );
+                ctx.output.push_mapped(single_line, line);
             }
         }
     } else {
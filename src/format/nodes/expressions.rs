@@ -4,6 +4,11 @@ use crate::format::context::FormatContext;
 
 /// Format an expression and return it as a string.
 pub fn format_expression(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
+    // Expressions touching a `# fmt: off` region are kept as written
+    if ctx.is_node_skipped(node) {
+        return ctx.node_text(node).to_string();
+    }
+
     match node.kind() {
         // Literals
         "integer" | "float" | "string" | "true" | "false" | "null" => {
@@ -281,6 +286,10 @@ fn collect_arguments(node: Node<'_>, ctx: &FormatContext<'_>) -> Vec<String> {
 
 /// Format attribute access: `obj.attr`
 fn format_attribute(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
+    if let Some(chain) = format_method_chain(node, ctx, line_prefix_width(node, ctx)) {
+        return chain;
+    }
+
     let object = node.child_by_field_name("object");
     let attribute = node.child_by_field_name("attribute");
 
@@ -294,6 +303,105 @@ fn format_attribute(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
     }
 }
 
+/// Format a method chain: `obj.a().b().c()`
+///
+/// Chains with at least two method calls are kept on one line when they fit.
+/// Otherwise each `.method(...)` segment goes on its own backslash continuation
+/// line, indented one level deeper than the statement. `prefix_width` is the width
+/// of what precedes the chain on its line, indentation included. Returns `None`
+/// for anything that isn't a chain so the caller can fall back.
+fn format_method_chain(
+    node: Node<'_>,
+    ctx: &FormatContext<'_>,
+    prefix_width: usize,
+) -> Option<String> {
+    let source = ctx.node_text(node);
+    // Comments inside the chain aren't in the AST - leave those verbatim
+    if source.contains('#') {
+        return None;
+    }
+
+    let mut cursor = node.walk();
+    let segments: Vec<_> = node
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() != "line_continuation")
+        .collect();
+
+    let call_count = segments
+        .iter()
+        .filter(|c| c.kind() == "attribute_call")
+        .count();
+    if call_count < 2 {
+        return None;
+    }
+
+    let (object, rest) = segments.split_first()?;
+    let head = format_expression(*object, ctx);
+    if head.contains('\n') {
+        return None;
+    }
+
+    let mut parts: Vec<(bool, String)> = Vec::with_capacity(rest.len());
+    for segment in rest {
+        let is_call = segment.kind() == "attribute_call";
+        let text = if is_call {
+            format_chain_call(*segment, ctx)?
+        } else {
+            ctx.node_text(*segment).to_string()
+        };
+        if text.contains('\n') {
+            return None;
+        }
+        parts.push((is_call, text));
+    }
+
+    let inline: String = std::iter::once(head.clone())
+        .chain(parts.iter().map(|(_, text)| format!(".{}", text)))
+        .collect();
+    if prefix_width + ctx.visual_width(&inline) <= ctx.options.max_line_length {
+        return Some(inline);
+    }
+
+    let indent = ctx.indent_str();
+    let continuation = format!("{}{}", indent, ctx.options.indent_style.as_str());
+    let mut result = head;
+    for (is_call, text) in parts {
+        if is_call {
+            result.push_str(&format!(" \\\n{}", continuation));
+        }
+        result.push('.');
+        result.push_str(&text);
+    }
+    Some(result)
+}
+
+/// Width of the text before `node` on its line once formatted: the indentation plus
+/// whatever the statement puts in front of it (`var x := `, `return `), taken from
+/// the source line the node starts on.
+fn line_prefix_width(node: Node<'_>, ctx: &FormatContext<'_>) -> usize {
+    let start = node.start_position();
+    let before = ctx
+        .get_source_line(start.row + 1)
+        .and_then(|line| line.get(..start.column))
+        .unwrap_or("");
+    ctx.visual_width(&format!("{}{}", ctx.indent_str(), before.trim_start()))
+}
+
+/// Format one `method(args)` segment of a method chain.
+fn format_chain_call(node: Node<'_>, ctx: &FormatContext<'_>) -> Option<String> {
+    let name = node.named_child(0)?;
+    let args = node.child_by_field_name("arguments")?;
+    // Trailing commas ask for multiline arguments, which a chain can't express
+    if has_trailing_comma(args) {
+        return None;
+    }
+    Some(format!(
+        "{}({})",
+        ctx.node_text(name),
+        collect_arguments(args, ctx).join(", ")
+    ))
+}
+
 /// Format subscript access: `arr[idx]`
 fn format_subscript(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
     let value = node.child_by_field_name("value");
@@ -359,7 +467,11 @@ fn format_array(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
             .iter()
             .map(|c| format_expression(*c, ctx))
             .collect();
-        format!("[{}]", elements.join(", "))
+        if ctx.options.space_inside_brackets {
+            format!("[ {} ]", elements.join(", "))
+        } else {
+            format!("[{}]", elements.join(", "))
+        }
     }
 }
 
@@ -390,7 +502,7 @@ pub fn has_trailing_comma(node: Node<'_>) -> bool {
 ///
 /// Trailing comma determines format:
 /// - With trailing comma → multiline (one entry per line)
-/// - Without trailing comma → single line with spaces inside braces
+/// - Without trailing comma → single line, spaces inside braces per `space_inside_braces`
 ///
 /// Dicts containing comments are preserved verbatim since comments aren't in the AST.
 fn format_dictionary(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
@@ -428,9 +540,13 @@ fn format_dictionary(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
             indent
         )
     } else {
-        // Single-line: add space after { and before } for readability
+        // Single-line: by default add space after { and before } for readability
         let pairs: Vec<String> = children.iter().map(|c| format_pair(*c, ctx)).collect();
-        format!("{{ {} }}", pairs.join(", "))
+        if ctx.options.space_inside_braces {
+            format!("{{ {} }}", pairs.join(", "))
+        } else {
+            format!("{{{}}}", pairs.join(", "))
+        }
     }
 }
 
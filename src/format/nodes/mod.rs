@@ -3,9 +3,13 @@ mod declarations;
 mod expressions;
 mod statements;
 
+pub(crate) use control_flow::inline_body;
+pub(crate) use expressions::has_trailing_comma;
+
 use tree_sitter::Node;
 
 use super::context::FormatContext;
+use super::options::FormatOptions;
 
 /// Format a node and its children.
 pub fn format_node(node: Node<'_>, ctx: &mut FormatContext<'_>) {
@@ -96,22 +100,40 @@ fn format_source_file(node: Node<'_>, ctx: &mut FormatContext<'_>) {
 
     let mut prev_end_line: Option<usize> = None;
     let mut prev_kind: Option<&str> = None;
+    let mut assignments = AssignmentRun::default();
 
     for child in children {
         // Calculate blank lines to add
         if let (Some(prev), Some(prev_end)) = (prev_kind, prev_end_line) {
             let child_start_line = child.start_position().row + 1;
             let source_blanks = count_source_blank_lines(ctx, prev_end, child_start_line);
-            let required_blanks = blank_lines_between(prev, child.kind(), true);
-            // Use the maximum of what was in source vs what's required
-            let blank_lines = source_blanks.max(required_blanks).min(2);
+            let required_blanks = blank_lines_between(prev, child.kind(), true, ctx.options);
+            // Functions and classes get exactly the configured spacing; elsewhere use
+            // the maximum of what was in source vs what's required
+            let is_function_boundary = (is_function_or_class(prev)
+                || is_function_or_class(child.kind()))
+                && prev != "comment"
+                && child.kind() != "comment";
+            let blank_lines = if is_function_boundary {
+                required_blanks
+            } else {
+                source_blanks.max(required_blanks)
+            }
+            .min(ctx.options.max_blank_lines());
             ctx.output.push_blank_lines(blank_lines);
         }
 
+        let first_line = ctx.output.len();
         format_node(child, ctx);
+        assignments.add(child, first_line, ctx);
         prev_kind = Some(child.kind());
         prev_end_line = Some(child.end_position().row + 1);
     }
+    assignments.flush(ctx);
+}
+
+fn is_function_or_class(kind: &str) -> bool {
+    matches!(kind, "function_definition" | "class_definition")
 }
 
 /// Determine the minimum blank lines required between two nodes at top level.
@@ -120,20 +142,27 @@ fn format_source_file(node: Node<'_>, ctx: &mut FormatContext<'_>) {
 /// - "Surround functions and class definitions with two blank lines"
 /// - One blank line between different declaration sections (signal, enum, const, var)
 /// - class_name/extends are grouped together, then one blank line before declarations
-fn blank_lines_between(prev: &str, next: &str, is_top_level: bool) -> usize {
+///
+/// The counts around functions and classes come from `FormatOptions`.
+fn blank_lines_between(
+    prev: &str,
+    next: &str,
+    is_top_level: bool,
+    options: &FormatOptions,
+) -> usize {
     // Comments are handled separately by comment injection - don't add blank lines around them
     // This is especially important for inline comments (on the same line as code)
     if prev == "comment" || next == "comment" {
         return 0;
     }
 
-    // Functions and classes get 2 blank lines at top level, 1 within classes
-    let is_function_or_class =
-        |kind: &str| matches!(kind, "function_definition" | "class_definition");
-
-    // If either is a function or class definition, use 2 blank lines at top level
+    // Functions and classes get 2 blank lines at top level, 1 within classes (by default)
     if is_function_or_class(prev) || is_function_or_class(next) {
-        return if is_top_level { 2 } else { 1 };
+        return if is_top_level {
+            options.top_level_function_blank_lines()
+        } else {
+            options.class_member_blank_lines()
+        };
     }
 
     // Not top level - no required blank lines between declarations
@@ -186,7 +215,7 @@ fn blank_lines_between(prev: &str, next: &str, is_top_level: bool) -> usize {
 }
 
 /// Output a node verbatim from source (for skipped regions or unhandled nodes).
-fn format_verbatim(node: Node<'_>, ctx: &mut FormatContext<'_>) {
+pub(super) fn format_verbatim(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let start = node.start_position();
     let end = node.end_position();
 
@@ -204,26 +233,89 @@ fn format_skipped_node(node: Node<'_>, ctx: &mut FormatContext<'_>) {
 }
 
 /// Format a block of statements (function body, if body, etc.).
+///
+/// Blank lines are only kept between statements: the style guide wants none right
+/// after the block's header or before its end.
 pub fn format_block(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let mut cursor = node.walk();
     let children: Vec<_> = node.children(&mut cursor).collect();
 
+    ctx.output.trim_trailing_blank_lines();
+
     let mut prev_end_line: Option<usize> = None;
     let mut prev_kind: Option<&str> = None;
+    let mut assignments = AssignmentRun::default();
 
     for child in children {
         // Add blank lines within blocks (max 1 blank line within blocks)
         if let (Some(prev), Some(prev_end)) = (prev_kind, prev_end_line) {
             let child_start_line = child.start_position().row + 1;
             let source_blanks = count_source_blank_lines(ctx, prev_end, child_start_line);
-            let required_blanks = blank_lines_between(prev, child.kind(), false);
+            let required_blanks = blank_lines_between(prev, child.kind(), false, ctx.options);
             // Within blocks, allow max 1 blank line
             let blank_lines = source_blanks.max(required_blanks).min(1);
             ctx.output.push_blank_lines(blank_lines);
         }
 
+        let first_line = ctx.output.len();
         format_node(child, ctx);
+        assignments.add(child, first_line, ctx);
         prev_kind = Some(child.kind());
         prev_end_line = Some(child.end_position().row + 1);
     }
+    assignments.flush(ctx);
+
+    ctx.output.trim_trailing_blank_lines();
+}
+
+/// A run of adjacent single-line assignments and `var`s in one block, aligned
+/// together when `align_consecutive_assignments` is set.
+#[derive(Default)]
+struct AssignmentRun {
+    /// Output line of each statement in the run.
+    lines: Vec<usize>,
+    /// Source row the last statement ends on.
+    end_row: Option<usize>,
+}
+
+impl AssignmentRun {
+    /// Record `node` once formatted, starting at output line `first_line`. Anything
+    /// that is not an assignment on the very next source line ends the run.
+    fn add(&mut self, node: Node<'_>, first_line: usize, ctx: &mut FormatContext<'_>) {
+        if !ctx.options.align_consecutive_assignments {
+            return;
+        }
+        let row = node.start_position().row;
+        let alignable = row == node.end_position().row
+            && ctx.output.len() == first_line + 1
+            && is_assignment(node);
+        let continues = alignable && self.end_row.is_some_and(|end| row == end + 1);
+        if !continues {
+            self.flush(ctx);
+        }
+        if alignable {
+            self.lines.push(first_line);
+            self.end_row = Some(row);
+        }
+    }
+
+    /// Align the run collected so far and start a new one.
+    fn flush(&mut self, ctx: &mut FormatContext<'_>) {
+        if self.lines.len() > 1 {
+            ctx.output.align_assignments(&self.lines);
+        }
+        self.lines.clear();
+        self.end_row = None;
+    }
+}
+
+/// A `var` with a value, or a plain `x = value` statement.
+fn is_assignment(node: Node<'_>) -> bool {
+    match node.kind() {
+        "variable_statement" => node.child_by_field_name("value").is_some(),
+        "expression_statement" => node
+            .named_child(0)
+            .is_some_and(|expr| expr.kind() == "assignment"),
+        _ => false,
+    }
 }
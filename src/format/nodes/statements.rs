@@ -3,35 +3,44 @@ use tree_sitter::Node;
 use super::expressions::format_expression;
 use crate::format::context::FormatContext;
 
-/// Format extends statement: `extends Node2D`
+/// Format extends statement: `extends Node2D`, `extends "res://base.gd"`,
+/// or `extends Outer.Inner`.
 pub fn format_extends_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let line = node.start_position().row + 1;
     let indent = ctx.indent_str();
+    let text = format_extends_clause(node, ctx);
+    ctx.output.push_mapped(format!("{}{}", indent, text), line);
+}
 
-    // Get the type being extended - try different field names
-    let type_node = node
-        .child_by_field_name("value")
-        .or_else(|| node.child_by_field_name("type"));
-
-    // If field names don't work, find the first non-keyword child
-    let type_node = type_node.or_else(|| {
-        let mut cursor = node.walk();
-        let children: Vec<_> = node.children(&mut cursor).collect();
-        children.into_iter().find(|c| c.kind() != "extends")
-    });
+/// Format an extends clause (without indentation) with exactly one space after
+/// `extends`. Also used for the `extends` field of inner class definitions.
+pub fn format_extends_clause(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
+    // The target is the first named child (a `type` or a `string` path)
+    let mut cursor = node.walk();
+    let target = node.named_children(&mut cursor).next();
 
-    if let Some(tn) = type_node {
-        let type_text = ctx.node_text(tn).trim();
-        ctx.output
-            .push_mapped(format!("{}extends {}", indent, type_text), line);
-    } else {
+    match target {
+        Some(target) => format!("extends {}", format_extends_target(target, ctx)),
         // Fallback: use source text
-        let text = ctx.node_text(node);
-        ctx.output
-            .push_mapped(format!("{}{}", indent, text.trim()), line);
+        None => ctx.node_text(node).trim().to_string(),
     }
 }
 
+/// Rebuild an extends target from its tokens.
+///
+/// Quoted paths are kept verbatim, and qualified names drop any whitespace
+/// around `.` so `Outer . Inner` becomes `Outer.Inner`.
+fn format_extends_target(node: Node<'_>, ctx: &FormatContext<'_>) -> String {
+    if node.kind() == "string" || node.child_count() == 0 {
+        return ctx.node_text(node).trim().to_string();
+    }
+
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .map(|child| format_extends_target(child, ctx))
+        .collect()
+}
+
 /// Format class_name statement: `class_name MyClass`
 pub fn format_class_name_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let line = node.start_position().row + 1;
@@ -80,6 +89,11 @@ pub fn format_continue_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
 
 /// Format return statement: `return` or `return value`
 pub fn format_return_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
+    if is_multiline_with_comments(node, ctx) {
+        super::format_verbatim(node, ctx);
+        return;
+    }
+
     let line = node.start_position().row + 1;
     let indent = ctx.indent_str();
 
@@ -101,6 +115,11 @@ pub fn format_return_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
 
 /// Format expression statement (standalone expression).
 pub fn format_expression_statement(node: Node<'_>, ctx: &mut FormatContext<'_>) {
+    if is_multiline_with_comments(node, ctx) {
+        super::format_verbatim(node, ctx);
+        return;
+    }
+
     let line = node.start_position().row + 1;
     let indent = ctx.indent_str();
 
@@ -111,6 +130,12 @@ pub fn format_expression_statement(node: Node<'_>, ctx: &mut FormatContext<'_>)
     }
 }
 
+/// Multiline statements with comments inside (e.g. a `# fmt: off` region in a call)
+/// are kept verbatim so each comment stays mapped to its own line.
+fn is_multiline_with_comments(node: Node<'_>, ctx: &FormatContext<'_>) -> bool {
+    node.start_position().row != node.end_position().row && ctx.node_text(node).contains('#')
+}
+
 /// Format annotation: `@export`, `@onready`, etc.
 pub fn format_annotation(node: Node<'_>, ctx: &mut FormatContext<'_>) {
     let line = node.start_position().row + 1;
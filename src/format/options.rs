@@ -1,5 +1,10 @@
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 
+use super::editorconfig;
+use crate::config::{FormatConfig, IndentKind};
+
 /// Indentation style for formatting.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -18,17 +23,18 @@ impl IndentStyle {
         }
     }
 
-    /// Get the visual width of one indent level (for line length calculation).
-    pub fn width(&self) -> usize {
+    /// Get the visual width of one indent level (for line length calculation),
+    /// with a tab advancing `tab_width` columns.
+    pub fn width(&self, tab_width: usize) -> usize {
         match self {
-            IndentStyle::Tabs => 4, // Tab counts as 4 spaces for line length
+            IndentStyle::Tabs => tab_width.max(1),
             IndentStyle::Spaces(n) => *n,
         }
     }
 }
 
 /// Formatting options.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FormatOptions {
     /// Indentation style (tabs or spaces).
     #[serde(default)]
@@ -38,6 +44,10 @@ pub struct FormatOptions {
     #[serde(default = "default_line_length")]
     pub max_line_length: usize,
 
+    /// Columns a tab advances to when measuring line length.
+    #[serde(default = "default_tab_width")]
+    pub tab_width: usize,
+
     /// Whether to ensure a trailing newline at end of file.
     #[serde(default = "default_true")]
     pub trailing_newline: bool,
@@ -45,28 +55,125 @@ pub struct FormatOptions {
     /// Whether to reorder class members according to the GDScript style guide.
     #[serde(default)]
     pub reorder: bool,
+
+    /// Sort enum members alphabetically when reordering (enums with explicit values are kept).
+    #[serde(default)]
+    pub sort_enum_members: bool,
+
+    /// Sort constants alphabetically by name when reordering.
+    #[serde(default)]
+    pub sort_constants: bool,
+
+    /// Pad single-line dictionaries as `{ a: 1 }` rather than `{a: 1}`.
+    #[serde(default = "default_true")]
+    pub space_inside_braces: bool,
+
+    /// Pad single-line arrays as `[ 1, 2 ]` rather than `[1, 2]`.
+    #[serde(default)]
+    pub space_inside_brackets: bool,
+
+    /// Pad the `=` of consecutive single-line assignments and `var`s to a common column.
+    #[serde(default)]
+    pub align_consecutive_assignments: bool,
+
+    /// Wrap standalone `#` comments longer than `max_line_length`.
+    #[serde(default)]
+    pub reflow_comments: bool,
+
+    /// With `reflow_comments`, also wrap `##` doc comments.
+    #[serde(default)]
+    pub reflow_doc_comments: bool,
+
+    /// Project-specific methods to order alongside engine virtual methods.
+    #[serde(default)]
+    pub additional_virtual_methods: Vec<String>,
+
+    /// Blank lines around top-level functions and classes.
+    #[serde(default = "default_blank_lines_top_level_functions")]
+    pub blank_lines_top_level_functions: usize,
+
+    /// Blank lines around functions and classes nested inside a class.
+    #[serde(default = "default_blank_lines_class_members")]
+    pub blank_lines_class_members: usize,
+
+    /// Maximum number of consecutive blank lines kept anywhere in the output.
+    #[serde(default = "default_max_consecutive_blank_lines")]
+    pub max_consecutive_blank_lines: usize,
+
+    /// Re-parse the output and fail with `FormatError::AstChanged` if its AST
+    /// differs from the source's, instead of returning it.
+    #[serde(default)]
+    pub verify_ast: bool,
 }
 
+/// Upper bound for any configured blank-line count.
+pub(crate) const MAX_BLANK_LINES: usize = 4;
+
 fn default_line_length() -> usize {
     100
 }
 
+fn default_tab_width() -> usize {
+    4
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_blank_lines_top_level_functions() -> usize {
+    2
+}
+
+fn default_blank_lines_class_members() -> usize {
+    1
+}
+
+fn default_max_consecutive_blank_lines() -> usize {
+    2
+}
+
 impl Default for FormatOptions {
+    /// Same as [`FormatOptions::godot_default`].
     fn default() -> Self {
+        Self::godot_default()
+    }
+}
+
+impl FormatOptions {
+    /// The official GDScript style guide: tab indentation, 100-column lines, a
+    /// trailing newline, padded dictionary braces and two blank lines around
+    /// top-level functions. Optional passes such as reordering are off.
+    pub fn godot_default() -> Self {
         Self {
             indent_style: IndentStyle::default(),
             max_line_length: default_line_length(),
+            tab_width: default_tab_width(),
             trailing_newline: true,
             reorder: false,
+            sort_enum_members: false,
+            sort_constants: false,
+            space_inside_braces: true,
+            space_inside_brackets: false,
+            align_consecutive_assignments: false,
+            reflow_comments: false,
+            reflow_doc_comments: false,
+            additional_virtual_methods: Vec::new(),
+            blank_lines_top_level_functions: default_blank_lines_top_level_functions(),
+            blank_lines_class_members: default_blank_lines_class_members(),
+            max_consecutive_blank_lines: default_max_consecutive_blank_lines(),
+            verify_ast: false,
+        }
+    }
+
+    /// Start from [`FormatOptions::godot_default`] and change only what is set, e.g.
+    /// `FormatOptions::builder().indent_spaces(4).max_line_length(120).build()`.
+    pub fn builder() -> FormatOptionsBuilder {
+        FormatOptionsBuilder {
+            options: Self::godot_default(),
         }
     }
-}
 
-impl FormatOptions {
     /// Create options with spaces indentation.
     pub fn with_spaces(n: usize) -> Self {
         Self {
@@ -74,4 +181,185 @@ impl FormatOptions {
             ..Default::default()
         }
     }
+
+    /// Build options from the config's `[format]` section, using defaults for unset fields.
+    pub fn from_config(config: &FormatConfig) -> Self {
+        let defaults = Self::default();
+        let indent_style = match config.indent_style {
+            Some(IndentKind::Spaces) => IndentStyle::Spaces(config.indent_size.unwrap_or(4)),
+            Some(IndentKind::Tabs) | None => IndentStyle::Tabs,
+        };
+
+        Self {
+            indent_style,
+            max_line_length: config.line_length.unwrap_or(defaults.max_line_length),
+            tab_width: config.tab_width.unwrap_or(defaults.tab_width),
+            reorder: config.reorder.unwrap_or(defaults.reorder),
+            sort_enum_members: config
+                .sort_enum_members
+                .unwrap_or(defaults.sort_enum_members),
+            sort_constants: config.sort_constants.unwrap_or(defaults.sort_constants),
+            space_inside_braces: config
+                .space_inside_braces
+                .unwrap_or(defaults.space_inside_braces),
+            space_inside_brackets: config
+                .space_inside_brackets
+                .unwrap_or(defaults.space_inside_brackets),
+            align_consecutive_assignments: config
+                .align_consecutive_assignments
+                .unwrap_or(defaults.align_consecutive_assignments),
+            reflow_comments: config.reflow_comments.unwrap_or(defaults.reflow_comments),
+            reflow_doc_comments: config
+                .reflow_doc_comments
+                .unwrap_or(defaults.reflow_doc_comments),
+            additional_virtual_methods: config
+                .additional_virtual_methods
+                .clone()
+                .unwrap_or(defaults.additional_virtual_methods),
+            blank_lines_top_level_functions: config
+                .blank_lines_top_level_functions
+                .unwrap_or(defaults.blank_lines_top_level_functions),
+            blank_lines_class_members: config
+                .blank_lines_class_members
+                .unwrap_or(defaults.blank_lines_class_members),
+            max_consecutive_blank_lines: config
+                .max_consecutive_blank_lines
+                .unwrap_or(defaults.max_consecutive_blank_lines),
+            ..defaults
+        }
+    }
+
+    /// Build options for `path`: like `from_config`, but when the config leaves
+    /// `indent_style` unset, indentation comes from the file's `.editorconfig`.
+    pub fn from_config_for_file(config: &FormatConfig, path: &Path) -> Self {
+        let mut options = Self::from_config(config);
+        if config.indent_style.is_none() && config.editorconfig.unwrap_or(true) {
+            if let Some(indent_style) = editorconfig::indent_style_for(path) {
+                options.indent_style = indent_style;
+            }
+        }
+        options
+    }
+
+    /// Maximum consecutive blank lines, clamped to `1..=4`.
+    pub fn max_blank_lines(&self) -> usize {
+        self.max_consecutive_blank_lines.clamp(1, MAX_BLANK_LINES)
+    }
+
+    /// Blank lines around top-level functions and classes, clamped to the maximum.
+    pub fn top_level_function_blank_lines(&self) -> usize {
+        self.blank_lines_top_level_functions
+            .min(self.max_blank_lines())
+    }
+
+    /// Blank lines around nested functions and classes, clamped to the maximum.
+    pub fn class_member_blank_lines(&self) -> usize {
+        self.blank_lines_class_members.min(self.max_blank_lines())
+    }
+}
+
+/// Builds [`FormatOptions`] one setting at a time, from [`FormatOptions::builder`].
+#[derive(Debug, Clone)]
+pub struct FormatOptionsBuilder {
+    options: FormatOptions,
+}
+
+impl FormatOptionsBuilder {
+    pub fn indent_tabs(mut self) -> Self {
+        self.options.indent_style = IndentStyle::Tabs;
+        self
+    }
+
+    pub fn indent_spaces(mut self, n: usize) -> Self {
+        self.options.indent_style = IndentStyle::Spaces(n);
+        self
+    }
+
+    pub fn max_line_length(mut self, max_line_length: usize) -> Self {
+        self.options.max_line_length = max_line_length;
+        self
+    }
+
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.options.tab_width = tab_width;
+        self
+    }
+
+    pub fn trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.options.trailing_newline = trailing_newline;
+        self
+    }
+
+    pub fn reorder(mut self, reorder: bool) -> Self {
+        self.options.reorder = reorder;
+        self
+    }
+
+    pub fn sort_enum_members(mut self, sort_enum_members: bool) -> Self {
+        self.options.sort_enum_members = sort_enum_members;
+        self
+    }
+
+    pub fn sort_constants(mut self, sort_constants: bool) -> Self {
+        self.options.sort_constants = sort_constants;
+        self
+    }
+
+    pub fn space_inside_braces(mut self, space_inside_braces: bool) -> Self {
+        self.options.space_inside_braces = space_inside_braces;
+        self
+    }
+
+    pub fn space_inside_brackets(mut self, space_inside_brackets: bool) -> Self {
+        self.options.space_inside_brackets = space_inside_brackets;
+        self
+    }
+
+    pub fn align_consecutive_assignments(mut self, align: bool) -> Self {
+        self.options.align_consecutive_assignments = align;
+        self
+    }
+
+    pub fn reflow_comments(mut self, reflow_comments: bool) -> Self {
+        self.options.reflow_comments = reflow_comments;
+        self
+    }
+
+    pub fn reflow_doc_comments(mut self, reflow_doc_comments: bool) -> Self {
+        self.options.reflow_doc_comments = reflow_doc_comments;
+        self
+    }
+
+    pub fn additional_virtual_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.additional_virtual_methods = methods.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn blank_lines_top_level_functions(mut self, count: usize) -> Self {
+        self.options.blank_lines_top_level_functions = count;
+        self
+    }
+
+    pub fn blank_lines_class_members(mut self, count: usize) -> Self {
+        self.options.blank_lines_class_members = count;
+        self
+    }
+
+    pub fn max_consecutive_blank_lines(mut self, count: usize) -> Self {
+        self.options.max_consecutive_blank_lines = count;
+        self
+    }
+
+    pub fn verify_ast(mut self, verify_ast: bool) -> Self {
+        self.options.verify_ast = verify_ast;
+        self
+    }
+
+    pub fn build(self) -> FormatOptions {
+        self.options
+    }
 }
@@ -1,5 +1,6 @@
-use super::comments::Comments;
+use super::comments::{reflow_comment, Comments};
 use super::options::FormatOptions;
+use super::skip_regions::SkipRegions;
 
 /// A single formatted line with optional source line mapping.
 #[derive(Debug, Clone)]
@@ -37,15 +38,34 @@ impl FormattedLine {
 }
 
 /// Builder for formatted output.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct FormattedOutput {
     lines: Vec<FormattedLine>,
+    /// Maximum number of consecutive blank lines.
+    max_blank_lines: usize,
+}
+
+impl Default for FormattedOutput {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl FormattedOutput {
     /// Create a new empty output.
     pub fn new() -> Self {
-        Self { lines: Vec::new() }
+        Self {
+            lines: Vec::new(),
+            max_blank_lines: 2,
+        }
+    }
+
+    /// Create a new empty output with a custom cap on consecutive blank lines.
+    pub fn with_max_blank_lines(max_blank_lines: usize) -> Self {
+        Self {
+            lines: Vec::new(),
+            max_blank_lines,
+        }
     }
 
     /// Add a formatted line.
@@ -69,9 +89,9 @@ impl FormattedOutput {
         self.lines.push(FormattedLine::empty());
     }
 
-    /// Add multiple empty lines, but ensure we don't exceed 2 consecutive.
+    /// Add multiple empty lines, but ensure we don't exceed the configured maximum.
     pub fn push_blank_lines(&mut self, count: usize) {
-        let count = count.min(2); // Never more than 2 consecutive blank lines
+        let count = count.min(self.max_blank_lines);
         let trailing_blanks = self.trailing_blank_count();
         let to_add = count.saturating_sub(trailing_blanks);
         for _ in 0..to_add {
@@ -79,6 +99,39 @@ impl FormattedOutput {
         }
     }
 
+    /// Pad the assignment operator (`=` or `:=`) of the lines at `indices` so they
+    /// all start in the same column. Lines without one are left alone.
+    pub fn align_assignments(&mut self, indices: &[usize]) {
+        let operators: Vec<_> = indices
+            .iter()
+            .map(|&i| assignment_operator(&self.lines[i].content))
+            .collect();
+        let column = |content: &str, pos: usize| content[..pos].chars().count();
+        let Some(target) = indices
+            .iter()
+            .zip(&operators)
+            .filter_map(|(&i, pos)| pos.map(|pos| column(&self.lines[i].content, pos)))
+            .max()
+        else {
+            return;
+        };
+
+        for (&i, pos) in indices.iter().zip(operators) {
+            let Some(pos) = pos else {
+                continue;
+            };
+            let content = &mut self.lines[i].content;
+            let padding = " ".repeat(target - column(content, pos));
+            content.insert_str(pos, &padding);
+        }
+    }
+
+    /// Drop any blank lines at the end of the output.
+    pub fn trim_trailing_blank_lines(&mut self) {
+        let keep = self.lines.len() - self.trailing_blank_count();
+        self.lines.truncate(keep);
+    }
+
     /// Count trailing blank lines.
     fn trailing_blank_count(&self) -> usize {
         self.lines
@@ -250,6 +303,31 @@ impl FormattedOutput {
         self.lines = new_lines;
     }
 
+    /// Wrap standalone comments that exceed the line length (`reflow_comments`).
+    ///
+    /// Runs after `inject_comments`. Inline comments and comments inside
+    /// `# fmt: off` regions are never touched.
+    pub fn reflow_comments(
+        &mut self,
+        comments: &Comments,
+        skip_regions: &SkipRegions,
+        options: &FormatOptions,
+    ) {
+        for line in &mut self.lines {
+            let Some(src_line) = line.source_line else {
+                continue;
+            };
+            if skip_regions.is_skipped(src_line)
+                || comments.get_standalone(src_line) != Some(&line.content)
+            {
+                continue;
+            }
+            if let Some(wrapped) = reflow_comment(&line.content, options) {
+                line.content = wrapped.join("\n");
+            }
+        }
+    }
+
     /// Convert to final string output.
     pub fn to_string(&self, options: &FormatOptions) -> String {
         let mut result: Vec<&str> = self.lines.iter().map(|l| l.content.as_str()).collect();
@@ -268,4 +346,30 @@ impl FormattedOutput {
 
         output
     }
+
+    /// Source line (1-indexed) for each line of `to_string` output, if known.
+    /// Lines whose content spans several output lines map each one to the same source line.
+    pub fn line_mapping(&self) -> Vec<Option<usize>> {
+        let mut end = self.lines.len();
+        while end > 0 && self.lines[end - 1].content.is_empty() {
+            end -= 1;
+        }
+
+        self.lines[..end]
+            .iter()
+            .flat_map(|l| std::iter::repeat_n(l.source_line, l.content.split('\n').count()))
+            .collect()
+    }
+}
+
+/// Byte offset of the first ` = ` or ` := ` operator in a single-line statement.
+fn assignment_operator(content: &str) -> Option<usize> {
+    if content.contains('\n') {
+        return None;
+    }
+    [" = ", " := "]
+        .iter()
+        .filter_map(|op| content.find(op))
+        .min()
+        .map(|i| i + 1)
 }
@@ -0,0 +1,73 @@
+use tree_sitter::Node;
+
+use super::{run_formatter_mapped, FormatError, FormatOptions};
+use crate::parser;
+
+/// Format only the statements overlapping lines `start..=end` (1-indexed, inclusive)
+/// and return the whole file, with every other line kept as it was.
+///
+/// Statements are taken from the innermost block that contains the whole range, so
+/// a range inside a function body reformats just those statements, while a range
+/// touching the `func` line reformats the whole function. Nothing changes when the
+/// range only covers blank lines or comments.
+pub fn run_formatter_range(
+    source: &str,
+    options: &FormatOptions,
+    start: usize,
+    end: usize,
+) -> Result<String, FormatError> {
+    let tree = parser::parse(source).map_err(FormatError::Parse)?;
+    let Some((first, last)) = statement_lines(tree.root_node(), start, end) else {
+        return Ok(source.to_string());
+    };
+
+    let (formatted, mapping) = run_formatter_mapped(source, options)?;
+    let in_range = |line: &Option<usize>| line.is_some_and(|l| (first..=last).contains(&l));
+    let (Some(from), Some(to)) = (
+        mapping.iter().position(in_range),
+        mapping.iter().rposition(in_range),
+    ) else {
+        return Ok(source.to_string());
+    };
+
+    let source_lines: Vec<&str> = source.lines().collect();
+    let formatted_lines: Vec<&str> = formatted.lines().collect();
+    let mut lines: Vec<&str> = Vec::with_capacity(source_lines.len());
+    lines.extend(&source_lines[..first - 1]);
+    lines.extend(&formatted_lines[from..=to]);
+    lines.extend(source_lines.get(last..).unwrap_or_default());
+
+    let mut result = lines.join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// First and last source line of the statements overlapping `start..=end`.
+fn statement_lines(root: Node<'_>, start: usize, end: usize) -> Option<(usize, usize)> {
+    let scope = innermost_block(root, start, end).unwrap_or(root);
+
+    let mut cursor = scope.walk();
+    let lines: Vec<_> = scope
+        .named_children(&mut cursor)
+        .filter(|c| c.kind() != "comment")
+        .map(|c| (c.start_position().row + 1, c.end_position().row + 1))
+        .filter(|&(first, last)| first <= end && last >= start)
+        .collect();
+
+    let first = lines.iter().map(|&(first, _)| first).min()?;
+    let last = lines.iter().map(|&(_, last)| last).max()?;
+    Some((first, last))
+}
+
+/// The deepest block (`body`/`class_body`) below `node` that spans the whole range.
+fn innermost_block(node: Node<'_>, start: usize, end: usize) -> Option<Node<'_>> {
+    let mut cursor = node.walk();
+    let child = node
+        .named_children(&mut cursor)
+        .find(|c| c.start_position().row + 1 <= start && c.end_position().row + 1 >= end)?;
+
+    innermost_block(child, start, end)
+        .or_else(|| matches!(child.kind(), "body" | "class_body").then_some(child))
+}
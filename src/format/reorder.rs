@@ -25,100 +25,14 @@ use tree_sitter::Node;
 
 use crate::parser;
 
+use super::member_kind::{
+    classify, get_annotation_name, has_export_modifier, has_section_modifier,
+    is_section_annotation, is_standalone_annotation, node_text,
+};
 use super::skip_regions::SkipRegions;
-use super::FormatError;
+use super::{FormatError, FormatOptions};
 
-/// Classification of class members for reordering.
-/// The order of variants determines sort priority (lower = earlier in file).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum MemberKind {
-    // 01. File-level annotations
-    Tool,
-    Icon,
-    StaticUnload,
-
-    // 02. class_name
-    ClassName,
-
-    // 03. extends
-    Extends,
-
-    // 04. signals
-    Signal,
-
-    // 05. enums
-    Enum,
-
-    // 06. constants
-    Const,
-
-    // 07. static variables
-    StaticVar,
-
-    // 08. @export variables
-    ExportVar,
-
-    // 09. regular variables
-    Var,
-
-    // 10. @onready variables
-    OnreadyVar,
-
-    // 11. _static_init()
-    StaticInit,
-
-    // 12. remaining static methods
-    StaticMethod,
-
-    // 14. Virtual methods in specific order
-    VirtualInit,
-    VirtualEnterTree,
-    VirtualReady,
-    VirtualProcess,
-    VirtualPhysicsProcess,
-    VirtualOther,
-
-    // 15. Overridden custom methods (private methods not in virtual list)
-    OverriddenCustomMethod,
-
-    // 16. Regular methods
-    Method,
-
-    // 17. Inner classes
-    InnerClass,
-}
-
-impl MemberKind {
-    /// Check if this is a header kind (no blank lines between these).
-    fn is_header(&self) -> bool {
-        matches!(
-            self,
-            MemberKind::Tool
-                | MemberKind::Icon
-                | MemberKind::StaticUnload
-                | MemberKind::ClassName
-                | MemberKind::Extends
-        )
-    }
-
-    /// Check if this is a function-like kind (2 blank lines around these).
-    fn is_function_like(&self) -> bool {
-        matches!(
-            self,
-            MemberKind::StaticInit
-                | MemberKind::StaticMethod
-                | MemberKind::VirtualInit
-                | MemberKind::VirtualEnterTree
-                | MemberKind::VirtualReady
-                | MemberKind::VirtualProcess
-                | MemberKind::VirtualPhysicsProcess
-                | MemberKind::VirtualOther
-                | MemberKind::OverriddenCustomMethod
-                | MemberKind::Method
-                | MemberKind::InnerClass
-        )
-    }
-}
+pub use super::member_kind::MemberKind;
 
 /// A declaration with its source text and metadata.
 #[derive(Debug, Clone)]
@@ -137,102 +51,70 @@ pub struct Declaration {
 
     /// Whether this declaration has a leading section annotation (@export_category, @export_group, @export_subgroup)
     pub has_section_annotation: bool,
-}
 
-/// Extract the annotation name from an annotation node.
-fn get_annotation_name<'a>(node: Node<'a>, source: &'a str) -> Option<&'a str> {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "identifier" {
-            return node_text(child, source);
-        }
-    }
-    None
-}
+    /// Source line of the declaration itself, excluding leading comments (1-indexed)
+    pub line: usize,
 
-/// Get the text of a node.
-fn node_text<'a>(node: Node<'a>, source: &'a str) -> Option<&'a str> {
-    source.get(node.start_byte()..node.end_byte())
-}
+    /// Short label such as `func _ready` or `var speed`, for messages
+    pub label: String,
 
-/// Check if a function is static by looking for static keyword.
-fn is_static_function(node: Node<'_>) -> bool {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "static_keyword" {
-            return true;
-        }
-    }
-    false
-}
+    /// Declared name, used to sort constants alphabetically
+    pub name: Option<String>,
 
-/// Classify a function as a virtual method kind.
-fn classify_virtual_method(name: &str) -> MemberKind {
-    match name {
-        "_init" => MemberKind::VirtualInit,
-        "_enter_tree" => MemberKind::VirtualEnterTree,
-        "_ready" => MemberKind::VirtualReady,
-        "_process" => MemberKind::VirtualProcess,
-        "_physics_process" => MemberKind::VirtualPhysicsProcess,
-        "_exit_tree"
-        | "_input"
-        | "_unhandled_input"
-        | "_notification"
-        | "_draw"
-        | "_gui_input"
-        | "_unhandled_key_input"
-        | "_shortcut_input"
-        | "_get_configuration_warnings"
-        | "_get_configuration_warning" => MemberKind::VirtualOther,
-        name if name.starts_with('_') => MemberKind::OverriddenCustomMethod,
-        _ => MemberKind::Method,
-    }
+    /// Export group (started by `@export_group`, `@export_subgroup` or `@export_category`)
+    /// this exported variable belongs to. Grouped variables stay together in source order.
+    pub export_group: Option<usize>,
 }
 
-/// Check if an annotation is an export variant.
-fn is_export_annotation(name: &str) -> bool {
-    name == "export" || name.starts_with("export_")
-}
-
-/// Check if an annotation is standalone (not attached to a declaration).
-fn is_standalone_annotation(name: &str) -> bool {
-    matches!(name, "tool" | "icon" | "static_unload")
+impl Declaration {
+    /// The kind this declaration sorts as: members of an export group all sort as
+    /// exported variables, so an `@onready @export` var does not leave its group.
+    pub fn sort_kind(&self) -> MemberKind {
+        if self.export_group.is_some() {
+            MemberKind::ExportVar
+        } else {
+            self.kind
+        }
+    }
 }
 
-/// Check if an annotation is a section marker (export_category, export_group, export_subgroup).
-fn is_section_annotation(name: &str) -> bool {
-    matches!(name, "export_category" | "export_group" | "export_subgroup")
+/// A class member that is out of order and would be moved by `reorder_source`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderViolation {
+    /// Label of the misplaced member, e.g. `signal died`.
+    pub member: String,
+    /// The member's kind.
+    pub kind: MemberKind,
+    /// Line of the member in the source (1-indexed).
+    pub line: usize,
+    /// Label of the member that should directly precede it, or `None` if it belongs first.
+    pub expected_after: Option<String>,
 }
 
-/// Get annotations and modifiers from inside a node.
-/// Returns annotations (like @export, @onready) and modifiers (like static).
-fn get_node_modifiers(node: Node<'_>, source: &str) -> Vec<String> {
-    let mut modifiers = Vec::new();
-    let mut cursor = node.walk();
+/// Short label for a declaration node: its keyword and name, or its first line.
+fn declaration_label(node: Node<'_>, source: &str) -> String {
+    let keyword = match node.kind() {
+        "signal_statement" => "signal",
+        "enum_definition" => "enum",
+        "const_statement" => "const",
+        "variable_statement" => "var",
+        "function_definition" => "func",
+        "constructor_definition" => return "func _init".to_string(),
+        "class_definition" => "class",
+        _ => "",
+    };
+    let name = node
+        .child_by_field_name("name")
+        .and_then(|n| node_text(n, source));
 
-    for child in node.children(&mut cursor) {
-        if child.kind() == "annotations" {
-            // Found annotations wrapper - extract individual annotations
-            let mut ann_cursor = child.walk();
-            for ann in child.children(&mut ann_cursor) {
-                if ann.kind() == "annotation" {
-                    if let Some(name) = get_annotation_name(ann, source) {
-                        modifiers.push(name.to_string());
-                    }
-                }
-            }
-        } else if child.kind() == "annotation" {
-            // Direct annotation child
-            if let Some(name) = get_annotation_name(child, source) {
-                modifiers.push(name.to_string());
-            }
-        } else if child.kind() == "static_keyword" {
-            // Static keyword as direct child
-            modifiers.push("static".to_string());
-        }
+    match name {
+        Some(name) if !keyword.is_empty() => format!("{} {}", keyword, name),
+        _ => node_text(node, source)
+            .and_then(|text| text.lines().next())
+            .unwrap_or(keyword)
+            .trim()
+            .to_string(),
     }
-
-    modifiers
 }
 
 /// Get the text from a range of lines (1-indexed, inclusive).
@@ -252,11 +134,21 @@ fn get_lines_text(source: &str, start_line: usize, end_line: usize) -> String {
     result
 }
 
-/// Extract declarations from a scope.
+/// Whether a comment follows code on the same line, like `var x = 1 # comment`.
+fn is_inline_comment(comment: Node<'_>, source: &str) -> bool {
+    let line_start = source[..comment.start_byte()]
+        .rfind('\n')
+        .map_or(0, |i| i + 1);
+    !source[line_start..comment.start_byte()].trim().is_empty()
+}
+
+/// Extract declarations from a scope, treating methods named in `extra_virtuals`
+/// as virtual methods.
 fn extract_declarations(
     node: Node<'_>,
     source: &str,
     skip_regions: &SkipRegions,
+    extra_virtuals: &[String],
 ) -> Vec<Declaration> {
     let mut declarations = Vec::new();
     let mut cursor = node.walk();
@@ -265,6 +157,9 @@ fn extract_declarations(
     let mut i = 0;
     let mut original_index = 0;
     let mut processed_annotation_indices = std::collections::HashSet::new();
+    // Export group started by the latest section marker, and how many were seen
+    let mut export_group: Option<usize> = None;
+    let mut group_count = 0;
 
     while i < children.len() {
         let child = children[i];
@@ -282,12 +177,8 @@ fn extract_declarations(
             if let Some(name) = get_annotation_name(child, source) {
                 if is_standalone_annotation(name) {
                     // Standalone annotation - add as its own declaration
-                    let kind = match name {
-                        "tool" => MemberKind::Tool,
-                        "icon" => MemberKind::Icon,
-                        "static_unload" => MemberKind::StaticUnload,
-                        _ => unreachable!(),
-                    };
+                    let kind = MemberKind::for_standalone_annotation(name)
+                        .expect("standalone annotations have a member kind");
                     let text = get_lines_text(source, child_start_line, child_end_line);
                     declarations.push(Declaration {
                         kind,
@@ -295,6 +186,10 @@ fn extract_declarations(
                         original_index,
                         has_doc_comment: false,
                         has_section_annotation: false,
+                        line: child_start_line,
+                        label: declaration_label(child, source),
+                        name: None,
+                        export_group: None,
                     });
                     processed_annotation_indices.insert(i);
                     original_index += 1;
@@ -338,9 +233,9 @@ fn extract_declarations(
 
                             // Check for comments
                             if prev_child.kind() == "comment" {
-                                // Only consider comments that start at the beginning of a line
-                                if prev_child.start_position().column != 0 {
-                                    break;
+                                // An inline comment goes with the code on its line
+                                if is_inline_comment(prev_child, source) {
+                                    continue;
                                 }
                                 let prev_end_line = prev_child.end_position().row + 1;
                                 // Allow up to 1 blank line between comment and annotation
@@ -364,6 +259,8 @@ fn extract_declarations(
                             break;
                         }
 
+                        group_count += 1;
+                        export_group = Some(group_count);
                         let text = get_lines_text(source, start_line, child_end_line);
                         declarations.push(Declaration {
                             kind: MemberKind::Var,
@@ -371,6 +268,10 @@ fn extract_declarations(
                             original_index,
                             has_doc_comment,
                             has_section_annotation: true,
+                            line: child_start_line,
+                            label: declaration_label(child, source),
+                            name: None,
+                            export_group,
                         });
                         processed_annotation_indices.insert(i);
                         original_index += 1;
@@ -382,66 +283,7 @@ fn extract_declarations(
         }
 
         // Classify based on node type and annotations
-        let kind = match child.kind() {
-            "class_name_statement" => Some(MemberKind::ClassName),
-            "extends_statement" => Some(MemberKind::Extends),
-            "signal_statement" => Some(MemberKind::Signal),
-            "enum_definition" => Some(MemberKind::Enum),
-            "const_statement" => Some(MemberKind::Const),
-            "variable_statement" => {
-                // Get annotations and modifiers from inside the node (tree-sitter puts them as children)
-                let node_modifiers = get_node_modifiers(child, source);
-
-                // Check modifiers (priority: onready > export > static > regular)
-                let mut var_kind = MemberKind::Var;
-                for m in &node_modifiers {
-                    if m == "onready" {
-                        var_kind = MemberKind::OnreadyVar;
-                        break;
-                    }
-                }
-                if var_kind == MemberKind::Var {
-                    for m in &node_modifiers {
-                        if is_export_annotation(m) {
-                            var_kind = MemberKind::ExportVar;
-                            break;
-                        }
-                    }
-                }
-                if var_kind == MemberKind::Var {
-                    for m in &node_modifiers {
-                        if m == "static" {
-                            var_kind = MemberKind::StaticVar;
-                            break;
-                        }
-                    }
-                }
-                Some(var_kind)
-            }
-            "function_definition" => {
-                let is_static = is_static_function(child);
-                let name = child
-                    .child_by_field_name("name")
-                    .and_then(|n| node_text(n, source))
-                    .unwrap_or("");
-
-                if is_static {
-                    if name == "_static_init" {
-                        Some(MemberKind::StaticInit)
-                    } else {
-                        Some(MemberKind::StaticMethod)
-                    }
-                } else {
-                    Some(classify_virtual_method(name))
-                }
-            }
-            // _init() is parsed as constructor_definition, not function_definition
-            "constructor_definition" => Some(MemberKind::VirtualInit),
-            "class_definition" => Some(MemberKind::InnerClass),
-            // Comments (including ## doc comments) are not standalone declarations.
-            // They are included with the following declaration they document.
-            _ => None,
-        };
+        let kind = classify(child, source, extra_virtuals);
 
         if let Some(kind) = kind {
             // Include preceding comments and annotations (no blank line between)
@@ -449,6 +291,7 @@ fn extract_declarations(
             let mut start_line = child_start_line;
             let mut has_doc_comment = false;
             let mut has_section_annotation = false;
+            let mut has_export_annotation = false;
 
             // First, look at preceding AST children for annotations like @export_category
             // This handles multiline annotations correctly by using AST node boundaries
@@ -476,6 +319,8 @@ fn extract_declarations(
                                 // Check if it's a section annotation
                                 if is_section_annotation(name) {
                                     has_section_annotation = true;
+                                } else {
+                                    has_export_annotation = true;
                                 }
                                 start_line = prev_child.start_position().row + 1;
                                 processed_annotation_indices.insert(prev_idx);
@@ -488,10 +333,11 @@ fn extract_declarations(
                 // Comments immediately preceding a declaration are attached to it
                 // But NOT inline/trailing comments (those that start in the middle of a line)
                 else if prev_child.kind() == "comment" {
-                    // Only consider comments that start at the beginning of a line (column 0)
-                    // This excludes inline comments like `var x = 1 # comment`
-                    if prev_child.start_position().column != 0 {
-                        break;
+                    // An inline comment like `var x = 1 # comment` belongs to the code
+                    // before it on its line: skip it and let that code decide whether the
+                    // line moves with this declaration
+                    if is_inline_comment(prev_child, source) {
+                        continue;
                     }
                     let prev_end_line = prev_child.end_position().row + 1;
                     // Include comments that precede a declaration, allowing:
@@ -521,12 +367,34 @@ fn extract_declarations(
 
             let text = get_lines_text(source, start_line, child_end_line);
 
+            // A section marker starts a new export group that later exported
+            // variables join until the next marker
+            let is_var = child.kind() == "variable_statement";
+            let starts_group =
+                has_section_annotation || (is_var && has_section_modifier(child, source));
+            if starts_group {
+                group_count += 1;
+                export_group = Some(group_count);
+            }
+            let exported = is_var && (has_export_annotation || has_export_modifier(child, source));
+
             declarations.push(Declaration {
                 kind,
                 text,
                 original_index,
                 has_doc_comment,
                 has_section_annotation,
+                line: child_start_line,
+                label: declaration_label(child, source),
+                name: child
+                    .child_by_field_name("name")
+                    .and_then(|n| node_text(n, source))
+                    .map(str::to_string),
+                export_group: if starts_group || exported {
+                    export_group
+                } else {
+                    None
+                },
             });
             original_index += 1;
         }
@@ -538,13 +406,94 @@ fn extract_declarations(
 }
 
 /// Sort declarations by MemberKind, preserving original order within same kind.
-fn sort_declarations(declarations: &mut [Declaration]) {
-    declarations.sort_by(|a, b| match a.kind.cmp(&b.kind) {
+/// With `sort_constants`, constants are ordered by name instead.
+fn sort_declarations(declarations: &mut [Declaration], options: &FormatOptions) {
+    declarations.sort_by(|a, b| match a.sort_kind().cmp(&b.sort_kind()) {
+        std::cmp::Ordering::Equal if options.sort_constants && a.kind == MemberKind::Const => a
+            .name
+            .cmp(&b.name)
+            .then(a.original_index.cmp(&b.original_index)),
         std::cmp::Ordering::Equal => a.original_index.cmp(&b.original_index),
         other => other,
     });
 }
 
+/// Sort the members of every enum alphabetically.
+///
+/// Enums with an explicit `= value` anywhere are left alone, since moving any
+/// member would change the auto-numbered values after it. Enums containing
+/// comments are skipped too, as there is no reliable way to tell which member a
+/// comment belongs to. This is the first step of `reorder_source_with_options`
+/// when `sort_enum_members` is set.
+pub fn sort_enum_members(source: &str) -> Result<String, FormatError> {
+    let tree = parser::parse(source).map_err(FormatError::Parse)?;
+    let skip_regions = SkipRegions::parse(source);
+
+    let mut replacements = Vec::new();
+    collect_enum_member_sorts(tree.root_node(), source, &skip_regions, &mut replacements);
+
+    let mut result = source.to_string();
+    for (start, end, text) in replacements.into_iter().rev() {
+        result.replace_range(start..end, &text);
+    }
+    Ok(result)
+}
+
+/// Collect `(start_byte, end_byte, replacement)` edits that sort enum members, in source order.
+fn collect_enum_member_sorts(
+    node: Node<'_>,
+    source: &str,
+    skip_regions: &SkipRegions,
+    replacements: &mut Vec<(usize, usize, String)>,
+) {
+    if node.kind() == "enum_definition" {
+        if skip_regions.is_skipped(node.start_position().row + 1) {
+            return;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut cursor = body.walk();
+        let children: Vec<_> = body.children(&mut cursor).collect();
+        if children.iter().any(|c| c.kind() == "comment") {
+            return;
+        }
+        let members: Vec<_> = children
+            .into_iter()
+            .filter(|c| c.kind() == "enum_entry" || c.kind() == "enumerator")
+            .collect();
+        if members
+            .iter()
+            .any(|m| m.child_by_field_name("value").is_some())
+        {
+            return;
+        }
+
+        let mut sorted: Vec<_> = members
+            .iter()
+            .filter_map(|m| node_text(*m, source))
+            .collect();
+        if sorted.len() != members.len() {
+            return;
+        }
+        sorted.sort();
+
+        // Write the sorted names into the existing member slots, keeping commas and spacing
+        for (member, text) in members.iter().zip(sorted) {
+            if node_text(*member, source) != Some(text) {
+                replacements.push((member.start_byte(), member.end_byte(), text.to_string()));
+            }
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_enum_member_sorts(child, source, skip_regions, replacements);
+    }
+}
+
 /// Determine blank lines needed between two declarations.
 fn blank_lines_between(prev: &Declaration, next: &Declaration) -> usize {
     // Header items have no blank lines between them
@@ -564,7 +513,7 @@ fn blank_lines_between(prev: &Declaration, next: &Declaration) -> usize {
     }
 
     // Same category: no blank line
-    if prev.kind == next.kind {
+    if prev.sort_kind() == next.sort_kind() {
         return 0;
     }
 
@@ -601,10 +550,27 @@ fn reconstruct_source(declarations: &[Declaration]) -> String {
 
 /// Reorder declarations in source according to GDScript style guide.
 pub fn reorder_source(source: &str) -> Result<String, FormatError> {
+    reorder_source_with_options(source, &FormatOptions::default())
+}
+
+/// Like [`reorder_source`], also applying the `sort_enum_members`,
+/// `sort_constants` and `additional_virtual_methods` options.
+pub fn reorder_source_with_options(
+    source: &str,
+    options: &FormatOptions,
+) -> Result<String, FormatError> {
     if source.trim().is_empty() {
         return Ok(source.to_string());
     }
 
+    let sorted_enums;
+    let source = if options.sort_enum_members {
+        sorted_enums = sort_enum_members(source)?;
+        sorted_enums.as_str()
+    } else {
+        source
+    };
+
     let tree = parser::parse(source).map_err(FormatError::Parse)?;
     let root = tree.root_node();
     let skip_regions = SkipRegions::parse(source);
@@ -619,7 +585,12 @@ pub fn reorder_source(source: &str) -> Result<String, FormatError> {
     }
 
     // Extract and sort top-level declarations
-    let mut declarations = extract_declarations(root, source, &skip_regions);
+    let mut declarations = extract_declarations(
+        root,
+        source,
+        &skip_regions,
+        &options.additional_virtual_methods,
+    );
 
     if declarations.is_empty() {
         return Ok(source.to_string());
@@ -627,7 +598,7 @@ pub fn reorder_source(source: &str) -> Result<String, FormatError> {
 
     // Check if already in correct order
     let original_order: Vec<_> = declarations.iter().map(|d| d.original_index).collect();
-    sort_declarations(&mut declarations);
+    sort_declarations(&mut declarations, options);
     let sorted_order: Vec<_> = declarations.iter().map(|d| d.original_index).collect();
 
     // If no reordering needed at top level, check inner classes only
@@ -638,7 +609,7 @@ pub fn reorder_source(source: &str) -> Result<String, FormatError> {
     for decl in &mut declarations {
         if decl.kind == MemberKind::InnerClass {
             let original = decl.text.clone();
-            decl.text = reorder_inner_class(&decl.text, &skip_regions, 1)?;
+            decl.text = reorder_inner_class(&decl.text, &skip_regions, options, 1)?;
             if decl.text != original {
                 any_inner_reordered = true;
             }
@@ -661,10 +632,96 @@ pub fn reorder_source(source: &str) -> Result<String, FormatError> {
     Ok(result)
 }
 
+/// Report the members `reorder_source` would move, without rewriting anything.
+///
+/// A member is out of order when an earlier member in the same class belongs
+/// after it. Inner classes are checked as well.
+pub fn check_order(source: &str) -> Result<Vec<OrderViolation>, FormatError> {
+    check_order_with_options(source, &FormatOptions::default())
+}
+
+/// Like [`check_order`], honouring the `additional_virtual_methods` option.
+pub fn check_order_with_options(
+    source: &str,
+    options: &FormatOptions,
+) -> Result<Vec<OrderViolation>, FormatError> {
+    let tree = parser::parse(source).map_err(FormatError::Parse)?;
+    Ok(order_violations(
+        tree.root_node(),
+        source,
+        &options.additional_virtual_methods,
+    ))
+}
+
+/// Like [`check_order_with_options`], for callers that already hold a parsed tree.
+pub(crate) fn order_violations(
+    root: Node<'_>,
+    source: &str,
+    extra_virtuals: &[String],
+) -> Vec<OrderViolation> {
+    let skip_regions = SkipRegions::parse(source);
+
+    // Mirror reorder_source, which leaves files with skipped top-level members alone
+    let mut cursor = root.walk();
+    if root
+        .children(&mut cursor)
+        .any(|child| skip_regions.is_skipped(child.start_position().row + 1))
+    {
+        return Vec::new();
+    }
+
+    let mut violations = Vec::new();
+    collect_order_violations(root, source, &skip_regions, extra_virtuals, &mut violations);
+    violations.sort_by_key(|v| v.line);
+    violations
+}
+
+fn collect_order_violations(
+    scope: Node<'_>,
+    source: &str,
+    skip_regions: &SkipRegions,
+    extra_virtuals: &[String],
+    violations: &mut Vec<OrderViolation>,
+) {
+    let declarations = extract_declarations(scope, source, skip_regions, extra_virtuals);
+
+    let mut sorted: Vec<&Declaration> = declarations.iter().collect();
+    sorted.sort_by_key(|d| (d.sort_kind(), d.original_index));
+
+    let mut latest_kind: Option<MemberKind> = None;
+    for decl in &declarations {
+        if latest_kind.is_some_and(|latest| decl.sort_kind() < latest) {
+            let position = sorted
+                .iter()
+                .position(|d| d.original_index == decl.original_index)
+                .unwrap_or(0);
+            violations.push(OrderViolation {
+                member: decl.label.clone(),
+                kind: decl.kind,
+                line: decl.line,
+                expected_after: position
+                    .checked_sub(1)
+                    .map(|prev| sorted[prev].label.clone()),
+            });
+        }
+        latest_kind = latest_kind.max(Some(decl.sort_kind()));
+    }
+
+    let mut cursor = scope.walk();
+    for child in scope.children(&mut cursor) {
+        if child.kind() == "class_definition" {
+            if let Some(body) = child.child_by_field_name("body") {
+                collect_order_violations(body, source, skip_regions, extra_virtuals, violations);
+            }
+        }
+    }
+}
+
 /// Reorder the body of an inner class.
 fn reorder_inner_class(
     class_text: &str,
     skip_regions: &SkipRegions,
+    options: &FormatOptions,
     _depth: usize,
 ) -> Result<String, FormatError> {
     let tree = parser::parse(class_text).map_err(FormatError::Parse)?;
@@ -702,18 +759,23 @@ fn reorder_inner_class(
     let body_tree = parser::parse(body_text).map_err(FormatError::Parse)?;
     let body_root = body_tree.root_node();
 
-    let mut declarations = extract_declarations(body_root, body_text, skip_regions);
+    let mut declarations = extract_declarations(
+        body_root,
+        body_text,
+        skip_regions,
+        &options.additional_virtual_methods,
+    );
 
     if declarations.is_empty() {
         return Ok(class_text.to_string());
     }
 
-    sort_declarations(&mut declarations);
+    sort_declarations(&mut declarations, options);
 
     // Recursively handle nested inner classes
     for decl in &mut declarations {
         if decl.kind == MemberKind::InnerClass {
-            decl.text = reorder_inner_class(&decl.text, skip_regions, _depth + 1)?;
+            decl.text = reorder_inner_class(&decl.text, skip_regions, options, _depth + 1)?;
         }
     }
 
@@ -745,75 +807,6 @@ fn reorder_inner_class(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_member_kind_ordering() {
-        assert!(MemberKind::Tool < MemberKind::ClassName);
-        assert!(MemberKind::ClassName < MemberKind::Extends);
-        assert!(MemberKind::Extends < MemberKind::Signal);
-        assert!(MemberKind::Signal < MemberKind::Enum);
-        assert!(MemberKind::Enum < MemberKind::Const);
-        assert!(MemberKind::Const < MemberKind::StaticVar);
-        assert!(MemberKind::StaticVar < MemberKind::ExportVar);
-        assert!(MemberKind::ExportVar < MemberKind::Var);
-        assert!(MemberKind::Var < MemberKind::OnreadyVar);
-        assert!(MemberKind::OnreadyVar < MemberKind::StaticInit);
-        assert!(MemberKind::StaticInit < MemberKind::StaticMethod);
-        assert!(MemberKind::StaticMethod < MemberKind::VirtualInit);
-        assert!(MemberKind::VirtualInit < MemberKind::VirtualEnterTree);
-        assert!(MemberKind::VirtualEnterTree < MemberKind::VirtualReady);
-        assert!(MemberKind::VirtualReady < MemberKind::VirtualProcess);
-        assert!(MemberKind::VirtualProcess < MemberKind::VirtualPhysicsProcess);
-        assert!(MemberKind::VirtualPhysicsProcess < MemberKind::VirtualOther);
-        assert!(MemberKind::VirtualOther < MemberKind::OverriddenCustomMethod);
-        assert!(MemberKind::OverriddenCustomMethod < MemberKind::Method);
-        assert!(MemberKind::Method < MemberKind::InnerClass);
-    }
-
-    #[test]
-    fn test_is_export_annotation() {
-        assert!(is_export_annotation("export"));
-        assert!(is_export_annotation("export_range"));
-        assert!(is_export_annotation("export_enum"));
-        assert!(!is_export_annotation("onready"));
-        assert!(!is_export_annotation("tool"));
-    }
-
-    #[test]
-    fn test_classify_virtual_method() {
-        assert_eq!(classify_virtual_method("_init"), MemberKind::VirtualInit);
-        assert_eq!(classify_virtual_method("_ready"), MemberKind::VirtualReady);
-        assert_eq!(
-            classify_virtual_method("_enter_tree"),
-            MemberKind::VirtualEnterTree
-        );
-        assert_eq!(
-            classify_virtual_method("_process"),
-            MemberKind::VirtualProcess
-        );
-        assert_eq!(
-            classify_virtual_method("_physics_process"),
-            MemberKind::VirtualPhysicsProcess
-        );
-        assert_eq!(
-            classify_virtual_method("_exit_tree"),
-            MemberKind::VirtualOther
-        );
-        assert_eq!(
-            classify_virtual_method("_custom"),
-            MemberKind::OverriddenCustomMethod
-        );
-        assert_eq!(classify_virtual_method("foo"), MemberKind::Method);
-    }
-
-    #[test]
-    fn test_is_standalone_annotation() {
-        assert!(is_standalone_annotation("tool"));
-        assert!(is_standalone_annotation("icon"));
-        assert!(is_standalone_annotation("static_unload"));
-        assert!(!is_standalone_annotation("export"));
-        assert!(!is_standalone_annotation("onready"));
-    }
-
     #[test]
     fn test_debug_multiline_export_category() {
         let source = r#"extends ActionProperties
@@ -48,6 +48,13 @@ impl SkipRegions {
             .any(|(start, end)| line >= *start && line <= *end)
     }
 
+    /// Check if any line in `start..=end` (1-indexed) is in a skip region.
+    pub fn overlaps(&self, start: usize, end: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|(skip_start, skip_end)| start <= *skip_end && end >= *skip_start)
+    }
+
     /// Check if empty (no skip regions).
     pub fn is_empty(&self) -> bool {
         self.ranges.is_empty()
@@ -88,6 +95,17 @@ mod tests {
         assert!(regions.is_skipped(4));
     }
 
+    #[test]
+    fn test_overlaps() {
+        let source = "a\nb\n# fmt: off\nc\n# fmt: on\nd";
+        let regions = SkipRegions::parse(source);
+        assert!(!regions.overlaps(1, 2));
+        assert!(regions.overlaps(2, 4));
+        assert!(regions.overlaps(4, 4));
+        assert!(regions.overlaps(1, 6));
+        assert!(!regions.overlaps(6, 6));
+    }
+
     #[test]
     fn test_multiple_skip_regions() {
         let source = "# fmt: off\na\n# fmt: on\nb\n# fmt: off\nc\n# fmt: on";
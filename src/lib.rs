@@ -4,5 +4,8 @@ pub mod lint;
 pub mod parser;
 pub mod rules;
 
-pub use format::{run_formatter, FormatError, FormatOptions, IndentStyle};
+pub use format::{
+    run_formatter, run_formatter_mapped, FormatError, FormatOptions, FormatOptionsBuilder,
+    IndentStyle,
+};
 pub use lint::{run_linter, Diagnostic, LintContext, Rule, Severity};
@@ -13,6 +13,16 @@ pub struct Diagnostic {
     pub end_line: Option<usize>,
     pub end_column: Option<usize>,
     pub suggestion: Option<String>,
+    pub fix: Option<Fix>,
+}
+
+/// A source edit that resolves a diagnostic, applied by `gdlint lint --fix`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    /// Byte range in the source to replace.
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub replacement: String,
 }
 
 impl Diagnostic {
@@ -27,6 +37,7 @@ impl Diagnostic {
             end_line: None,
             end_column: None,
             suggestion: None,
+            fix: None,
         }
     }
 
@@ -51,6 +62,11 @@ impl Diagnostic {
         self.suggestion = Some(suggestion.into());
         self
     }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
 }
 
 impl std::fmt::Display for Diagnostic {
@@ -59,6 +75,7 @@ impl std::fmt::Display for Diagnostic {
             Severity::Error => "error",
             Severity::Warning => "warning",
             Severity::Info => "info",
+            Severity::Hint => "hint",
         };
         write!(
             f,
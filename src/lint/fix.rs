@@ -0,0 +1,22 @@
+use crate::lint::Diagnostic;
+
+/// Apply the fixes attached to `diagnostics` to `source`.
+///
+/// Fixes are applied back to front so earlier byte offsets stay valid. A fix
+/// that overlaps one already applied is skipped; running the linter again
+/// will report it with fresh offsets.
+pub fn apply_fixes(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut fixes: Vec<_> = diagnostics.iter().filter_map(|d| d.fix.as_ref()).collect();
+    fixes.sort_by_key(|fix| std::cmp::Reverse(fix.start_byte));
+
+    let mut result = source.to_string();
+    let mut applied_start = usize::MAX;
+    for fix in fixes {
+        if fix.end_byte > applied_start || fix.end_byte > source.len() {
+            continue;
+        }
+        result.replace_range(fix.start_byte..fix.end_byte, &fix.replacement);
+        applied_start = fix.start_byte;
+    }
+    result
+}
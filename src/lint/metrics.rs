@@ -0,0 +1,92 @@
+use serde::Serialize;
+use tree_sitter::Node;
+
+use crate::parser::parse;
+
+/// Code-size and complexity metrics for a single file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FileMetrics {
+    /// Number of source lines.
+    pub lines: usize,
+    /// Number of functions, including methods of inner classes.
+    pub functions: usize,
+    /// Number of functions whose name doesn't start with an underscore.
+    pub public_methods: usize,
+    /// Deepest nesting of `if`/`for`/`while`/`match` blocks inside a function.
+    pub max_nesting_depth: usize,
+    /// Average function length in lines (0 when there are no functions).
+    pub average_function_length: f64,
+}
+
+/// Compute metrics for a GDScript source file.
+pub fn compute_metrics(source: &str) -> Result<FileMetrics, String> {
+    let tree = parse(source)?;
+    let mut metrics = FileMetrics {
+        lines: source.lines().count(),
+        ..Default::default()
+    };
+
+    let mut function_lines = 0;
+    collect_functions(tree.root_node(), &mut |func| {
+        metrics.functions += 1;
+        function_lines += func.end_position().row - func.start_position().row + 1;
+
+        // `_init` has no name field and is never public
+        let is_public = func
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+            .is_some_and(|name| !name.starts_with('_'));
+        if is_public {
+            metrics.public_methods += 1;
+        }
+
+        if let Some(body) = func.child_by_field_name("body") {
+            metrics.max_nesting_depth = metrics.max_nesting_depth.max(nesting_depth(body));
+        }
+    });
+
+    if metrics.functions > 0 {
+        metrics.average_function_length = function_lines as f64 / metrics.functions as f64;
+    }
+
+    Ok(metrics)
+}
+
+/// Visit every function, `_init` (a `constructor_definition`) included.
+fn collect_functions<'a>(node: Node<'a>, visit: &mut impl FnMut(Node<'a>)) {
+    if is_function(node) {
+        visit(node);
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_functions(child, visit);
+    }
+}
+
+fn is_function(node: Node<'_>) -> bool {
+    matches!(
+        node.kind(),
+        "function_definition" | "constructor_definition"
+    )
+}
+
+/// Deepest chain of nested control-flow blocks under `node`.
+fn nesting_depth(node: Node<'_>) -> usize {
+    let mut deepest = 0;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        // Nested functions are measured on their own
+        if is_function(child) {
+            continue;
+        }
+        let own = usize::from(matches!(
+            child.kind(),
+            "if_statement" | "for_statement" | "while_statement" | "match_statement"
+        ));
+        deepest = deepest.max(own + nesting_depth(child));
+    }
+
+    deepest
+}
@@ -1,11 +1,21 @@
 mod context;
 mod diagnostic;
+mod fix;
+mod metrics;
+mod profile;
+mod project;
 mod rule;
 mod runner;
 mod suppression;
 
 pub use context::LintContext;
-pub use diagnostic::Diagnostic;
+pub use diagnostic::{Diagnostic, Fix};
+pub use fix::apply_fixes;
+pub use metrics::{compute_metrics, FileMetrics};
+pub use profile::RuleProfile;
+pub use project::{
+    class_name_collisions, find_class_name, ClassNameDeclaration, CLASS_NAME_COLLISION_RULE_ID,
+};
 pub use rule::{Rule, RuleCategory, RuleMetadata, Severity};
-pub use runner::run_linter;
+pub use runner::{run_linter, run_linter_profiled, PARSE_ERROR_RULE_ID};
 pub use suppression::Suppressions;
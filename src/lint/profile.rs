@@ -0,0 +1,29 @@
+//! Per-rule timing collected by `gdlint lint --profile`.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Time spent in each rule's checks, accumulated across files.
+#[derive(Debug, Default)]
+pub struct RuleProfile {
+    totals: HashMap<&'static str, Duration>,
+}
+
+impl RuleProfile {
+    /// Add `elapsed` to the total for `rule_id`.
+    pub fn record(&mut self, rule_id: &'static str, elapsed: Duration) {
+        *self.totals.entry(rule_id).or_default() += elapsed;
+    }
+
+    /// Rule ids with their total time, slowest first.
+    pub fn sorted(&self) -> Vec<(&'static str, Duration)> {
+        let mut entries: Vec<_> = self.totals.iter().map(|(id, d)| (*id, *d)).collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        entries
+    }
+
+    /// Time spent in all rules together.
+    pub fn total(&self) -> Duration {
+        self.totals.values().sum()
+    }
+}
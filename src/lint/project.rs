@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::lint::{Diagnostic, RuleCategory, Severity};
+use crate::parser::parse;
+
+/// Rule id of the project-wide check for `class_name` declared in several scripts.
+pub const CLASS_NAME_COLLISION_RULE_ID: &str = "no-global-class-name-collision";
+
+/// A top-level `class_name` declaration in a linted script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassNameDeclaration {
+    pub name: String,
+    pub file_path: PathBuf,
+    /// 1-based position of the class name.
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Find the script's top-level `class_name`, if it declares one.
+pub fn find_class_name(
+    source: &str,
+    file_path: &Path,
+) -> Result<Option<ClassNameDeclaration>, String> {
+    let tree = parse(source)?;
+    let root = tree.root_node();
+
+    let mut cursor = root.walk();
+    let declaration = root
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "class_name_statement")
+        .find_map(|statement| statement.child_by_field_name("name"))
+        .and_then(|name| {
+            let text = name.utf8_text(source.as_bytes()).ok()?;
+            Some(ClassNameDeclaration {
+                name: text.to_string(),
+                file_path: file_path.to_path_buf(),
+                line: name.start_position().row + 1,
+                column: name.start_position().column + 1,
+            })
+        });
+
+    Ok(declaration)
+}
+
+/// Report every `class_name` declared by more than one of `declarations`, once per
+/// conflicting file, naming the other files. Only the given files are considered.
+pub fn class_name_collisions(
+    declarations: &[ClassNameDeclaration],
+    config: &Config,
+) -> Vec<Diagnostic> {
+    if !config.is_rule_enabled_in(CLASS_NAME_COLLISION_RULE_ID, RuleCategory::Basic, true) {
+        return Vec::new();
+    }
+    let severity = config.get_rule_severity(CLASS_NAME_COLLISION_RULE_ID, Severity::Error);
+
+    let mut by_name: BTreeMap<&str, Vec<&ClassNameDeclaration>> = BTreeMap::new();
+    for declaration in declarations {
+        by_name
+            .entry(declaration.name.as_str())
+            .or_default()
+            .push(declaration);
+    }
+
+    let mut diagnostics = Vec::new();
+    for (name, group) in by_name.into_iter().filter(|(_, group)| group.len() > 1) {
+        for declaration in &group {
+            let others: Vec<String> = group
+                .iter()
+                .filter(|other| other.file_path != declaration.file_path)
+                .map(|other| format!("{}:{}", other.file_path.display(), other.line))
+                .collect();
+            if others.is_empty() {
+                continue;
+            }
+            diagnostics.push(
+                Diagnostic::new(
+                    CLASS_NAME_COLLISION_RULE_ID,
+                    severity,
+                    format!(
+                        "class_name '{}' is also declared in {}",
+                        name,
+                        others.join(", ")
+                    ),
+                )
+                .with_location(declaration.line, declaration.column)
+                .with_file(&declaration.file_path),
+            );
+        }
+    }
+
+    diagnostics
+}
@@ -4,13 +4,35 @@ use tree_sitter::Node;
 use crate::config::RuleConfig;
 use crate::lint::LintContext;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+/// Ordered from most to least severe, so `Severity::Error < Severity::Warning`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
     #[default]
     Warning,
     Info,
+    /// Shown faded by editors; never fails a run, even with `--warnings-as-errors`.
+    Hint,
+}
+
+impl Severity {
+    /// Whether a diagnostic of this severity fails a run that fails on `threshold`
+    /// and anything more severe. Hints never fail a run.
+    pub fn fails_run(self, threshold: Severity) -> bool {
+        self != Severity::Hint && self <= threshold
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+            Severity::Hint => write!(f, "hint"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +42,7 @@ pub enum RuleCategory {
     Basic,
     Design,
     Style,
+    Custom,
 }
 
 impl std::fmt::Display for RuleCategory {
@@ -30,6 +53,7 @@ impl std::fmt::Display for RuleCategory {
             RuleCategory::Basic => write!(f, "basic"),
             RuleCategory::Design => write!(f, "design"),
             RuleCategory::Style => write!(f, "style"),
+            RuleCategory::Custom => write!(f, "custom"),
         }
     }
 }
@@ -56,6 +80,11 @@ pub trait Rule: Send + Sync {
 
     fn check_file_end(&self, _ctx: &mut LintContext<'_>) {}
 
+    /// Opinionated rules return false and only run once enabled in the config.
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
     fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
         Ok(())
     }
@@ -1,32 +1,172 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use tree_sitter::TreeCursor;
 
 use crate::config::Config;
-use crate::lint::{Diagnostic, LintContext, Rule};
+use crate::lint::{Diagnostic, LintContext, Rule, RuleProfile, Severity};
 use crate::parser::parse;
 
+/// Rule id of the diagnostic reported when a file can't be parsed at all.
+pub const PARSE_ERROR_RULE_ID: &str = "parse-error";
+
+/// Lint `source` with `rules`.
+///
+/// A file that fails to parse yields a single `parse-error` diagnostic instead of
+/// an `Err`, so it shows up in every output format and fails the run.
 pub fn run_linter(
     source: &str,
     file_path: &Path,
     rules: &[Box<dyn Rule>],
     config: &Config,
 ) -> Result<Vec<Diagnostic>, String> {
-    let tree = parse(source)?;
+    run_linter_profiled(source, file_path, rules, config, None)
+}
+
+/// Like `run_linter`, but when `profile` is given, also times every rule check
+/// and adds it to the profile. Without a profile no timing is done.
+pub fn run_linter_profiled(
+    source: &str,
+    file_path: &Path,
+    rules: &[Box<dyn Rule>],
+    config: &Config,
+    profile: Option<&mut RuleProfile>,
+) -> Result<Vec<Diagnostic>, String> {
+    let tree = match parse(source) {
+        Ok(tree) => tree,
+        Err(e) => {
+            let diagnostic = Diagnostic::new(
+                PARSE_ERROR_RULE_ID,
+                Severity::Error,
+                format!("Failed to parse file: {}", e),
+            )
+            .with_location(1, 1)
+            .with_file(file_path);
+            return Ok(vec![diagnostic]);
+        }
+    };
     let mut ctx = LintContext::new(source, &tree, file_path, config);
+    let mut timings = profile.is_some().then(|| vec![Duration::ZERO; rules.len()]);
 
-    for rule in rules {
-        rule.check_file_start(&mut ctx);
+    for (idx, rule) in rules.iter().enumerate() {
+        timed(timing_for(&mut timings, idx), || {
+            rule.check_file_start(&mut ctx)
+        });
     }
 
     let interested_kinds = build_interest_map(rules);
-    traverse_and_check(&tree.root_node(), &mut ctx, rules, &interested_kinds);
+    traverse_and_check(
+        &tree.root_node(),
+        &mut ctx,
+        rules,
+        &interested_kinds,
+        &mut timings,
+    );
+
+    for (idx, rule) in rules.iter().enumerate() {
+        timed(timing_for(&mut timings, idx), || {
+            rule.check_file_end(&mut ctx)
+        });
+    }
+
+    if let (Some(profile), Some(timings)) = (profile, timings) {
+        for (rule, elapsed) in rules.iter().zip(timings) {
+            profile.record(rule.meta().id, elapsed);
+        }
+    }
+
+    let diagnostics = ctx.into_diagnostics();
+    if config.dedupe_diagnostics {
+        Ok(dedupe(diagnostics))
+    } else {
+        Ok(diagnostics)
+    }
+}
+
+/// Merge diagnostics that repeat an earlier one at the same position with a
+/// near-identical message (the same words, ignoring case and punctuation), as
+/// overlapping rules can report. The most severe is kept, in the place of the first,
+/// and keeps any suggestion or fix the others carried. When the merged diagnostics
+/// come from different rules, the message names the other rules so none is hidden.
+fn dedupe(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    // Each kept diagnostic with the ids of every rule merged into it
+    let mut kept: Vec<(Diagnostic, Vec<String>)> = Vec::with_capacity(diagnostics.len());
+    let mut seen: HashMap<(usize, usize, String), usize> = HashMap::new();
 
-    for rule in rules {
-        rule.check_file_end(&mut ctx);
+    for mut diagnostic in diagnostics {
+        let key = (
+            diagnostic.line,
+            diagnostic.column,
+            message_words(&diagnostic.message),
+        );
+        match seen.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(kept.len());
+                let rule_ids = vec![diagnostic.rule_id.clone()];
+                kept.push((diagnostic, rule_ids));
+            }
+            Entry::Occupied(entry) => {
+                let (existing, rule_ids) = &mut kept[*entry.get()];
+                if !rule_ids.contains(&diagnostic.rule_id) {
+                    rule_ids.push(diagnostic.rule_id.clone());
+                }
+                if diagnostic.severity < existing.severity {
+                    std::mem::swap(existing, &mut diagnostic);
+                }
+                existing.suggestion = existing.suggestion.take().or(diagnostic.suggestion);
+                existing.fix = existing.fix.take().or(diagnostic.fix);
+            }
+        }
     }
 
-    Ok(ctx.into_diagnostics())
+    kept.into_iter()
+        .map(|(mut diagnostic, rule_ids)| {
+            let others: Vec<_> = rule_ids
+                .iter()
+                .filter(|id| **id != diagnostic.rule_id)
+                .map(String::as_str)
+                .collect();
+            if !others.is_empty() {
+                diagnostic.message = format!(
+                    "{} (also reported by {})",
+                    diagnostic.message,
+                    others.join(", ")
+                );
+            }
+            diagnostic
+        })
+        .collect()
+}
+
+/// `message` lowercased, with punctuation dropped and words joined by single spaces.
+fn message_words(message: &str) -> String {
+    message
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Per-rule durations for the current file, indexed like `rules`; `None` when not profiling.
+type Timings = Option<Vec<Duration>>;
+
+fn timing_for(timings: &mut Timings, idx: usize) -> Option<&mut Duration> {
+    timings.as_mut().map(|t| &mut t[idx])
+}
+
+/// Run `check`, adding its duration to `timing` when profiling.
+fn timed(timing: Option<&mut Duration>, check: impl FnOnce()) {
+    match timing {
+        Some(total) => {
+            let start = Instant::now();
+            check();
+            *total += start.elapsed();
+        }
+        None => check(),
+    }
 }
 
 fn build_interest_map(rules: &[Box<dyn Rule>]) -> Vec<(usize, Option<&'static [&'static str]>)> {
@@ -42,9 +182,10 @@ fn traverse_and_check(
     ctx: &mut LintContext<'_>,
     rules: &[Box<dyn Rule>],
     interest_map: &[(usize, Option<&'static [&'static str]>)],
+    timings: &mut Timings,
 ) {
     let mut cursor = root.walk();
-    traverse_recursive(&mut cursor, ctx, rules, interest_map);
+    traverse_recursive(&mut cursor, ctx, rules, interest_map, timings);
 }
 
 fn traverse_recursive(
@@ -52,6 +193,7 @@ fn traverse_recursive(
     ctx: &mut LintContext<'_>,
     rules: &[Box<dyn Rule>],
     interest_map: &[(usize, Option<&'static [&'static str]>)],
+    timings: &mut Timings,
 ) {
     let node = cursor.node();
     let kind = node.kind();
@@ -63,13 +205,15 @@ fn traverse_recursive(
         };
 
         if should_check {
-            rules[*idx].check_node(node, ctx);
+            timed(timing_for(timings, *idx), || {
+                rules[*idx].check_node(node, ctx)
+            });
         }
     }
 
     if cursor.goto_first_child() {
         loop {
-            traverse_recursive(cursor, ctx, rules, interest_map);
+            traverse_recursive(cursor, ctx, rules, interest_map, timings);
             if !cursor.goto_next_sibling() {
                 break;
             }
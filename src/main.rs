@@ -1,13 +1,20 @@
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use clap::{Parser, Subcommand};
 use ignore::WalkBuilder;
 use miette::{miette, IntoDiagnostic, Result};
 
-use gdtools::config::{load_config, Config};
-use gdtools::lint::{run_linter, Diagnostic, Rule, Severity};
-use gdtools::rules::all_rules;
+use gdtools::config::{load_config_with_ruleset, ruleset_source, Config};
+use gdtools::format::{reorder_source_with_options, run_formatter, unified_diff, FormatOptions};
+use gdtools::lint::{
+    apply_fixes, class_name_collisions, compute_metrics, find_class_name, run_linter_profiled,
+    Diagnostic, FileMetrics, Rule, RuleProfile, Severity,
+};
+use gdtools::rules::docs::long_description;
+use gdtools::rules::{all_rules, regex_rules};
 
 #[derive(Parser)]
 #[command(
@@ -21,6 +28,11 @@ struct Cli {
 
     #[arg(global = true, short, long)]
     config: Option<PathBuf>,
+
+    /// Built-in rule preset to merge the config over: minimal, recommended, strict or
+    /// godot-official (overrides `ruleset` in the config)
+    #[arg(global = true, long)]
+    ruleset: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -36,8 +48,72 @@ enum Command {
         #[arg(short, long)]
         quiet: bool,
 
+        /// Same as --fail-on warning
         #[arg(short = 'w', long)]
         warnings_as_errors: bool,
+
+        /// Lowest severity that makes the run exit with an error (hints never do)
+        #[arg(long, value_enum, default_value = "error")]
+        fail_on: FailOn,
+
+        /// Do not print the diagnostic count summary
+        #[arg(long)]
+        no_summary: bool,
+
+        /// Print text diagnostics one per line as `file:line:col: ...` instead of grouped by file
+        #[arg(long)]
+        flat: bool,
+
+        /// Color severities in text output: auto (when stdout is a terminal and NO_COLOR is unset), always or never
+        #[arg(long, value_enum, default_value = "auto")]
+        color: ColorChoice,
+
+        /// Keep running and re-lint files when they change
+        #[arg(long, conflicts_with_all = ["since", "fix", "profile"])]
+        watch: bool,
+
+        /// Write the report to this file instead of stdout (still written with --quiet)
+        #[arg(short, long)]
+        output_file: Option<PathBuf>,
+
+        /// Only lint files matching this gitignore-style glob, relative to each walked directory
+        /// (repeatable; adds to `include`)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Only lint files changed since this git ref, e.g. origin/main (uncommitted changes to tracked files count)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Apply safe automatic fixes in place, then report what remains
+        #[arg(long)]
+        fix: bool,
+
+        /// Print the files that would be linted, one per line, without linting them
+        #[arg(long)]
+        list_files: bool,
+
+        /// Print the time spent in each rule to stderr after the run
+        #[arg(long)]
+        profile: bool,
+    },
+    /// Report per-file code metrics (lines, functions, nesting) without linting
+    Metrics {
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+    /// Show which files reordering class members would change, without writing them
+    /// (`gdformat --reorder` applies it); exits with 1 when any would change
+    Reorder {
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+
+        /// Print a unified diff of each file that would be reordered
+        #[arg(long)]
+        diff: bool,
     },
     /// Check configuration file
     CheckConfig,
@@ -45,13 +121,71 @@ enum Command {
     DumpConfig,
     /// List all available rules
     Rules,
+    /// Show a detailed explanation of a rule
+    Explain {
+        /// Rule id, e.g. load-constant-name
+        rule_id: String,
+    },
+}
+
+/// How the text format lays out diagnostics.
+#[derive(Clone, Copy)]
+struct TextStyle {
+    /// One `file:line:col: ...` line per diagnostic instead of grouped by file.
+    flat: bool,
+    /// Color the severity with ANSI escapes.
+    color: bool,
+}
+
+/// `--color` setting for text output.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to color output. `auto` colors only a terminal stdout, and honors
+    /// `NO_COLOR` (https://no-color.org).
+    fn enabled(self, to_stdout: bool) -> bool {
+        use std::io::IsTerminal;
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                to_stdout
+                    && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+                    && io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// Severity threshold for `--fail-on`.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum FailOn {
+    Error,
+    Warning,
+    Info,
+}
+
+impl FailOn {
+    fn severity(self) -> Severity {
+        match self {
+            FailOn::Error => Severity::Error,
+            FailOn::Warning => Severity::Warning,
+            FailOn::Info => Severity::Info,
+        }
+    }
 }
 
-#[derive(Clone, Debug, Default, clap::ValueEnum)]
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
 enum OutputFormat {
     #[default]
     Text,
     Json,
+    Junit,
 }
 
 fn main() -> ExitCode {
@@ -73,28 +207,113 @@ fn main() -> ExitCode {
 fn run() -> Result<bool> {
     let cli = Cli::parse();
 
-    let config = load_config(cli.config.as_deref()).map_err(|e| miette!(e))?;
+    let mut config = load_config_with_ruleset(cli.config.as_deref(), cli.ruleset.as_deref())
+        .map_err(|e| miette!(e))?;
 
     match cli.command.unwrap_or(Command::Lint {
         paths: vec![PathBuf::from(".")],
         format: OutputFormat::Text,
         quiet: false,
         warnings_as_errors: false,
+        fail_on: FailOn::Error,
+        no_summary: false,
+        flat: false,
+        color: ColorChoice::Auto,
+        watch: false,
+        output_file: None,
+        include: Vec::new(),
+        since: None,
+        fix: false,
+        list_files: false,
+        profile: false,
     }) {
         Command::Lint {
             paths,
             format,
             quiet,
             warnings_as_errors,
+            fail_on,
+            no_summary,
+            flat,
+            color,
+            watch,
+            output_file,
+            include,
+            since,
+            fix,
+            list_files,
+            profile,
         } => {
-            let has_errors = run_lint(&paths, &config, format, quiet, warnings_as_errors)?;
-            Ok(has_errors)
+            config.include.extend(include);
+            let changed = since.as_deref().map(changed_files_since).transpose()?;
+            if list_files {
+                for file in collect_files(&paths, &config)? {
+                    if is_changed(&file, changed.as_ref()) {
+                        println!("{}", file.display());
+                    }
+                }
+                return Ok(false);
+            }
+            let text = TextStyle {
+                flat,
+                color: color.enabled(output_file.is_none()),
+            };
+            let report = Report {
+                format,
+                quiet,
+                no_summary,
+                text,
+                output_file,
+            };
+            let mut fail_on = fail_on.severity();
+            if warnings_as_errors {
+                fail_on = fail_on.max(Severity::Warning);
+            }
+            if watch {
+                return watch_and_lint(&paths, &config, &report);
+            }
+            run_lint(
+                &paths,
+                &config,
+                &report,
+                fail_on,
+                changed.as_ref(),
+                fix,
+                profile,
+            )
         }
-        Command::CheckConfig => {
-            println!("Configuration is valid");
+        Command::Metrics { paths, format } => {
+            run_metrics(&paths, &config, format)?;
             Ok(false)
         }
+        Command::Reorder { paths, diff } => run_reorder(&paths, &config, diff),
+        Command::CheckConfig => {
+            let rules = all_rules();
+            let metas: Vec<_> = rules.iter().map(|rule| rule.meta().clone()).collect();
+            let mut problems = config.validate(&metas).err().unwrap_or_default();
+            // Rule options are only understood by the rules themselves
+            for mut rule in rules {
+                if let Some(rule_config) = config.get_rule_config(rule.meta().id) {
+                    if let Err(e) = rule.configure(rule_config) {
+                        problems.push(e);
+                    }
+                }
+            }
+            if problems.is_empty() {
+                println!("Configuration is valid");
+                return Ok(false);
+            }
+            for problem in &problems {
+                eprintln!("{}", problem);
+            }
+            Ok(true)
+        }
         Command::DumpConfig => {
+            // With --ruleset, print the preset itself as a starting point
+            if let Some(source) = cli.ruleset.as_deref().and_then(ruleset_source) {
+                print!("{}", source);
+                return Ok(false);
+            }
             let default = Config::default();
             let toml = toml::to_string_pretty(&default).into_diagnostic()?;
             println!("{}", toml);
@@ -104,6 +323,10 @@ fn run() -> Result<bool> {
             list_rules();
             Ok(false)
         }
+        Command::Explain { rule_id } => {
+            explain_rule(&rule_id)?;
+            Ok(false)
+        }
     }
 }
 
@@ -118,7 +341,7 @@ fn list_rules() {
         by_category
             .entry(meta.category.to_string())
             .or_default()
-            .push(meta);
+            .push((meta, rule.enabled_by_default()));
     }
 
     let mut categories: Vec<_> = by_category.keys().cloned().collect();
@@ -127,54 +350,184 @@ fn list_rules() {
     for category in categories {
         println!("{}:", category.to_uppercase());
         let mut rules = by_category.remove(&category).unwrap();
-        rules.sort_by_key(|m| m.id);
-        for meta in rules {
+        rules.sort_by_key(|(m, _)| m.id);
+        for (meta, enabled_by_default) in rules {
             let severity = match meta.default_severity {
                 Severity::Error => "error",
                 Severity::Warning => "warning",
                 Severity::Info => "info",
+                Severity::Hint => "hint",
             };
-            println!("  {:<30} [{}] {}", meta.id, severity, meta.description);
+            let off = if enabled_by_default {
+                ""
+            } else {
+                " (off by default)"
+            };
+            println!(
+                "  {:<30} [{}] {}{}",
+                meta.id, severity, meta.description, off
+            );
         }
         println!();
     }
 }
 
+fn explain_rule(rule_id: &str) -> Result<()> {
+    let rules = all_rules();
+    let rule = rules
+        .iter()
+        .find(|r| r.meta().id == rule_id)
+        .ok_or_else(|| {
+            miette!(
+                "Unknown rule \"{}\" (run `gdlint rules` to list rules)",
+                rule_id
+            )
+        })?;
+    let meta = rule.meta();
+
+    println!("{} ({})\n", meta.id, meta.name);
+    println!("Category:         {}", meta.category);
+    println!("Default severity: {}", meta.default_severity);
+    if !rule.enabled_by_default() {
+        println!("Enabled:          off by default");
+    }
+    println!("\n{}", meta.description);
+    if let Some(long_description) = long_description(meta.id) {
+        println!("\n{}", long_description);
+    }
+
+    Ok(())
+}
+
+/// Where and how a lint run reports its diagnostics.
+struct Report {
+    format: OutputFormat,
+    quiet: bool,
+    no_summary: bool,
+    text: TextStyle,
+    output_file: Option<PathBuf>,
+}
+
+impl Report {
+    /// Write the diagnostics to the output file, or to stdout unless quiet.
+    fn emit(&self, diagnostics: &[Diagnostic], files: &[PathBuf], config: &Config) -> Result<()> {
+        // The text summary goes to stderr, so --quiet silences it; other formats embed it
+        let text_summary_silenced = self.quiet && matches!(self.format, OutputFormat::Text);
+        let summary = (!self.no_summary && !text_summary_silenced)
+            .then(|| Summary::from_diagnostics(diagnostics, files.len()));
+
+        match &self.output_file {
+            Some(path) => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent).into_diagnostic()?;
+                }
+                let mut file = std::fs::File::create(path)
+                    .map_err(|e| miette!("Cannot write report to {:?}: {}", path, e))?;
+                output_diagnostics(
+                    &mut file,
+                    diagnostics,
+                    files,
+                    self.format,
+                    self.text,
+                    summary.as_ref(),
+                    config,
+                )
+                .into_diagnostic()
+            }
+            None if !self.quiet => output_diagnostics(
+                &mut io::stdout().lock(),
+                diagnostics,
+                files,
+                self.format,
+                self.text,
+                summary.as_ref(),
+                config,
+            )
+            .into_diagnostic(),
+            None => Ok(()),
+        }
+    }
+}
+
 fn run_lint(
     paths: &[PathBuf],
     config: &Config,
-    format: OutputFormat,
-    quiet: bool,
-    warnings_as_errors: bool,
+    report: &Report,
+    fail_on: Severity,
+    changed: Option<&HashSet<PathBuf>>,
+    fix: bool,
+    profile: bool,
 ) -> Result<bool> {
     let rules = create_rules(config)?;
     let mut all_diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut files: Vec<PathBuf> = Vec::new();
+    let mut profile = profile.then(RuleProfile::default);
 
     for path in paths {
         if path.is_file() {
-            let diagnostics = lint_file(path, &rules, config)?;
+            if !is_changed(path, changed) {
+                continue;
+            }
+            let diagnostics = if fix {
+                fix_file(path, &rules, config, profile.as_mut())?
+            } else {
+                lint_file(path, &rules, config, profile.as_mut())?
+            };
             all_diagnostics.extend(diagnostics);
+            files.push(path.clone());
         } else if path.is_dir() {
-            let diagnostics = lint_directory(path, &rules, config)?;
+            let (diagnostics, dir_files) =
+                lint_directory(path, &rules, config, changed, fix, profile.as_mut())?;
             all_diagnostics.extend(diagnostics);
+            files.extend(dir_files);
         }
     }
 
-    let has_errors = all_diagnostics.iter().any(|d| {
-        d.severity == Severity::Error || (warnings_as_errors && d.severity == Severity::Warning)
-    });
+    all_diagnostics.extend(project_diagnostics(&files, config));
+
+    let has_errors = all_diagnostics
+        .iter()
+        .any(|d| d.severity.fails_run(fail_on));
 
-    if !quiet {
-        output_diagnostics(&all_diagnostics, format);
+    report.emit(&all_diagnostics, &files, config)?;
+    if let Some(profile) = &profile {
+        print_profile(profile);
     }
 
     Ok(has_errors)
 }
 
+/// Checks that need the whole set of linted files, run after each file is linted.
+/// Files that can't be read or parsed were already reported and are skipped.
+fn project_diagnostics(files: &[PathBuf], config: &Config) -> Vec<Diagnostic> {
+    let declarations: Vec<_> = files
+        .iter()
+        .filter_map(|path| {
+            let source = std::fs::read_to_string(path).ok()?;
+            find_class_name(&source, path).ok().flatten()
+        })
+        .collect();
+    class_name_collisions(&declarations, config)
+}
+
+/// Print `--profile` timings to stderr, slowest rule first.
+fn print_profile(profile: &RuleProfile) {
+    let millis = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+    eprintln!("Time per rule:");
+    for (rule_id, elapsed) in profile.sorted() {
+        eprintln!("{:>10.3} ms  {}", millis(elapsed), rule_id);
+    }
+    eprintln!("{:>10.3} ms  total", millis(profile.total()));
+}
+
 fn create_rules(config: &Config) -> Result<Vec<Box<dyn Rule>>> {
     let mut rules = all_rules();
+    rules.extend(regex_rules(config).map_err(|e| miette!(e))?);
 
-    rules.retain(|r| config.is_rule_enabled(r.meta().id));
+    rules.retain(|r| {
+        let meta = r.meta();
+        config.is_rule_enabled_in(meta.id, meta.category, r.enabled_by_default())
+    });
 
     for rule in &mut rules {
         if let Some(rule_config) = config.get_rule_config(rule.meta().id) {
@@ -185,49 +538,499 @@ fn create_rules(config: &Config) -> Result<Vec<Box<dyn Rule>>> {
     Ok(rules)
 }
 
-fn lint_file(path: &PathBuf, rules: &[Box<dyn Rule>], config: &Config) -> Result<Vec<Diagnostic>> {
+fn lint_file(
+    path: &PathBuf,
+    rules: &[Box<dyn Rule>],
+    config: &Config,
+    profile: Option<&mut RuleProfile>,
+) -> Result<Vec<Diagnostic>> {
     let source = std::fs::read_to_string(path).into_diagnostic()?;
-    run_linter(&source, path, rules, config)
+    run_linter_profiled(&source, path, rules, config, profile)
         .map_err(|e| miette!("Parse error in {:?}: {}", path, e))
 }
 
-fn lint_directory(
+/// Lint a file, write back any automatic fixes, and return the diagnostics left after fixing.
+fn fix_file(
     path: &PathBuf,
     rules: &[Box<dyn Rule>],
     config: &Config,
+    mut profile: Option<&mut RuleProfile>,
 ) -> Result<Vec<Diagnostic>> {
+    let source = std::fs::read_to_string(path).into_diagnostic()?;
+    let diagnostics = run_linter_profiled(&source, path, rules, config, profile.as_deref_mut())
+        .map_err(|e| miette!("Parse error in {:?}: {}", path, e))?;
+    if diagnostics.iter().all(|d| d.fix.is_none()) {
+        return Ok(diagnostics);
+    }
+
+    let fixed = apply_fixes(&source, &diagnostics);
+    std::fs::write(path, &fixed).into_diagnostic()?;
+    run_linter_profiled(&fixed, path, rules, config, profile)
+        .map_err(|e| miette!("Parse error in {:?} after fixing: {}", path, e))
+}
+
+fn lint_directory(
+    path: &PathBuf,
+    rules: &[Box<dyn Rule>],
+    config: &Config,
+    changed: Option<&HashSet<PathBuf>>,
+    fix: bool,
+    mut profile: Option<&mut RuleProfile>,
+) -> Result<(Vec<Diagnostic>, Vec<PathBuf>)> {
     let mut all_diagnostics = Vec::new();
+    let mut files = collect_directory_files(path, config)?;
+    files.retain(|file| is_changed(file, changed));
+
+    for file_path in &files {
+        let result = if fix {
+            fix_file(file_path, rules, config, profile.as_deref_mut())
+        } else {
+            lint_file(file_path, rules, config, profile.as_deref_mut())
+        };
+        match result {
+            Ok(diagnostics) => all_diagnostics.extend(diagnostics),
+            Err(e) => eprintln!("{:?}", e),
+        }
+    }
+
+    Ok((all_diagnostics, files))
+}
+
+/// Collect the `.gd` files under a directory, honoring ignore files, `include` and `exclude`.
+fn collect_directory_files(path: &PathBuf, config: &Config) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
 
     let walker = WalkBuilder::new(path).standard_filters(true).build();
+    let include = config.include_matcher(path);
 
     for entry in walker {
         let entry = entry.into_diagnostic()?;
         let file_path = entry.path();
 
-        if file_path.extension().map(|e| e == "gd").unwrap_or(false) {
-            let should_exclude = config.exclude.iter().any(|pattern| {
-                file_path
-                    .to_string_lossy()
-                    .contains(pattern.trim_matches('*'))
-            });
-
-            if !should_exclude {
-                match lint_file(&file_path.to_path_buf(), rules, config) {
-                    Ok(diagnostics) => all_diagnostics.extend(diagnostics),
-                    Err(e) => eprintln!("{:?}", e),
+        if file_path.extension().map(|e| e == "gd").unwrap_or(false)
+            && !include.matched(file_path, false).is_ignore()
+            && !is_excluded(file_path, config)
+        {
+            files.push(file_path.to_path_buf());
+        }
+    }
+
+    Ok(files)
+}
+
+/// Resolve `paths` to the files a lint run would check: files are taken as
+/// given, directories are walked with `collect_directory_files`.
+fn collect_files(paths: &[PathBuf], config: &Config) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_file() {
+            files.push(path.clone());
+        } else if path.is_dir() {
+            files.extend(collect_directory_files(path, config)?);
+        }
+    }
+    Ok(files)
+}
+
+/// Files added, copied, modified or renamed since `git_ref`, counting uncommitted
+/// changes, as canonical paths. Deleted files are left out.
+fn changed_files_since(git_ref: &str) -> Result<HashSet<PathBuf>> {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|e| miette!("--since needs git: {}", e))
+    };
+
+    let toplevel = git(&["rev-parse", "--show-toplevel"])?;
+    if !toplevel.status.success() {
+        return Err(miette!("--since only works inside a git repository"));
+    }
+    let root = PathBuf::from(String::from_utf8_lossy(&toplevel.stdout).trim());
+
+    // -z keeps unusual file names unquoted; the names are relative to the repository root
+    let diff = git(&[
+        "diff",
+        "--name-only",
+        "-z",
+        "--diff-filter=ACMR",
+        git_ref,
+        "--",
+    ])?;
+    if !diff.status.success() {
+        return Err(miette!(
+            "git diff against '{}' failed: {}",
+            git_ref,
+            String::from_utf8_lossy(&diff.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&diff.stdout)
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .filter_map(|name| root.join(name).canonicalize().ok())
+        .collect())
+}
+
+/// Whether `path` is in the `--since` set; every file is when `--since` isn't given.
+fn is_changed(path: &Path, changed: Option<&HashSet<PathBuf>>) -> bool {
+    changed.is_none_or(|changed| {
+        path.canonicalize()
+            .is_ok_and(|path| changed.contains(&path))
+    })
+}
+
+fn is_excluded(path: &Path, config: &Config) -> bool {
+    config
+        .exclude
+        .iter()
+        .any(|pattern| path.to_string_lossy().contains(pattern.trim_matches('*')))
+}
+
+/// Lint `paths`, then re-lint `.gd` files under them whenever they change, until
+/// interrupted. Every report covers all watched files, so `--output-file` and the
+/// summary never shrink to just the files that changed.
+fn watch_and_lint(paths: &[PathBuf], config: &Config, report: &Report) -> Result<bool> {
+    use notify::{RecursiveMode, Watcher};
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    // Rapid saves (editor swap files, format-on-save) arrive as bursts of events
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    let rules = create_rules(config)?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).into_diagnostic()?;
+    for path in paths {
+        watcher
+            .watch(path, RecursiveMode::Recursive)
+            .into_diagnostic()?;
+    }
+
+    // Diagnostics of every watched file, by the path a normal run reports
+    let mut by_file = BTreeMap::new();
+    for path in watched_files(paths, config)?.into_values() {
+        let diagnostics = lint_watched(&path, &rules, config);
+        by_file.insert(path, diagnostics);
+    }
+    emit_watched(&by_file, report, config)?;
+
+    eprintln!("Watching for changes (press Ctrl-C to stop)...");
+
+    // Ctrl-C terminates the process through the default signal handler; the loop
+    // only ends early if the watcher itself goes away.
+    while let Ok(event) = rx.recv() {
+        let mut changed = BTreeSet::new();
+        let mut next = Some(event);
+        while let Some(event) = next {
+            if let Ok(event) = event {
+                if !event.kind.is_access() {
+                    changed.extend(event.paths);
                 }
             }
+            next = rx.recv_timeout(DEBOUNCE).ok();
+        }
+
+        let files = watched_files(paths, config)?;
+        let changed: Vec<_> = changed
+            .into_iter()
+            .filter_map(|p| p.canonicalize().ok())
+            .filter_map(|p| files.get(&p).cloned())
+            .collect();
+        // Deleted or newly excluded files drop out of the report
+        let watched_before = by_file.len();
+        let current: HashSet<&PathBuf> = files.values().collect();
+        by_file.retain(|path, _| current.contains(path));
+        if changed.is_empty() && by_file.len() == watched_before {
+            continue;
+        }
+
+        for path in changed {
+            let diagnostics = lint_watched(&path, &rules, config);
+            by_file.insert(path, diagnostics);
+        }
+
+        // Clear the screen and move the cursor home
+        if report.output_file.is_none() {
+            print!("\x1B[2J\x1B[1;1H");
+        }
+        emit_watched(&by_file, report, config)?;
+    }
+
+    Ok(false)
+}
+
+/// Lint one watched file; a file that can't be read or parsed is reported on
+/// stderr and counts as clean until it changes again, so watching carries on.
+fn lint_watched(path: &PathBuf, rules: &[Box<dyn Rule>], config: &Config) -> Vec<Diagnostic> {
+    lint_file(path, rules, config, None).unwrap_or_else(|e| {
+        eprintln!("{:?}", e);
+        Vec::new()
+    })
+}
+
+/// Report the current diagnostics of every watched file, plus the checks that
+/// span files, as one run.
+fn emit_watched(
+    by_file: &std::collections::BTreeMap<PathBuf, Vec<Diagnostic>>,
+    report: &Report,
+    config: &Config,
+) -> Result<()> {
+    let files: Vec<PathBuf> = by_file.keys().cloned().collect();
+    let mut diagnostics: Vec<Diagnostic> = by_file.values().flatten().cloned().collect();
+    diagnostics.extend(project_diagnostics(&files, config));
+    report.emit(&diagnostics, &files, config)
+}
+
+/// The files a normal lint run over `paths` would check, keyed by canonical path.
+fn watched_files(
+    paths: &[PathBuf],
+    config: &Config,
+) -> Result<std::collections::HashMap<PathBuf, PathBuf>> {
+    let mut files = std::collections::HashMap::new();
+    for path in paths {
+        let found = if path.is_dir() {
+            collect_directory_files(path, config)?
+        } else {
+            vec![path.clone()]
+        };
+        files.extend(
+            found
+                .into_iter()
+                .filter_map(|p| p.canonicalize().ok().map(|canonical| (canonical, p))),
+        );
+    }
+    Ok(files)
+}
+
+/// Report the `.gd` files under `paths` that reordering would change, by name or,
+/// with `diff`, as a unified diff. Files are formatted first, as reordering
+/// expects, so an unformatted file is diffed against its formatted text. Returns
+/// whether any file would change.
+fn run_reorder(paths: &[PathBuf], config: &Config, diff: bool) -> Result<bool> {
+    let mut any_reordered = false;
+    for path in collect_files(paths, config)? {
+        let filename = path.display().to_string();
+        let source = std::fs::read_to_string(&path).into_diagnostic()?;
+        let options = FormatOptions::from_config_for_file(&config.format, &path);
+        let result = run_formatter(&source, &options).and_then(|formatted| {
+            let reordered = reorder_source_with_options(&formatted, &options)?;
+            Ok((formatted, reordered))
+        });
+        let (formatted, reordered) = match result {
+            Ok(pair) => pair,
+            Err(e) => {
+                eprintln!("Error reordering {}: {}", filename, e);
+                continue;
+            }
+        };
+        if formatted == reordered {
+            continue;
+        }
+
+        any_reordered = true;
+        if diff {
+            print!("{}", unified_diff(&filename, &formatted, &reordered));
+        } else {
+            println!("Would reorder: {}", filename);
+        }
+    }
+    Ok(any_reordered)
+}
+
+/// Compute and print metrics for every `.gd` file under `paths`, with totals.
+fn run_metrics(paths: &[PathBuf], config: &Config, format: OutputFormat) -> Result<()> {
+    let files = collect_files(paths, config)?;
+
+    let mut reports = Vec::new();
+    for path in files {
+        let source = std::fs::read_to_string(&path).into_diagnostic()?;
+        match compute_metrics(&source) {
+            Ok(metrics) => reports.push((path, metrics)),
+            Err(e) => eprintln!("Parse error in {:?}: {}", path, e),
+        }
+    }
+
+    let totals = MetricsTotals::from_reports(&reports);
+
+    match format {
+        OutputFormat::Text => {
+            println!(
+                "{:<50} {:>7} {:>9} {:>7} {:>8} {:>11}",
+                "file", "lines", "functions", "public", "nesting", "avg fn len"
+            );
+            for (path, m) in &reports {
+                println!(
+                    "{:<50} {:>7} {:>9} {:>7} {:>8} {:>11.1}",
+                    path.display(),
+                    m.lines,
+                    m.functions,
+                    m.public_methods,
+                    m.max_nesting_depth,
+                    m.average_function_length
+                );
+            }
+            println!(
+                "{:<50} {:>7} {:>9} {:>7} {:>8} {:>11.1}",
+                format!("total ({} files)", totals.files),
+                totals.lines,
+                totals.functions,
+                totals.public_methods,
+                totals.max_nesting_depth,
+                totals.average_function_length
+            );
+        }
+        OutputFormat::Json => {
+            #[derive(serde::Serialize)]
+            struct JsonFileMetrics<'a> {
+                file: &'a str,
+                #[serde(flatten)]
+                metrics: &'a FileMetrics,
+            }
+
+            #[derive(serde::Serialize)]
+            struct JsonOutput<'a> {
+                files: Vec<JsonFileMetrics<'a>>,
+                totals: &'a MetricsTotals,
+            }
+
+            let output = JsonOutput {
+                files: reports
+                    .iter()
+                    .map(|(path, metrics)| JsonFileMetrics {
+                        file: path.to_str().unwrap_or(""),
+                        metrics,
+                    })
+                    .collect(),
+                totals: &totals,
+            };
+
+            if let Ok(json) = serde_json::to_string_pretty(&output) {
+                println!("{}", json);
+            }
+        }
+        OutputFormat::Junit => {
+            return Err(miette!("JUnit output is not supported for metrics"));
         }
     }
 
-    Ok(all_diagnostics)
+    Ok(())
+}
+
+#[derive(serde::Serialize)]
+struct MetricsTotals {
+    files: usize,
+    lines: usize,
+    functions: usize,
+    public_methods: usize,
+    max_nesting_depth: usize,
+    average_function_length: f64,
+}
+
+impl MetricsTotals {
+    fn from_reports(reports: &[(PathBuf, FileMetrics)]) -> Self {
+        let functions: usize = reports.iter().map(|(_, m)| m.functions).sum();
+        let function_lines: f64 = reports
+            .iter()
+            .map(|(_, m)| m.average_function_length * m.functions as f64)
+            .sum();
+        Self {
+            files: reports.len(),
+            lines: reports.iter().map(|(_, m)| m.lines).sum(),
+            functions,
+            public_methods: reports.iter().map(|(_, m)| m.public_methods).sum(),
+            max_nesting_depth: reports
+                .iter()
+                .map(|(_, m)| m.max_nesting_depth)
+                .max()
+                .unwrap_or(0),
+            average_function_length: if functions > 0 {
+                function_lines / functions as f64
+            } else {
+                0.0
+            },
+        }
+    }
 }
 
-fn output_diagnostics(diagnostics: &[Diagnostic], format: OutputFormat) {
+#[derive(serde::Serialize)]
+struct Summary {
+    errors: usize,
+    warnings: usize,
+    info: usize,
+    hints: usize,
+    files: usize,
+}
+
+impl Summary {
+    fn from_diagnostics(diagnostics: &[Diagnostic], files: usize) -> Self {
+        let count = |severity: Severity| {
+            diagnostics
+                .iter()
+                .filter(|d| d.severity == severity)
+                .count()
+        };
+        Self {
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            info: count(Severity::Info),
+            hints: count(Severity::Hint),
+            files,
+        }
+    }
+}
+
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |n: usize, word: &str| {
+            if n == 1 {
+                format!("{} {}", n, word)
+            } else {
+                format!("{} {}s", n, word)
+            }
+        };
+        write!(
+            f,
+            "{}, {}, {} info, {} across {}",
+            plural(self.errors, "error"),
+            plural(self.warnings, "warning"),
+            self.info,
+            plural(self.hints, "hint"),
+            plural(self.files, "file")
+        )
+    }
+}
+
+fn output_diagnostics(
+    out: &mut dyn Write,
+    diagnostics: &[Diagnostic],
+    files: &[PathBuf],
+    format: OutputFormat,
+    text: TextStyle,
+    summary: Option<&Summary>,
+    config: &Config,
+) -> io::Result<()> {
     match format {
         OutputFormat::Text => {
-            for diag in diagnostics {
-                println!("{}", diag);
+            if text.flat {
+                for diag in diagnostics {
+                    writeln!(
+                        out,
+                        "{}:{}:{}: {}: {} ({})",
+                        diag.file_path.display(),
+                        diag.line,
+                        diag.column,
+                        severity_label(diag.severity, text.color),
+                        diag.message,
+                        diag.rule_id
+                    )?;
+                }
+            } else {
+                write_grouped(out, diagnostics, text.color)?;
+            }
+            if let Some(summary) = summary {
+                eprintln!("{}", summary);
             }
         }
         OutputFormat::Json => {
@@ -239,6 +1042,8 @@ fn output_diagnostics(diagnostics: &[Diagnostic], format: OutputFormat) {
                 severity: &'a str,
                 rule: &'a str,
                 message: &'a str,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                help_uri: Option<String>,
             }
 
             let json_diags: Vec<_> = diagnostics
@@ -251,15 +1056,144 @@ fn output_diagnostics(diagnostics: &[Diagnostic], format: OutputFormat) {
                         Severity::Error => "error",
                         Severity::Warning => "warning",
                         Severity::Info => "info",
+                        Severity::Hint => "hint",
                     },
                     rule: &d.rule_id,
                     message: &d.message,
+                    help_uri: config.help_uri(&d.rule_id),
                 })
                 .collect();
 
-            if let Ok(json) = serde_json::to_string_pretty(&json_diags) {
-                println!("{}", json);
+            #[derive(serde::Serialize)]
+            struct JsonOutput<'a> {
+                diagnostics: Vec<JsonDiagnostic<'a>>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                summary: Option<&'a Summary>,
+            }
+
+            let output = JsonOutput {
+                diagnostics: json_diags,
+                summary,
+            };
+
+            if let Ok(json) = serde_json::to_string_pretty(&output) {
+                writeln!(out, "{}", json)?;
             }
         }
+        OutputFormat::Junit => write!(out, "{}", junit_report(diagnostics, files))?,
+    }
+    Ok(())
+}
+
+/// Write text diagnostics under a header line per file, files in path order and
+/// each file's diagnostics by position, with a blank line between files.
+fn write_grouped(out: &mut dyn Write, diagnostics: &[Diagnostic], color: bool) -> io::Result<()> {
+    let mut by_file: std::collections::BTreeMap<&Path, Vec<&Diagnostic>> =
+        std::collections::BTreeMap::new();
+    for diag in diagnostics {
+        by_file.entry(&diag.file_path).or_default().push(diag);
+    }
+
+    for (i, (path, mut diags)) in by_file.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{}", path.display())?;
+        diags.sort_by_key(|d| (d.line, d.column));
+        for diag in diags {
+            writeln!(
+                out,
+                "  {}:{}: {}: {} ({})",
+                diag.line,
+                diag.column,
+                severity_label(diag.severity, color),
+                diag.message,
+                diag.rule_id
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// The severity name, in red, yellow, cyan or dim for hints when `color` is set.
+fn severity_label(severity: Severity, color: bool) -> String {
+    if !color {
+        return severity.to_string();
+    }
+    let code = match severity {
+        Severity::Error => "31",
+        Severity::Warning => "33",
+        Severity::Info => "36",
+        Severity::Hint => "2",
+    };
+    format!("\x1B[{}m{}\x1B[0m", code, severity)
+}
+
+/// Render diagnostics as JUnit XML: one `<testsuite>` per file and one `<testcase>`
+/// per diagnostic. Errors and warnings are failures, info and hint diagnostics are skipped,
+/// and files without diagnostics get a single passing testcase.
+fn junit_report(diagnostics: &[Diagnostic], files: &[PathBuf]) -> String {
+    let mut suites = String::new();
+    let mut total_tests = 0;
+    let mut total_failures = 0;
+    let mut total_skipped = 0;
+
+    for file in files {
+        let file_name = xml_escape(&file.display().to_string());
+        let file_diags: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| &d.file_path == file)
+            .collect();
+
+        let mut cases = String::new();
+        let mut failures = 0;
+        let mut skipped = 0;
+        for d in &file_diags {
+            let name = xml_escape(&format!("{} at {}:{}", d.rule_id, d.line, d.column));
+            let outcome = if matches!(d.severity, Severity::Info | Severity::Hint) {
+                skipped += 1;
+                format!("      <skipped message=\"{}\"/>\n", xml_escape(&d.message))
+            } else {
+                failures += 1;
+                format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    xml_escape(&d.rule_id),
+                    xml_escape(&d.message),
+                    xml_escape(&d.message)
+                )
+            };
+            cases.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\">\n{}    </testcase>\n",
+                name, file_name, outcome
+            ));
+        }
+        if file_diags.is_empty() {
+            cases.push_str(&format!(
+                "    <testcase name=\"gdlint\" classname=\"{}\"/>\n",
+                file_name
+            ));
+        }
+
+        let tests = file_diags.len().max(1);
+        total_tests += tests;
+        total_failures += failures;
+        total_skipped += skipped;
+        suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n{}  </testsuite>\n",
+            file_name, tests, failures, skipped, cases
+        ));
     }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"gdlint\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n{}</testsuites>\n",
+        total_tests, total_failures, total_skipped, suites
+    )
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
 }
@@ -1,15 +1,117 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
-use crate::lint::{LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::rules::design::has_comment;
+use crate::rules::naming::{configure_string_list, contains_call_to, is_class_scope_variable};
+use crate::rules::style::operator_text;
 
 static LOAD_PATTERN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r#"(load|preload)\s*\(\s*["']([^"']+)["']\s*\)"#).unwrap());
 
+static YIELD_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\byield\b").unwrap());
+
+/// Godot 3 `onready var` / `export var` / `export(int) var` at the start of a line.
+static LEGACY_KEYWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(onready|export)\b(?:\s*\([^)]*\))?\s+(?:onready\s+)?var\b").unwrap()
+});
+
+/// Reports the `ERROR` and `MISSING` nodes tree-sitter inserts when it recovers
+/// from malformed source, so broken scripts aren't linted silently.
+#[derive(Debug)]
+pub struct SyntaxErrorRule {
+    meta: RuleMetadata,
+}
+
+impl Default for SyntaxErrorRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "syntax-error",
+                name: "Syntax Error",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Error,
+                description: "Source must be valid GDScript",
+            },
+        }
+    }
+}
+
+/// Longest snippet of unexpected source quoted in a syntax error message.
+const SYNTAX_ERROR_SNIPPET_CHARS: usize = 40;
+
+/// Collect error and missing nodes, without descending into error nodes.
+fn collect_syntax_errors<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if node.is_error() || node.is_missing() {
+        out.push(node);
+        return;
+    }
+    if !node.has_error() {
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_syntax_errors(child, out);
+    }
+}
+
+impl Rule for SyntaxErrorRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let mut errors = Vec::new();
+        collect_syntax_errors(ctx.tree().root_node(), &mut errors);
+        if errors.is_empty() {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let reports: Vec<_> = errors
+            .into_iter()
+            .map(|node| {
+                let message = if node.is_missing() {
+                    format!("Syntax error: missing \"{}\"", node.kind())
+                } else {
+                    let snippet: String = ctx
+                        .node_text(node)
+                        .lines()
+                        .next()
+                        .unwrap_or("")
+                        .trim()
+                        .chars()
+                        .take(SYNTAX_ERROR_SNIPPET_CHARS)
+                        .collect();
+                    if snippet.is_empty() {
+                        "Syntax error".to_string()
+                    } else {
+                        format!("Syntax error: unexpected \"{}\"", snippet)
+                    }
+                };
+                (node, message)
+            })
+            .collect();
+
+        for (node, message) in reports {
+            ctx.report_node(node, self.meta.id, severity, message);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct UnnecessaryPassRule {
     meta: RuleMetadata,
@@ -143,34 +245,97 @@ fn collect_parameters<'a>(func: Node<'a>, ctx: &LintContext<'_>) -> Vec<(String,
     params
 }
 
-fn collect_used_identifiers(func: Node<'_>, ctx: &LintContext<'_>) -> HashSet<String> {
+/// Collect the identifiers the function body reads, ignoring member names
+/// (`obj.name`, `obj.name()`) and uses of locals that shadow a name.
+pub(crate) fn collect_used_identifiers(func: Node<'_>, ctx: &LintContext<'_>) -> HashSet<String> {
     let mut used = HashSet::new();
 
     if let Some(body) = func.child_by_field_name("body") {
-        collect_identifiers_recursive(body, ctx, &mut used);
+        let mut shadows = HashMap::new();
+        collect_shadowing_locals(body, ctx, &mut shadows);
+        collect_identifiers_recursive(body, ctx, &shadows, &mut used);
     }
 
     used
 }
 
+/// Byte ranges in which a local (`var x` or `for x in`) hides an outer name.
+type ShadowRanges = HashMap<String, Vec<std::ops::Range<usize>>>;
+
+fn collect_shadowing_locals(node: Node<'_>, ctx: &LintContext<'_>, shadows: &mut ShadowRanges) {
+    match node.kind() {
+        // `var x = x + 1` still reads the outer `x` in its initializer
+        "variable_statement" => {
+            if let (Some(name), Some(scope)) = (node.child_by_field_name("name"), node.parent()) {
+                shadows
+                    .entry(ctx.node_text(name).to_string())
+                    .or_default()
+                    .push(node.end_byte()..scope.end_byte());
+            }
+        }
+        // `for x in x:` iterates the outer `x`; the loop body sees the loop variable
+        "for_statement" => {
+            if let (Some(left), Some(right)) = (
+                node.child_by_field_name("left"),
+                node.child_by_field_name("right"),
+            ) {
+                shadows
+                    .entry(ctx.node_text(left).to_string())
+                    .or_default()
+                    .push(right.end_byte()..node.end_byte());
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_shadowing_locals(child, ctx, shadows);
+    }
+}
+
+/// Whether an identifier names a member after a `.` rather than a variable.
+fn is_member_name(node: Node<'_>) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        "attribute" => parent.named_child(0) != Some(node),
+        "attribute_call" | "attribute_subscript" => parent.named_child(0) == Some(node),
+        _ => false,
+    }
+}
+
 fn collect_identifiers_recursive(
     node: Node<'_>,
     ctx: &LintContext<'_>,
+    shadows: &ShadowRanges,
     used: &mut HashSet<String>,
 ) {
-    if node.kind() == "identifier" {
-        used.insert(ctx.node_text(node).to_string());
+    if node.kind() == "identifier" && !is_member_name(node) {
+        let name = ctx.node_text(node);
+        let shadowed = shadows
+            .get(name)
+            .is_some_and(|ranges| ranges.iter().any(|r| r.contains(&node.start_byte())));
+        let is_loop_variable = node.parent().is_some_and(|p| {
+            p.kind() == "for_statement" && p.child_by_field_name("left") == Some(node)
+        });
+        if !shadowed && !is_loop_variable {
+            used.insert(name.to_string());
+        }
     }
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        collect_identifiers_recursive(child, ctx, used);
+        collect_identifiers_recursive(child, ctx, shadows, used);
     }
 }
 
 #[derive(Debug)]
 pub struct ComparisonWithItselfRule {
     meta: RuleMetadata,
+    /// Never flag `x != x`, which is sometimes used as a NaN check.
+    allow_ne_self: bool,
 }
 
 impl Default for ComparisonWithItselfRule {
@@ -183,30 +348,43 @@ impl Default for ComparisonWithItselfRule {
                 default_severity: Severity::Warning,
                 description: "Comparing a value with itself is likely a bug",
             },
+            allow_ne_self: false,
         }
     }
 }
 
-/// Check if a binary_operator or comparison_operator node is a comparison.
-/// Uses the AST operator field instead of text matching.
-fn is_comparison_operator(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
-    // comparison_operator nodes are always comparisons
-    if node.kind() == "comparison_operator" {
-        return true;
-    }
+/// Get the operator of a binary_operator or comparison_operator node, if it is
+/// a comparison. Reads the operator token itself rather than scanning the text.
+fn comparison_operator<'a>(node: Node<'_>, ctx: &'a LintContext<'_>) -> Option<&'a str> {
+    operator_text(node, ctx).filter(|op| matches!(*op, "==" | "!=" | "<" | "<=" | ">" | ">="))
+}
 
-    // For binary_operator, check the operator child
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        // The operator is typically an unnamed node between operands
-        if !child.is_named() {
-            let op = ctx.node_text(child);
-            if matches!(op, "==" | "!=" | "<" | "<=" | ">" | ">=") {
-                return true;
-            }
+/// Best-effort check whether `name` is declared as a float anywhere in the file:
+/// `var x: float`, `var x := 1.0`, `var x = 1.0` or a `float` parameter.
+fn is_declared_float(node: Node<'_>, name: &str, ctx: &LintContext<'_>) -> bool {
+    let declared_name = match node.kind() {
+        "variable_statement" => node.child_by_field_name("name"),
+        "typed_parameter" | "typed_default_parameter" | "default_parameter" => node.named_child(0),
+        _ => None,
+    };
+
+    if declared_name.is_some_and(|n| ctx.node_text(n) == name) {
+        let typed_float = node
+            .child_by_field_name("type")
+            .is_some_and(|t| ctx.node_text(t) == "float");
+        let float_value = node
+            .child_by_field_name("value")
+            .is_some_and(|v| v.kind() == "float");
+        if typed_float || float_value {
+            return true;
         }
     }
-    false
+
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|child| is_declared_float(child, name, ctx));
+    found
 }
 
 impl Rule for ComparisonWithItselfRule {
@@ -219,32 +397,113 @@ impl Rule for ComparisonWithItselfRule {
     }
 
     fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
-        // Check if this is a comparison operation using AST inspection
-        if !is_comparison_operator(node, ctx) {
-            return;
-        }
-
-        let child_count = node.named_child_count();
-        if child_count < 2 {
+        let Some(op) = comparison_operator(node, ctx) else {
             return;
-        }
+        };
+        let is_not_equal = op == "!=";
 
         if let (Some(left), Some(right)) = (node.named_child(0), node.named_child(1)) {
             let left_text = ctx.node_text(left);
             let right_text = ctx.node_text(right);
 
-            if left_text == right_text && !left_text.is_empty() {
-                let severity = ctx
-                    .config()
-                    .get_rule_severity(self.meta.id, self.meta.default_severity);
-                ctx.report_node(
-                    node,
-                    self.meta.id,
-                    severity,
-                    format!("Comparison of \"{}\" with itself", left_text),
-                );
+            if left_text != right_text || left_text.is_empty() {
+                return;
+            }
+
+            // `x != x` is the classic NaN check, which is legitimate for floats
+            if is_not_equal
+                && (self.allow_ne_self
+                    || (left.kind() == "identifier"
+                        && is_declared_float(ctx.tree().root_node(), left_text, ctx)))
+            {
+                return;
             }
+
+            let message = if is_not_equal {
+                format!(
+                    "Comparison of \"{}\" with itself, use is_nan({}) to check for NaN",
+                    left_text, left_text
+                )
+            } else {
+                format!("Comparison of \"{}\" with itself", left_text)
+            };
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            ctx.report_node(node, self.meta.id, severity, message);
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(allow) = config.options.get("allow_ne_self") {
+            self.allow_ne_self = allow
+                .as_bool()
+                .ok_or("comparison-with-itself: 'allow_ne_self' must be a boolean")?;
+        }
+        Ok(())
+    }
+}
+
+/// Flags `/` between two integer literals, which truncates: `5 / 2` is `2`.
+#[derive(Debug)]
+pub struct NoImplicitIntFloatDivisionRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoImplicitIntFloatDivisionRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-implicit-int-float-division",
+                name: "No Implicit Int Float Division",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Dividing two integer literals truncates the result",
+            },
+        }
+    }
+}
+
+impl Rule for NoImplicitIntFloatDivisionRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["binary_operator"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if operator_text(node, ctx) != Some("/") {
+            return;
+        }
+        // Only literal / literal, where the operand types are certain
+        let (Some(left), Some(right)) = (node.named_child(0), node.named_child(1)) else {
+            return;
+        };
+        if left.kind() != "integer" || right.kind() != "integer" {
+            return;
+        }
+
+        let (left, right) = (ctx.node_text(left), ctx.node_text(right));
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let position = node.start_position();
+        let mut diagnostic = Diagnostic::new(
+            self.meta.id,
+            severity,
+            format!(
+                "\"{} / {}\" is integer division, use a float literal if a fractional result is intended",
+                left, right
+            ),
+        )
+        .with_location(position.row + 1, position.column + 1);
+        // `0x10.0` isn't a float, so only decimal literals get a suggestion
+        if left.chars().all(|c| c.is_ascii_digit() || c == '_') {
+            diagnostic = diagnostic.with_suggestion(format!("{}.0 / {}", left, right));
         }
+        ctx.report(diagnostic);
     }
 
     fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
@@ -328,6 +587,119 @@ impl Rule for DuplicatedLoadRule {
     }
 }
 
+/// Marker words that `no-todo-without-owner` checks by default.
+const TODO_KEYWORDS: &[&str] = &["TODO", "FIXME", "HACK"];
+
+/// Default owner format: the keyword followed by a parenthesized name, `TODO(alice):`.
+const TODO_OWNER_PATTERN: &str = r"^\w+\([^)\s][^)]*\)";
+
+/// Build a regex matching any of `keywords` as a whole word.
+fn todo_keyword_regex(keywords: &[String]) -> Regex {
+    let alternatives: Vec<_> = keywords.iter().map(|k| regex::escape(k)).collect();
+    Regex::new(&format!(r"\b({})\b", alternatives.join("|"))).unwrap()
+}
+
+/// Flags TODO-style comments that don't name an owner. Like `DuplicatedLoadRule`
+/// this scans the source text, keeping only keywords inside comment nodes.
+#[derive(Debug)]
+pub struct NoTodoWithoutOwnerRule {
+    meta: RuleMetadata,
+    keywords: Regex,
+    pattern: Regex,
+}
+
+impl Default for NoTodoWithoutOwnerRule {
+    fn default() -> Self {
+        let keywords: Vec<String> = TODO_KEYWORDS.iter().map(|k| k.to_string()).collect();
+        Self {
+            meta: RuleMetadata {
+                id: "no-todo-without-owner",
+                name: "No Todo Without Owner",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "TODO, FIXME and HACK comments should name an owner",
+            },
+            keywords: todo_keyword_regex(&keywords),
+            pattern: Regex::new(TODO_OWNER_PATTERN).unwrap(),
+        }
+    }
+}
+
+impl Rule for NoTodoWithoutOwnerRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let root = ctx.tree().root_node();
+
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+        for (line_idx, line) in ctx.source().split('\n').enumerate() {
+            let keyword = self.keywords.find_iter(line).find(|keyword| {
+                let start = line_start + keyword.start();
+                root.descendant_for_byte_range(start, line_start + keyword.end())
+                    .is_some_and(|node| node.kind() == "comment")
+            });
+            if let Some(keyword) = keyword {
+                if !self.pattern.is_match(&line[keyword.start()..]) {
+                    diagnostics.push(
+                        Diagnostic::new(
+                            self.meta.id,
+                            severity,
+                            format!(
+                                "{} comment has no owner, write it as {}(name): ...",
+                                keyword.as_str(),
+                                keyword.as_str()
+                            ),
+                        )
+                        .with_location(line_idx + 1, keyword.start() + 1),
+                    );
+                }
+            }
+            line_start += line.len() + 1;
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(keywords) = config.options.get("keywords") {
+            let error =
+                || "no-todo-without-owner: 'keywords' must be a list of strings".to_string();
+            let keywords: Vec<String> = keywords
+                .as_array()
+                .ok_or_else(error)?
+                .iter()
+                .map(|k| k.as_str().map(str::to_string).ok_or_else(error))
+                .collect::<Result<_, _>>()?;
+            if keywords.is_empty() {
+                return Err(error());
+            }
+            self.keywords = todo_keyword_regex(&keywords);
+        }
+        if let Some(pattern) = config.options.get("pattern") {
+            let pattern = pattern
+                .as_str()
+                .ok_or("no-todo-without-owner: 'pattern' must be a string")?;
+            self.pattern = Regex::new(pattern)
+                .map_err(|e| format!("no-todo-without-owner: invalid 'pattern': {}", e))?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct ExpressionNotAssignedRule {
     meta: RuleMetadata,
@@ -389,3 +761,2195 @@ impl Rule for ExpressionNotAssignedRule {
         Ok(())
     }
 }
+
+/// Flags a local whose value is overwritten before anything reads it.
+///
+/// Deliberately conservative: only locals declared in the same block are tracked,
+/// and any mention of a name in another statement (including nested blocks)
+/// counts as a read.
+#[derive(Debug)]
+pub struct NoDeadStoreRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoDeadStoreRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-dead-store",
+                name: "No Dead Store",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Values should not be overwritten before they are read",
+            },
+        }
+    }
+}
+
+impl Rule for NoDeadStoreRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["body"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut locals: HashSet<String> = HashSet::new();
+        // Local name -> the store whose value has not been read yet
+        let mut pending: HashMap<String, Node<'_>> = HashMap::new();
+        let mut dead_stores = Vec::new();
+
+        let mut cursor = node.walk();
+        for statement in node.named_children(&mut cursor) {
+            let (target, value, store) = match statement.kind() {
+                "variable_statement" => (
+                    statement.child_by_field_name("name"),
+                    statement.child_by_field_name("value"),
+                    statement,
+                ),
+                "expression_statement" => match statement.named_child(0) {
+                    Some(expr) if expr.kind() == "assignment" => (
+                        expr.child_by_field_name("left")
+                            .filter(|left| left.kind() == "identifier"),
+                        expr.child_by_field_name("right"),
+                        expr,
+                    ),
+                    _ => (None, None, statement),
+                },
+                _ => (None, None, statement),
+            };
+
+            let Some(target) = target else {
+                // Any other statement reads everything it mentions
+                for name in mentioned_names(statement, ctx) {
+                    pending.remove(&name);
+                }
+                continue;
+            };
+
+            // The right-hand side is evaluated before the store
+            if let Some(value) = value {
+                for name in mentioned_names(value, ctx) {
+                    pending.remove(&name);
+                }
+            }
+
+            let name = ctx.node_text(target).to_string();
+            if statement.kind() == "variable_statement" {
+                locals.insert(name.clone());
+            } else if !locals.contains(&name) {
+                continue;
+            }
+            if let Some(previous) = pending.remove(&name) {
+                dead_stores.push((previous, name.clone()));
+            }
+            if value.is_some() {
+                pending.insert(name, store);
+            }
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        for (store, name) in dead_stores {
+            ctx.report_node(
+                store,
+                self.meta.id,
+                severity,
+                format!(
+                    "Value assigned to \"{}\" is overwritten before it is read",
+                    name
+                ),
+            );
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Every variable name mentioned under `node`, ignoring member names.
+fn mentioned_names(node: Node<'_>, ctx: &LintContext<'_>) -> Vec<String> {
+    let mut names = Vec::new();
+    if node.kind() == "identifier" && !is_member_name(node) {
+        names.push(ctx.node_text(node).to_string());
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        names.extend(mentioned_names(child, ctx));
+    }
+    names
+}
+
+/// Flags `return <value>` in a function annotated `-> void`. With
+/// `inconsistent_returns`, also flags bare `return`s in other functions that
+/// return a value elsewhere.
+#[derive(Debug)]
+pub struct NoReturnValueFromVoidFunctionRule {
+    meta: RuleMetadata,
+    inconsistent_returns: bool,
+}
+
+impl Default for NoReturnValueFromVoidFunctionRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-return-value-from-void-function",
+                name: "No Return Value From Void Function",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Error,
+                description: "Functions declared -> void should not return a value",
+            },
+            inconsistent_returns: false,
+        }
+    }
+}
+
+impl Rule for NoReturnValueFromVoidFunctionRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["function_definition"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+        let mut returns = Vec::new();
+        collect_returns(body, &mut returns);
+        let has_value = |ret: &Node<'_>| {
+            let mut cursor = ret.walk();
+            let found = ret
+                .named_children(&mut cursor)
+                .any(|c| c.kind() != "comment");
+            found
+        };
+
+        let is_void = node
+            .child_by_field_name("return_type")
+            .is_some_and(|t| ctx.node_text(t).trim() == "void");
+        if is_void {
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            for ret in returns.iter().filter(|r| has_value(r)) {
+                ctx.report_node(
+                    *ret,
+                    self.meta.id,
+                    severity,
+                    "Function is declared -> void but returns a value".to_string(),
+                );
+            }
+            return;
+        }
+
+        if !self.inconsistent_returns || !returns.iter().any(has_value) {
+            return;
+        }
+        // Less severe than returning from a void function unless configured otherwise
+        let severity = ctx.config().get_rule_severity(self.meta.id, Severity::Info);
+        for ret in returns.iter().filter(|r| !has_value(r)) {
+            ctx.report_node(
+                *ret,
+                self.meta.id,
+                severity,
+                "Bare return in a function that returns a value elsewhere".to_string(),
+            );
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(value) = config.options.get("inconsistent_returns") {
+            self.inconsistent_returns = value.as_bool().ok_or(
+                "no-return-value-from-void-function: 'inconsistent_returns' must be a boolean",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect the `return` statements under `node`, skipping nested functions and lambdas.
+fn collect_returns<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if node.kind() == "return_statement" {
+        out.push(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if !matches!(child.kind(), "function_definition" | "lambda") {
+            collect_returns(child, out);
+        }
+    }
+}
+
+/// Flags `self` inside a `static func`, which has no instance to refer to. Bare
+/// member names would need symbol resolution, so only `self` is checked.
+#[derive(Debug)]
+pub struct NoStaticFuncAccessingInstanceMemberRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoStaticFuncAccessingInstanceMemberRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-static-func-accessing-instance-member",
+                name: "No Static Func Accessing Instance Member",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Error,
+                description: "Static functions have no instance, so they cannot use self",
+            },
+        }
+    }
+}
+
+/// Collect the `self` nodes under `node`. Lambdas are included, since they capture
+/// the static context they are created in.
+fn collect_self_references<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if node.kind() == "self" {
+        out.push(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_self_references(child, out);
+    }
+}
+
+impl Rule for NoStaticFuncAccessingInstanceMemberRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["function_definition"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut cursor = node.walk();
+        if !node
+            .children(&mut cursor)
+            .any(|c| c.kind() == "static_keyword")
+        {
+            return;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut references = Vec::new();
+        collect_self_references(body, &mut references);
+        if references.is_empty() {
+            return;
+        }
+
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(n))
+            .unwrap_or("");
+        let message = format!("\"self\" used in static function \"{}\"", name);
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        for reference in references {
+            ctx.report_node(reference, self.meta.id, severity, message.clone());
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct NoYieldInGodot4Rule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoYieldInGodot4Rule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-yield-in-godot4",
+                name: "No Yield In Godot 4",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Error,
+                description: "yield was removed in Godot 4, use await instead",
+            },
+        }
+    }
+}
+
+/// Check if a node is a `yield` usage: either a dedicated yield node or a
+/// call whose function is the bare `yield` identifier.
+fn is_yield_node(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    match node.kind() {
+        "yield" | "yield_expression" => true,
+        "call" => node
+            .child(0)
+            .map(|f| f.kind() == "identifier" && ctx.node_text(f) == "yield")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn contains_yield_node(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    if is_yield_node(node, ctx) {
+        return true;
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|child| contains_yield_node(child, ctx));
+    found
+}
+
+impl Rule for NoYieldInGodot4Rule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "yield", "yield_expression", "ERROR"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let is_yield = if node.kind() == "ERROR" {
+            // The grammar may fail to build a call around `yield`; fall back to
+            // scanning the text, unless a nested node will be reported anyway
+            YIELD_PATTERN.is_match(ctx.node_text(node)) && !contains_yield_node(node, ctx)
+        } else {
+            // A bare `yield` token inside a yield_expression is reported via its parent
+            is_yield_node(node, ctx)
+                && !(node.kind() == "yield"
+                    && node.parent().map(|p| p.kind()) == Some("yield_expression"))
+        };
+
+        if is_yield {
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            ctx.report_node(
+                node,
+                self.meta.id,
+                severity,
+                "\"yield\" was removed in Godot 4, use \"await\" instead",
+            );
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags the Godot 3 `onready` and `export` keywords, which became the
+/// `@onready` and `@export` annotations in Godot 4.
+///
+/// The grammar doesn't model the keyword forms, so declarations are found by
+/// scanning lines; matches inside strings and comments are ignored.
+#[derive(Debug)]
+pub struct NoOnreadyWithoutAtRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoOnreadyWithoutAtRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-onready-without-at",
+                name: "No Onready Without At",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Error,
+                description: "onready and export keywords were replaced by annotations in Godot 4",
+            },
+        }
+    }
+}
+
+impl Rule for NoOnreadyWithoutAtRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let root = ctx.tree().root_node();
+
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+        for (line_idx, line) in ctx.source().split('\n').enumerate() {
+            if let Some(keyword) = LEGACY_KEYWORD_PATTERN
+                .captures(line)
+                .and_then(|caps| caps.get(1))
+            {
+                let start = line_start + keyword.start();
+                let in_literal = root
+                    .descendant_for_byte_range(start, start + keyword.len())
+                    .is_some_and(|node| matches!(node.kind(), "string" | "comment"));
+                if !in_literal {
+                    let name = keyword.as_str();
+                    diagnostics.push(
+                        Diagnostic::new(
+                            self.meta.id,
+                            severity,
+                            format!(
+                                "\"{}\" is Godot 3 syntax, use the \"@{}\" annotation instead",
+                                name, name
+                            ),
+                        )
+                        .with_location(line_idx + 1, keyword.start() + 1)
+                        .with_suggestion(format!("@{}", name)),
+                    );
+                }
+            }
+            line_start += line.len() + 1;
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct AwaitOnLiteralRule {
+    meta: RuleMetadata,
+}
+
+impl Default for AwaitOnLiteralRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "await-on-literal",
+                name: "Await On Literal",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "await should be applied to a signal or coroutine, not a literal",
+            },
+        }
+    }
+}
+
+impl Rule for AwaitOnLiteralRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["await_expression"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut cursor = node.walk();
+        let mut operand = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "comment");
+        // Look through parentheses: `await (1)` is still a literal
+        while let Some(inner) = operand.filter(|o| o.kind() == "parenthesized_expression") {
+            operand = inner.named_child(0);
+        }
+
+        let Some(literal) = operand.filter(|o| {
+            matches!(
+                o.kind(),
+                "integer" | "float" | "string" | "true" | "false" | "null"
+            )
+        }) else {
+            return;
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "\"await\" on the literal {} returns it immediately, await a signal or coroutine",
+            ctx.node_text(literal)
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct NoRedundantAwaitRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoRedundantAwaitRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-redundant-await",
+                name: "No Redundant Await",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "await should not be applied twice to the same expression",
+            },
+        }
+    }
+}
+
+fn is_await_node(node: Node<'_>) -> bool {
+    matches!(node.kind(), "await" | "await_expression")
+}
+
+impl Rule for NoRedundantAwaitRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["await_expression"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        // `await await await x` is reported once, at the outermost await
+        if node.parent().is_some_and(is_await_node) {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        let operand = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "comment");
+        if !operand.is_some_and(is_await_node) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        ctx.report_node(
+            node,
+            self.meta.id,
+            severity,
+            "\"await\" is repeated, a single await is enough",
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct NoAssertWithSideEffectsRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoAssertWithSideEffectsRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-assert-with-side-effects",
+                name: "No Assert With Side Effects",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "assert conditions should not call functions or assign values",
+            },
+        }
+    }
+}
+
+/// Find the first call or assignment in an expression, if any.
+fn find_side_effect(node: Node<'_>) -> Option<Node<'_>> {
+    if matches!(
+        node.kind(),
+        "call" | "attribute_call" | "assignment" | "augmented_assignment"
+    ) {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let found = node.named_children(&mut cursor).find_map(find_side_effect);
+    found
+}
+
+impl Rule for NoAssertWithSideEffectsRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let is_assert = node
+            .child(0)
+            .is_some_and(|f| f.kind() == "identifier" && ctx.node_text(f) == "assert");
+        if !is_assert {
+            return;
+        }
+
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = arguments.walk();
+        let condition = arguments
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "comment");
+        let Some(side_effect) = condition.and_then(find_side_effect) else {
+            return;
+        };
+
+        let what = if side_effect.kind().ends_with("call") {
+            "calls a function"
+        } else {
+            "assigns a value"
+        };
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        ctx.report_node(
+            node,
+            self.meta.id,
+            severity,
+            format!(
+                "assert condition {}, but asserts are removed in release builds; \
+                 move it out of the assert",
+                what
+            ),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags `match` arms whose body is only `pass`, with no comment explaining why.
+#[derive(Debug)]
+pub struct NoEmptyMatchArmRule {
+    meta: RuleMetadata,
+    allow_wildcard_pass: bool,
+}
+
+impl Default for NoEmptyMatchArmRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-empty-match-arm",
+                name: "No Empty Match Arm",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "match arms should not consist solely of pass",
+            },
+            allow_wildcard_pass: true,
+        }
+    }
+}
+
+impl Rule for NoEmptyMatchArmRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["pattern_section"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut cursor = body.walk();
+        let mut statements = body
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "comment");
+        let only_pass = matches!(
+            (statements.next(), statements.next()),
+            (Some(stmt), None) if stmt.kind() == "pass_statement"
+        );
+        if !only_pass || has_comment(node) {
+            return;
+        }
+        // A comment on the line above the arm explains it too
+        if node.prev_sibling().is_some_and(|prev| {
+            prev.kind() == "comment" && prev.end_position().row + 1 == node.start_position().row
+        }) {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        let patterns: Vec<_> = node
+            .named_children(&mut cursor)
+            .filter(|c| c.id() != body.id() && !matches!(c.kind(), "comment" | "pattern_guard"))
+            .collect();
+        let Some(&first) = patterns.first() else {
+            return;
+        };
+        let is_wildcard = patterns.iter().all(|p| ctx.node_text(*p) == "_");
+        if is_wildcard && self.allow_wildcard_pass {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let pattern = &ctx.source()[first.start_byte()..patterns[patterns.len() - 1].end_byte()];
+        let message = format!(
+            "match arm \"{}\" only contains pass; handle the case or add a comment",
+            pattern
+        );
+        ctx.report_node(first, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(allow) = config.options.get("allow_wildcard_pass") {
+            self.allow_wildcard_pass = allow
+                .as_bool()
+                .ok_or("no-empty-match-arm: 'allow_wildcard_pass' must be a boolean")?;
+        }
+        Ok(())
+    }
+}
+
+/// Flags match arms that can never run: any arm after an unguarded `_` arm, and
+/// literal patterns already handled by an earlier unguarded arm.
+///
+/// Literals are compared by their source text, so `1` and `0x1` count as different.
+#[derive(Debug)]
+pub struct NoUnreachableMatchArmRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoUnreachableMatchArmRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-unreachable-match-arm",
+                name: "No Unreachable Match Arm",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "match arms after a wildcard or repeating a literal never run",
+            },
+        }
+    }
+}
+
+impl Rule for NoUnreachableMatchArmRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["match_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        // Arms sit either directly under the match or in its body block
+        let mut cursor = node.walk();
+        let sections: Vec<_> = node
+            .named_children(&mut cursor)
+            .flat_map(|child| {
+                let mut inner = child.walk();
+                if child.kind() == "pattern_section" {
+                    vec![child]
+                } else {
+                    child
+                        .named_children(&mut inner)
+                        .filter(|c| c.kind() == "pattern_section")
+                        .collect()
+                }
+            })
+            .collect();
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let mut wildcard_line: Option<usize> = None;
+        let mut seen_literals: HashMap<String, usize> = HashMap::new();
+
+        for section in sections {
+            let body = section.child_by_field_name("body");
+            let mut cursor = section.walk();
+            let mut has_guard = false;
+            let mut patterns = Vec::new();
+            for child in section.named_children(&mut cursor) {
+                match child.kind() {
+                    "pattern_guard" => has_guard = true,
+                    "comment" => {}
+                    _ if body.is_some_and(|b| b.id() == child.id()) => {}
+                    _ => patterns.push(child),
+                }
+            }
+            let Some(&first) = patterns.first() else {
+                continue;
+            };
+            let line = section.start_position().row + 1;
+
+            if let Some(wildcard_line) = wildcard_line {
+                let message = format!(
+                    "match arm \"{}\" is unreachable after the \"_\" arm on line {}",
+                    ctx.node_text(first),
+                    wildcard_line
+                );
+                ctx.report_node(first, self.meta.id, severity, message);
+                continue;
+            }
+
+            for pattern in &patterns {
+                let is_literal = matches!(
+                    pattern.kind(),
+                    "integer" | "float" | "string" | "true" | "false" | "null"
+                );
+                if !is_literal {
+                    continue;
+                }
+                let text = ctx.node_text(*pattern).to_string();
+                if let Some(&earlier) = seen_literals.get(&text) {
+                    let message = format!(
+                        "match pattern {} is unreachable, it is already handled on line {}",
+                        text, earlier
+                    );
+                    ctx.report_node(*pattern, self.meta.id, severity, message);
+                } else if !has_guard {
+                    seen_literals.insert(text, line);
+                }
+            }
+
+            if !has_guard && patterns.iter().any(|p| ctx.node_text(*p) == "_") {
+                wildcard_line = Some(line);
+            }
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags enum members that end up with the same value as an earlier member,
+/// either explicitly (`A = 1, B = 1`) or through auto-numbering (`A = 1, B = 0, C`).
+///
+/// Only integer literals (optionally negated) are evaluated. After a member with
+/// any other value, members are unchecked until the next literal.
+#[derive(Debug)]
+pub struct NoDuplicateEnumValueRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoDuplicateEnumValueRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-duplicate-enum-value",
+                name: "No Duplicate Enum Value",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "Enum members should not share a value",
+            },
+        }
+    }
+}
+
+/// Evaluate an integer literal such as `3`, `-1`, `0xFF`, `0b101` or `1_000`.
+fn integer_literal_value(node: Node<'_>, ctx: &LintContext<'_>) -> Option<i64> {
+    match node.kind() {
+        "integer" => {
+            let text = ctx.node_text(node).replace('_', "");
+            if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+                i64::from_str_radix(hex, 16).ok()
+            } else if let Some(bin) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+                i64::from_str_radix(bin, 2).ok()
+            } else {
+                text.parse().ok()
+            }
+        }
+        "unary_operator" => {
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+            match children.as_slice() {
+                [op, operand] if ctx.node_text(*op) == "-" => {
+                    integer_literal_value(*operand, ctx).map(|v| -v)
+                }
+                _ => None,
+            }
+        }
+        "parenthesized_expression" => node
+            .named_child(0)
+            .and_then(|inner| integer_literal_value(inner, ctx)),
+        _ => None,
+    }
+}
+
+impl Rule for NoDuplicateEnumValueRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["enum_definition"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut cursor = body.walk();
+        let members: Vec<_> = body
+            .children(&mut cursor)
+            .filter(|c| c.kind() == "enum_entry" || c.kind() == "enumerator")
+            .collect();
+
+        let mut seen: HashMap<i64, &str> = HashMap::new();
+        let mut duplicates = Vec::new();
+        // The value the next member without `= value` gets, if known
+        let mut next_value = Some(0);
+        for member in members {
+            let value = match member.child_by_field_name("value") {
+                Some(value) => integer_literal_value(value, ctx),
+                None => next_value,
+            };
+            next_value = value.and_then(|v| v.checked_add(1));
+
+            let Some(value) = value else {
+                continue;
+            };
+            let name = member
+                .child_by_field_name("name")
+                .map(|n| ctx.node_text(n))
+                .unwrap_or_else(|| ctx.node_text(member));
+            if let Some(first) = seen.get(&value) {
+                duplicates.push((member, name, value, *first));
+            } else {
+                seen.insert(value, name);
+            }
+        }
+        if duplicates.is_empty() {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let messages: Vec<_> = duplicates
+            .into_iter()
+            .map(|(member, name, value, first)| {
+                (
+                    member,
+                    format!(
+                        "Enum member \"{}\" has the same value ({}) as \"{}\"",
+                        name, value, first
+                    ),
+                )
+            })
+            .collect();
+        for (member, message) in messages {
+            ctx.report_node(member, self.meta.id, severity, message);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Check if a declaration carries the given annotation, either inline
+/// (`@onready var x`) or on the preceding lines (`@onready\nvar x`).
+pub(crate) fn has_annotation(node: Node<'_>, name: &str, ctx: &LintContext<'_>) -> bool {
+    has_annotation_matching(node, |n| n == name, ctx)
+}
+
+/// Like [`has_annotation`], but matches annotation names with a predicate
+/// (e.g. every `@export_*` variant).
+pub(crate) fn has_annotation_matching(
+    node: Node<'_>,
+    matches: impl Fn(&str) -> bool,
+    ctx: &LintContext<'_>,
+) -> bool {
+    let annotation_matches = |annotation: Node<'_>| {
+        let mut cursor = annotation.walk();
+        let found = annotation
+            .children(&mut cursor)
+            .any(|c| c.kind() == "identifier" && matches(ctx.node_text(c)));
+        found
+    };
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "annotations" {
+            let mut ann_cursor = child.walk();
+            if child
+                .children(&mut ann_cursor)
+                .any(|a| a.kind() == "annotation" && annotation_matches(a))
+            {
+                return true;
+            }
+        }
+    }
+
+    let mut prev = node.prev_named_sibling();
+    while let Some(sibling) = prev {
+        if sibling.kind() != "annotation" {
+            break;
+        }
+        if annotation_matches(sibling) {
+            return true;
+        }
+        prev = sibling.prev_named_sibling();
+    }
+
+    false
+}
+
+/// Flags class variables initialized with a `load(...)` call and no `@onready`.
+///
+/// `preload(...)` initializers are left to no-preload-variable-should-be-const: they
+/// resolve at compile time, and reporting them here too would repeat that rule.
+#[derive(Debug)]
+pub struct NoGlobalLoadAtParseTimeRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoGlobalLoadAtParseTimeRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-global-load-at-parse-time",
+                name: "No Global Load At Parse Time",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Class variables should not load resources without @onready",
+            },
+        }
+    }
+}
+
+impl Rule for NoGlobalLoadAtParseTimeRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["variable_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if !is_class_scope_variable(node) {
+            return;
+        }
+
+        let Some(value) = node.child_by_field_name("value") else {
+            return;
+        };
+        if !contains_call_to(value, "load", ctx) || has_annotation(node, "onready", ctx) {
+            return;
+        }
+
+        if let Some(name_node) = node.child_by_field_name("name") {
+            let name = ctx.node_text(name_node).to_string();
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            ctx.report_node(
+                name_node,
+                self.meta.id,
+                severity,
+                format!(
+                    "Class variable \"{}\" loads a resource at parse time, use const with preload, or @onready",
+                    name
+                ),
+            );
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// A class variable initialized with `preload("...")` is resolved at compile time
+/// and is almost always meant as a `const`.
+#[derive(Debug)]
+pub struct NoPreloadVariableShouldBeConstRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoPreloadVariableShouldBeConstRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-preload-variable-should-be-const",
+                name: "No Preload Variable Should Be Const",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Class variables holding a preload should be const",
+            },
+        }
+    }
+}
+
+impl Rule for NoPreloadVariableShouldBeConstRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["variable_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if !is_class_scope_variable(node)
+            || node.child_by_field_name("setget").is_some()
+            || node.child_by_field_name("static").is_some()
+            || has_annotation_matching(
+                node,
+                |name| name == "onready" || name.starts_with("export"),
+                ctx,
+            )
+        {
+            return;
+        }
+
+        // Only `preload("...")` itself: `load` runs at runtime, and anything around
+        // the call may not be a constant expression
+        let Some(value) = node.child_by_field_name("value") else {
+            return;
+        };
+        if value.kind() != "call"
+            || !value
+                .named_child(0)
+                .is_some_and(|f| f.kind() == "identifier" && ctx.node_text(f) == "preload")
+        {
+            return;
+        }
+        let Some(arguments) = value.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = arguments.walk();
+        let args: Vec<_> = arguments
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "comment")
+            .collect();
+        if !matches!(args.as_slice(), [path] if path.kind() == "string") {
+            return;
+        }
+
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        let name = ctx.node_text(name_node);
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let position = name_node.start_position();
+        ctx.report(
+            Diagnostic::new(self.meta.id, severity, preload_variable_message(name))
+                .with_location(position.row + 1, position.column + 1)
+                .with_suggestion(format!("const {} = {}", name, ctx.node_text(value))),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The message for a class variable holding a preload. const-could-be-used reports
+/// the same words, so `dedupe_diagnostics` merges the two into one diagnostic.
+pub(crate) fn preload_variable_message(name: &str) -> String {
+    format!("Class variable \"{}\" holds a preload, use const", name)
+}
+
+#[derive(Debug)]
+pub struct NoSpaceInNodeNamesRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoSpaceInNodeNamesRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-space-in-node-names",
+                name: "No Space In Node Names",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "Unquoted $ node paths should not contain spaces",
+            },
+        }
+    }
+}
+
+/// Check if text contains whitespace outside of a quoted section.
+fn has_unquoted_space(text: &str) -> bool {
+    let mut quote: Option<char> = None;
+    for c in text.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == ' ' || c == '\t' => return true,
+            None => {}
+        }
+    }
+    false
+}
+
+impl Rule for NoSpaceInNodeNamesRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["get_node"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let text = ctx.node_text(node).to_string();
+        if !has_unquoted_space(&text) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let path = text.trim_start_matches(['$', '%']);
+        ctx.report_node(
+            node,
+            self.meta.id,
+            severity,
+            format!(
+                "Node path \"{}\" contains a space, quote it ($\"{}\") or use a unique name (%Name)",
+                text, path
+            ),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Godot 3 APIs that were renamed or replaced in Godot 4.
+///
+/// Plain keys match any function or method call with that name. Dotted keys
+/// (`OS.get_ticks_msec`) only match when accessed through that identifier.
+const DEPRECATED_APIS: &[(&str, &str)] = &[
+    ("instance", "instantiate"),
+    ("change_scene", "change_scene_to_file"),
+    ("change_scene_to", "change_scene_to_packed"),
+    ("is_a_parent_of", "is_ancestor_of"),
+    ("linear_interpolate", "lerp"),
+    ("rand_range", "randf_range"),
+    ("range_lerp", "remap"),
+    ("stepify", "snapped"),
+    ("deg2rad", "deg_to_rad"),
+    ("rad2deg", "rad_to_deg"),
+    ("str2var", "str_to_var"),
+    ("var2str", "var_to_str"),
+    ("OS.get_ticks_msec", "Time.get_ticks_msec"),
+    ("OS.get_ticks_usec", "Time.get_ticks_usec"),
+    ("OS.get_unix_time", "Time.get_unix_time_from_system"),
+    ("OS.get_datetime", "Time.get_datetime_dict_from_system"),
+    ("OS.window_size", "DisplayServer.window_get_size"),
+    ("File.new", "FileAccess.open"),
+    ("Directory.new", "DirAccess.open"),
+];
+
+#[derive(Debug)]
+pub struct DeprecatedApiRule {
+    meta: RuleMetadata,
+    renames: HashMap<String, String>,
+}
+
+impl Default for DeprecatedApiRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "deprecated-api",
+                name: "Deprecated API",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "Godot 3 APIs that were renamed in Godot 4 should not be used",
+            },
+            renames: DEPRECATED_APIS
+                .iter()
+                .map(|(old, new)| (old.to_string(), new.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl DeprecatedApiRule {
+    fn report(&self, name_node: Node<'_>, key: &str, ctx: &mut LintContext<'_>) {
+        let Some(replacement) = self.renames.get(key) else {
+            return;
+        };
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!("\"{}\" is deprecated, use \"{}\" instead", key, replacement);
+        ctx.report_node(name_node, self.meta.id, severity, message);
+    }
+}
+
+impl Rule for DeprecatedApiRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "attribute"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if node.kind() == "call" {
+            if let Some(name) = node.named_child(0).filter(|n| n.kind() == "identifier") {
+                let key = ctx.node_text(name).to_string();
+                self.report(name, &key, ctx);
+            }
+            return;
+        }
+
+        // attribute: receiver, then `.segment` pairs (identifiers or method calls)
+        let mut cursor = node.walk();
+        let segments: Vec<_> = node
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "line_continuation")
+            .collect();
+
+        for pair in segments.windows(2) {
+            let (receiver, segment) = (pair[0], pair[1]);
+            let (name, is_call) = match segment.kind() {
+                "identifier" => (segment, false),
+                "attribute_call" => match segment.named_child(0) {
+                    Some(name) => (name, true),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let name_text = ctx.node_text(name).to_string();
+
+            if receiver.kind() == "identifier" {
+                let qualified = format!("{}.{}", ctx.node_text(receiver), name_text);
+                if self.renames.contains_key(&qualified) {
+                    self.report(name, &qualified, ctx);
+                    continue;
+                }
+            }
+            if is_call {
+                self.report(name, &name_text, ctx);
+            }
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        let Some(renames) = config.options.get("renames") else {
+            return Ok(());
+        };
+        let table = renames
+            .as_table()
+            .ok_or("deprecated-api: 'renames' must be a table of old = \"new\" names")?;
+
+        for (old, new) in table {
+            let new = new.as_str().ok_or_else(|| {
+                format!("deprecated-api: replacement for '{}' must be a string", old)
+            })?;
+            // An empty replacement removes a built-in entry
+            if new.is_empty() {
+                self.renames.remove(old);
+            } else {
+                self.renames.insert(old.clone(), new.to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// APIs whose first argument names a signal, method, property, group, meta key
+/// or input action, and so is looked up as a `StringName`.
+const STRING_NAME_APIS: &[&str] = &[
+    "connect",
+    "disconnect",
+    "is_connected",
+    "emit_signal",
+    "has_signal",
+    "call",
+    "call_deferred",
+    "callv",
+    "has_method",
+    "set",
+    "get",
+    "set_deferred",
+    "has_meta",
+    "get_meta",
+    "set_meta",
+    "remove_meta",
+    "add_to_group",
+    "remove_from_group",
+    "is_in_group",
+    "is_action_pressed",
+    "is_action_just_pressed",
+    "is_action_just_released",
+    "is_action_released",
+    "get_action_strength",
+];
+
+#[derive(Debug)]
+pub struct StringShouldBeStringNameRule {
+    meta: RuleMetadata,
+    apis: HashSet<String>,
+}
+
+impl Default for StringShouldBeStringNameRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "string-should-be-stringname",
+                name: "String Should Be StringName",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description:
+                    "Name arguments to signal, method and input APIs should be StringName literals",
+            },
+            apis: STRING_NAME_APIS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Rule for StringShouldBeStringNameRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "attribute_call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(name) = node.named_child(0).filter(|n| n.kind() == "identifier") else {
+            return;
+        };
+        if !self.apis.contains(ctx.node_text(name)) {
+            return;
+        }
+
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = arguments.walk();
+        let first_arg = arguments
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "comment");
+        let Some(string) = first_arg.filter(|a| a.kind() == "string") else {
+            return;
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Use a StringName literal &{} instead of a String",
+            ctx.node_text(string)
+        );
+        ctx.report_node(string, self.meta.id, severity, message);
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(apis) = config.options.get("apis") {
+            let apis = apis
+                .as_array()
+                .ok_or("string-should-be-stringname: 'apis' must be a list of strings")?;
+            self.apis = apis
+                .iter()
+                .map(|a| {
+                    a.as_str().map(str::to_string).ok_or_else(|| {
+                        "string-should-be-stringname: 'apis' must be a list of strings".to_string()
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+        }
+        Ok(())
+    }
+}
+
+/// Which shorthand `no-string-node-path-for-get-node` suggests in place of `get_node("...")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodePathShorthand {
+    /// `$Path/To/Node`
+    Dollar,
+    /// `%Node` for a direct child's name, `$` for any other path
+    Unique,
+}
+
+/// Suggests `$`/`%` shorthands for `get_node` calls with a literal path.
+///
+/// Only bare `get_node("...")` and `self.get_node("...")` are checked, since the
+/// shorthands always look up from `self`. Paths built at runtime are skipped.
+#[derive(Debug)]
+pub struct NoStringNodePathRule {
+    meta: RuleMetadata,
+    prefer: NodePathShorthand,
+}
+
+impl Default for NoStringNodePathRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-string-node-path-for-get-node",
+                name: "No String Node Path For get_node",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Use the $ or % shorthand instead of get_node with a literal path",
+            },
+            prefer: NodePathShorthand::Dollar,
+        }
+    }
+}
+
+/// Quote a node path for `$`/`%` unless it only uses characters the bare form allows.
+fn node_path_shorthand(sigil: char, path: &str) -> String {
+    let bare = !path.is_empty()
+        && path
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '/' | '%'));
+    if bare {
+        format!("{}{}", sigil, path)
+    } else {
+        format!("{}\"{}\"", sigil, path)
+    }
+}
+
+impl Rule for NoStringNodePathRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "attribute_call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(name) = node.named_child(0).filter(|n| n.kind() == "identifier") else {
+            return;
+        };
+        if ctx.node_text(name) != "get_node" {
+            return;
+        }
+        // `other.get_node(...)` has no shorthand, only `self.get_node(...)` does
+        if node.kind() == "attribute_call"
+            && !node
+                .prev_named_sibling()
+                .is_some_and(|receiver| ctx.node_text(receiver) == "self")
+        {
+            return;
+        }
+
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = arguments.walk();
+        let args: Vec<_> = arguments
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "comment")
+            .collect();
+        let [path] = args.as_slice() else {
+            return;
+        };
+        if path.kind() != "string" {
+            return;
+        }
+        let text = ctx.node_text(*path);
+        let path_text = text.trim_matches(|c| c == '"' || c == '\'');
+        if path_text.is_empty() || path_text.contains('\\') {
+            return;
+        }
+
+        // Only a direct child's name can stand in as a unique name; other paths
+        // (`Body/Sprite2D`, `../Hud`) may not point at a unique node at all
+        let child_name = !path_text.contains('/') && !matches!(path_text, "." | "..");
+        let suggestion = match (self.prefer, path_text.strip_prefix('%')) {
+            (_, Some(unique)) => node_path_shorthand('%', unique),
+            (NodePathShorthand::Unique, None) if child_name => node_path_shorthand('%', path_text),
+            (_, None) => node_path_shorthand('$', path_text),
+        };
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let (line, column) = (
+            node.start_position().row + 1,
+            node.start_position().column + 1,
+        );
+        ctx.report(
+            Diagnostic::new(
+                self.meta.id,
+                severity,
+                format!("Use {} instead of get_node({})", suggestion, text),
+            )
+            .with_location(line, column)
+            .with_suggestion(suggestion),
+        );
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(prefer) = config.options.get("prefer") {
+            self.prefer = match prefer.as_str() {
+                Some("dollar") => NodePathShorthand::Dollar,
+                Some("unique") => NodePathShorthand::Unique,
+                _ => return Err(
+                    "no-string-node-path-for-get-node: 'prefer' must be \"dollar\" or \"unique\""
+                        .to_string(),
+                ),
+            };
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct NoConnectWithStringRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoConnectWithStringRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-connect-with-string-method-name",
+                name: "No Connect With String",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "Connect through the signal object instead of a signal name string",
+            },
+        }
+    }
+}
+
+impl Rule for NoConnectWithStringRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "attribute_call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(name) = node.named_child(0).filter(|n| n.kind() == "identifier") else {
+            return;
+        };
+        if ctx.node_text(name) != "connect" {
+            return;
+        }
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = arguments.walk();
+        let args: Vec<_> = arguments
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "comment")
+            .collect();
+        let Some(signal) = args.first().filter(|a| a.kind() == "string") else {
+            return;
+        };
+        let signal_name = ctx
+            .node_text(*signal)
+            .trim_matches(|c| c == '"' || c == '\'');
+        if signal_name.is_empty() || !signal_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return;
+        }
+
+        // `obj.connect(...)` keeps its receiver; `self.connect(...)` and `connect(...)` don't need one
+        let receiver = match node.parent() {
+            Some(parent) if node.kind() == "attribute_call" && parent.kind() == "attribute" => ctx
+                .source()[parent.start_byte()..node.start_byte()]
+                .trim_end()
+                .trim_end_matches('.')
+                .trim_end(),
+            _ => "",
+        };
+        // Godot 3 passed the target and method name separately: connect("sig", target, "method")
+        let callable = match args.as_slice() {
+            [_, target, method, ..] if method.kind() == "string" => {
+                let method = ctx
+                    .node_text(*method)
+                    .trim_matches(|c| c == '"' || c == '\'');
+                match ctx.node_text(*target) {
+                    "self" => method.to_string(),
+                    target => format!("{}.{}", target, method),
+                }
+            }
+            [_, callable, ..] => ctx.node_text(*callable).to_string(),
+            _ => "callable".to_string(),
+        };
+        let suggestion = match receiver {
+            "" | "self" => format!("{}.connect({})", signal_name, callable),
+            receiver => format!("{}.{}.connect({})", receiver, signal_name, callable),
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let (line, column) = (
+            node.start_position().row + 1,
+            node.start_position().column + 1,
+        );
+        ctx.report(
+            Diagnostic::new(
+                self.meta.id,
+                severity,
+                format!(
+                    "Connect to the signal instead of its name: use {}",
+                    suggestion
+                ),
+            )
+            .with_location(line, column)
+            .with_suggestion(suggestion),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Signals declared by common Godot base classes, which scripts use without
+/// declaring them.
+const COMMON_INHERITED_SIGNALS: &[&str] = &[
+    // Object, Node, CanvasItem and Resource
+    "script_changed",
+    "property_list_changed",
+    "ready",
+    "renamed",
+    "tree_entered",
+    "tree_exiting",
+    "tree_exited",
+    "child_entered_tree",
+    "child_exiting_tree",
+    "draw",
+    "visibility_changed",
+    "hidden",
+    "changed",
+    // Control and buttons
+    "resized",
+    "gui_input",
+    "mouse_entered",
+    "mouse_exited",
+    "focus_entered",
+    "focus_exited",
+    "pressed",
+    "button_down",
+    "button_up",
+    "toggled",
+    "text_changed",
+    "text_submitted",
+    "value_changed",
+    "item_selected",
+    // Physics, timers and animation
+    "body_entered",
+    "body_exited",
+    "area_entered",
+    "area_exited",
+    "input_event",
+    "timeout",
+    "finished",
+    "animation_started",
+    "animation_finished",
+];
+
+/// Signal methods whose receiver, when a bare name, is probably one of the
+/// script's own signals.
+const SIGNAL_METHODS: &[&str] = &["connect", "disconnect", "is_connected", "emit"];
+
+/// Flags signals used on the script itself (`hit.emit()`, `hit.connect(...)`,
+/// `emit_signal("hit")`) that no `signal` statement in the file declares. Signals
+/// can be inherited, so this is a heuristic: common engine signals and the
+/// `allowed` names are accepted.
+#[derive(Debug)]
+pub struct SignalConnectedButNotDeclaredRule {
+    meta: RuleMetadata,
+    allowed: HashSet<String>,
+}
+
+impl Default for SignalConnectedButNotDeclaredRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "signal-connected-but-not-declared",
+                name: "Signal Connected But Not Declared",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description: "Signals used by the script should be declared in it",
+            },
+            allowed: HashSet::new(),
+        }
+    }
+}
+
+/// Names declared in a file, split into signals and everything else.
+#[derive(Default)]
+struct DeclaredNames {
+    signals: HashSet<String>,
+    others: HashSet<String>,
+}
+
+impl DeclaredNames {
+    fn collect(&mut self, node: Node<'_>, source: &str) {
+        let text = |n: Node<'_>| source.get(n.byte_range()).unwrap_or("").to_string();
+        match node.kind() {
+            "signal_statement" => self
+                .signals
+                .extend(node.child_by_field_name("name").map(text)),
+            "variable_statement"
+            | "const_statement"
+            | "function_definition"
+            | "class_definition"
+            | "enum_definition" => self
+                .others
+                .extend(node.child_by_field_name("name").map(text)),
+            "parameters" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    let name = match child.kind() {
+                        "identifier" => Some(child),
+                        _ => child.named_child(0).filter(|c| c.kind() == "identifier"),
+                    };
+                    self.others.extend(name.map(text));
+                }
+            }
+            // The first identifier is the loop variable
+            "for_statement" => {
+                let mut cursor = node.walk();
+                let variable = node
+                    .children(&mut cursor)
+                    .find(|c| c.kind() == "identifier");
+                self.others.extend(variable.map(text));
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.collect(child, source);
+        }
+    }
+}
+
+/// A signal the script uses on itself, and whether it was named by a string.
+struct SignalReference<'a> {
+    node: Node<'a>,
+    name: String,
+    by_string: bool,
+}
+
+/// Collect `name.connect(...)`-style uses where `name` is bare or `self.name`, and
+/// `emit_signal("name")` / `connect("name", ...)` called on the script itself.
+fn collect_signal_references<'a>(node: Node<'a>, source: &str, out: &mut Vec<SignalReference<'a>>) {
+    let text = |n: Node<'_>| source.get(n.byte_range()).unwrap_or("");
+    let method = node
+        .named_child(0)
+        .filter(|n| n.kind() == "identifier")
+        .map(text);
+
+    match (node.kind(), method) {
+        ("attribute_call", Some(method)) => {
+            let receiver = node.prev_named_sibling();
+            let owner = receiver.and_then(|r| r.prev_named_sibling());
+            let on_self = owner.is_none_or(|o| o.kind() == "self");
+            // `button.connect("pressed", ...)` is the Godot 3 form on another object
+            let names_signal = first_argument(node).is_some_and(|a| a.kind() == "string");
+            match receiver {
+                Some(receiver)
+                    if receiver.kind() == "identifier"
+                        && on_self
+                        && SIGNAL_METHODS.contains(&method)
+                        && !names_signal =>
+                {
+                    out.push(SignalReference {
+                        node: receiver,
+                        name: text(receiver).to_string(),
+                        by_string: false,
+                    });
+                }
+                Some(receiver) if receiver.kind() == "self" && owner.is_none() => {
+                    out.extend(string_signal_argument(node, method, source));
+                }
+                _ => {}
+            }
+        }
+        ("call", Some(method)) => out.extend(string_signal_argument(node, method, source)),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_signal_references(child, source, out);
+    }
+}
+
+/// The signal named by the first argument of `emit_signal("name")` or `connect("name", ...)`.
+fn string_signal_argument<'a>(
+    call: Node<'a>,
+    method: &str,
+    source: &str,
+) -> Option<SignalReference<'a>> {
+    if !matches!(method, "emit_signal" | "connect") {
+        return None;
+    }
+    let first = first_argument(call).filter(|a| a.kind() == "string")?;
+    let name = source
+        .get(first.byte_range())?
+        .trim_matches(|c| c == '"' || c == '\'');
+    Some(SignalReference {
+        node: first,
+        name: name.to_string(),
+        by_string: true,
+    })
+}
+
+fn first_argument(call: Node<'_>) -> Option<Node<'_>> {
+    let arguments = call.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let first = arguments
+        .named_children(&mut cursor)
+        .find(|c| c.kind() != "comment");
+    first
+}
+
+impl Rule for SignalConnectedButNotDeclaredRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let root = ctx.tree().root_node();
+        let mut declared = DeclaredNames::default();
+        declared.collect(root, ctx.source());
+        let mut references = Vec::new();
+        collect_signal_references(root, ctx.source(), &mut references);
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        for reference in references {
+            let name = reference.name.as_str();
+            // Bare PascalCase receivers are classes, singletons and autoloads; bare
+            // names the file declares otherwise are variables holding a signal
+            let looks_local = name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+                && (reference.by_string || !declared.others.contains(name));
+            if !looks_local
+                || declared.signals.contains(name)
+                || COMMON_INHERITED_SIGNALS.contains(&name)
+                || self.allowed.contains(name)
+            {
+                continue;
+            }
+            ctx.report_node(
+                reference.node,
+                self.meta.id,
+                severity,
+                format!("Signal \"{}\" is not declared in this file", name),
+            );
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(allowed) = configure_string_list(config, self.meta.id, "allowed")? {
+            self.allowed = allowed.into_iter().collect();
+        }
+        Ok(())
+    }
+}
+
+/// Flags `emit_signal("")`, which names no signal and fails at runtime.
+#[derive(Debug)]
+pub struct NoEmptySignalNameInEmitRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoEmptySignalNameInEmitRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-empty-signal-name-in-emit",
+                name: "No Empty Signal Name In Emit",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Warning,
+                description: "emit_signal needs the name of a signal",
+            },
+        }
+    }
+}
+
+impl Rule for NoEmptySignalNameInEmitRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call", "attribute_call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let is_emit_signal = node
+            .named_child(0)
+            .filter(|n| n.kind() == "identifier")
+            .is_some_and(|n| ctx.node_text(n) == "emit_signal");
+        let is_empty_name = first_argument(node)
+            .filter(|a| a.kind() == "string")
+            .is_some_and(|a| matches!(ctx.node_text(a), "\"\"" | "''"));
+        if !is_emit_signal || !is_empty_name {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        ctx.report_node(
+            node,
+            self.meta.id,
+            severity,
+            "emit_signal called with an empty signal name".to_string(),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Print functions that log without saying where the message came from.
+const CONTEXTLESS_PRINTS: &[&str] = &[
+    "print",
+    "prints",
+    "printt",
+    "printraw",
+    "printerr",
+    "print_rich",
+];
+
+/// Logging calls that carry a script location and are never reported.
+const CONTEXT_PRINTS: &[&str] = &["push_warning", "push_error"];
+
+#[derive(Debug)]
+pub struct NoPrintWithoutContextRule {
+    meta: RuleMetadata,
+    banned: HashSet<String>,
+    allowed: HashSet<String>,
+}
+
+impl Default for NoPrintWithoutContextRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-print-without-context",
+                name: "No Print Without Context",
+                category: RuleCategory::Basic,
+                default_severity: Severity::Info,
+                description:
+                    "Use print_debug, push_warning or push_error instead of bare print calls",
+            },
+            banned: CONTEXTLESS_PRINTS.iter().map(|s| s.to_string()).collect(),
+            allowed: HashSet::new(),
+        }
+    }
+}
+
+/// Whether `node` sits in the body of an `if`/`elif OS.is_debug_build():` branch.
+fn is_under_debug_build_guard(node: Node<'_>, source: &str) -> bool {
+    let mut child = node;
+    while let Some(parent) = child.parent() {
+        if matches!(parent.kind(), "if_statement" | "elif_clause")
+            && parent
+                .child_by_field_name("body")
+                .is_some_and(|body| body.id() == child.id())
+        {
+            let is_debug_guard = parent
+                .child_by_field_name("condition")
+                .and_then(|c| source.get(c.byte_range()))
+                .is_some_and(|c| {
+                    c.chars()
+                        .filter(|ch| !ch.is_whitespace())
+                        .eq("OS.is_debug_build()".chars())
+                });
+            if is_debug_guard {
+                return true;
+            }
+        }
+        child = parent;
+    }
+    false
+}
+
+impl Rule for NoPrintWithoutContextRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(name) = node.child(0).filter(|n| n.kind() == "identifier") else {
+            return;
+        };
+        let name = ctx.node_text(name);
+        if CONTEXT_PRINTS.contains(&name)
+            || self.allowed.contains(name)
+            || !self.banned.contains(name)
+            || is_under_debug_build_guard(node, ctx.source())
+        {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "\"{}\" does not record where it was called; use print_debug, push_warning or push_error",
+            name
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        for (key, list) in [("banned", &mut self.banned), ("allowed", &mut self.allowed)] {
+            let Some(value) = config.options.get(key) else {
+                continue;
+            };
+            let error = || {
+                format!(
+                    "no-print-without-context: '{}' must be a list of strings",
+                    key
+                )
+            };
+            *list = value
+                .as_array()
+                .ok_or_else(error)?
+                .iter()
+                .map(|v| v.as_str().map(str::to_string).ok_or_else(error))
+                .collect::<Result<_, _>>()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::config::RegexRuleConfig;
+use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata};
+
+/// A rule defined in the `[regex_rules]` config section.
+///
+/// Without a `node_kind`, every source line is matched against the pattern.
+/// With one, the pattern is matched against the text of each node of that kind.
+#[derive(Debug)]
+pub struct RegexRule {
+    meta: RuleMetadata,
+    pattern: Regex,
+    message: String,
+    node_kinds: Option<&'static [&'static str]>,
+}
+
+impl RegexRule {
+    /// Build a rule from its config entry, checking that the pattern compiles.
+    ///
+    /// Rule metadata uses `&'static str`, so the id and node kind are leaked.
+    /// Rules are built once per run, so this is bounded by the config size.
+    pub fn from_config(id: &str, config: &RegexRuleConfig) -> Result<Self, String> {
+        let pattern = Regex::new(&config.pattern)
+            .map_err(|e| format!("Invalid pattern for regex rule '{}': {}", id, e))?;
+
+        let node_kinds = config.node_kind.as_deref().map(|kind| {
+            let kind: &'static str = Box::leak(kind.to_string().into_boxed_str());
+            let kinds: &'static [&'static str] = Box::leak(Box::new([kind]));
+            kinds
+        });
+
+        Ok(Self {
+            meta: RuleMetadata {
+                id: Box::leak(id.to_string().into_boxed_str()),
+                name: "Regex Rule",
+                category: RuleCategory::Custom,
+                default_severity: config.severity,
+                description: "Project-specific rule defined in gdtools.toml",
+            },
+            pattern,
+            message: config.message.clone(),
+            node_kinds,
+        })
+    }
+
+    /// Whether an enclosing node of the rule's kind matches too. Its text holds
+    /// this node's, so the outermost matching node reports for both.
+    fn has_matching_ancestor(&self, node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+        let kinds = self.node_kinds.unwrap_or(&[]);
+        let mut current = node.parent();
+        while let Some(ancestor) = current {
+            if kinds.contains(&ancestor.kind()) && self.pattern.is_match(ctx.node_text(ancestor)) {
+                return true;
+            }
+            current = ancestor.parent();
+        }
+        false
+    }
+}
+
+/// Convert a byte offset into a 1-indexed (line, column) pair.
+fn position_at(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    (line, offset - line_start + 1)
+}
+
+impl Rule for RegexRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        // Line mode doesn't look at nodes at all
+        Some(self.node_kinds.unwrap_or(&[]))
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        // Nested nodes of the same kind (a call in another call's arguments)
+        // would report the same span twice
+        if self.has_matching_ancestor(node, ctx) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let text = ctx.node_text(node);
+
+        let diagnostics: Vec<_> = self
+            .pattern
+            .find_iter(text)
+            .map(|m| {
+                let (line, column) = position_at(ctx.source(), node.start_byte() + m.start());
+                Diagnostic::new(self.meta.id, severity, self.message.clone())
+                    .with_location(line, column)
+            })
+            .collect();
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        if self.node_kinds.is_some() {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let source = ctx.source().to_string();
+        let mut diagnostics = Vec::new();
+
+        for (line_idx, line) in source.lines().enumerate() {
+            for m in self.pattern.find_iter(line) {
+                diagnostics.push(
+                    Diagnostic::new(self.meta.id, severity, self.message.clone())
+                        .with_location(line_idx + 1, m.start() + 1),
+                );
+            }
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+}
@@ -1,7 +1,13 @@
+use std::path::{Component, Path, PathBuf};
+
+use regex::Regex;
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
-use crate::lint::{LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::format::{glob_matches_path, glob_to_regex};
+use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::rules::naming::is_class_scope_variable;
+use crate::rules::style::is_virtual_method;
 
 #[derive(Debug)]
 pub struct MaxFunctionArgsRule {
@@ -290,3 +296,764 @@ impl Rule for MaxPublicMethodsRule {
         Ok(())
     }
 }
+
+#[derive(Debug)]
+pub struct MaximumClassMembersRule {
+    meta: RuleMetadata,
+    max_members: usize,
+}
+
+impl Default for MaximumClassMembersRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "maximum-class-members",
+                name: "Maximum Class Members",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "Classes should not declare too many members",
+            },
+            max_members: 50,
+        }
+    }
+}
+
+impl Rule for MaximumClassMembersRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["class_definition", "source_file"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut members = 0;
+
+        let body = if node.kind() == "source_file" {
+            Some(node)
+        } else {
+            node.child_by_field_name("body")
+        };
+
+        if let Some(body) = body {
+            let mut cursor = body.walk();
+            for child in body.children(&mut cursor) {
+                // Inner classes count as one member of the enclosing class
+                if matches!(
+                    child.kind(),
+                    "signal_statement"
+                        | "enum_definition"
+                        | "const_statement"
+                        | "variable_statement"
+                        | "function_definition"
+                        | "constructor_definition"
+                        | "class_definition"
+                ) {
+                    members += 1;
+                }
+            }
+        }
+
+        if members > self.max_members {
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+
+            let class_name = if node.kind() == "source_file" {
+                "<module>"
+            } else {
+                node.child_by_field_name("name")
+                    .map(|n| ctx.node_text(n))
+                    .unwrap_or("<anonymous>")
+            };
+
+            ctx.report_node(
+                node,
+                self.meta.id,
+                severity,
+                format!(
+                    "Class \"{}\" has {} members (max {})",
+                    class_name, members, self.max_members
+                ),
+            );
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(max) = config.options.get("max") {
+            let n = max
+                .as_integer()
+                .filter(|&n| n >= 1)
+                .ok_or("maximum-class-members: 'max' must be an integer of at least 1")?;
+            self.max_members = n as usize;
+        }
+        Ok(())
+    }
+}
+
+/// Which functions a typing rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequireOn {
+    /// Only functions whose name doesn't start with an underscore.
+    Public,
+    /// Every function.
+    All,
+}
+
+impl RequireOn {
+    fn applies_to(&self, name: &str) -> bool {
+        match self {
+            RequireOn::Public => !name.starts_with('_'),
+            RequireOn::All => true,
+        }
+    }
+}
+
+fn configure_require_on(
+    require_on: &mut RequireOn,
+    config: &RuleConfig,
+    rule_id: &str,
+) -> Result<(), String> {
+    if let Some(value) = config.options.get("require_on") {
+        *require_on = match value.as_str() {
+            Some("public") => RequireOn::Public,
+            Some("all") => RequireOn::All,
+            _ => {
+                return Err(format!(
+                    "{}: 'require_on' must be \"public\" or \"all\"",
+                    rule_id
+                ))
+            }
+        };
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct ReturnTypeAnnotationRequiredRule {
+    meta: RuleMetadata,
+    require_on: RequireOn,
+}
+
+impl Default for ReturnTypeAnnotationRequiredRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "return-type-annotation-required",
+                name: "Return Type Annotation Required",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "Functions should declare a return type",
+            },
+            require_on: RequireOn::Public,
+        }
+    }
+}
+
+impl Rule for ReturnTypeAnnotationRequiredRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["function_definition"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if node.child_by_field_name("return_type").is_some() {
+            return;
+        }
+
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        let name = ctx.node_text(name_node);
+
+        // Engine callbacks have fixed signatures
+        if name == "_init" || is_virtual_method(name) || !self.require_on.applies_to(name) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!("Function \"{}\" has no return type annotation", name);
+        ctx.report_node(name_node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        configure_require_on(&mut self.require_on, config, self.meta.id)
+    }
+}
+
+#[derive(Debug)]
+pub struct TypedParametersRequiredRule {
+    meta: RuleMetadata,
+    require_on: RequireOn,
+    ignore_underscore: bool,
+}
+
+impl Default for TypedParametersRequiredRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "typed-parameters-required",
+                name: "Typed Parameters Required",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "Function parameters should have type annotations",
+            },
+            require_on: RequireOn::Public,
+            ignore_underscore: true,
+        }
+    }
+}
+
+impl Rule for TypedParametersRequiredRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["function_definition"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let func_name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(n))
+            .unwrap_or("");
+        if !self.require_on.applies_to(func_name) {
+            return;
+        }
+
+        let Some(params_node) = node.child_by_field_name("parameters") else {
+            return;
+        };
+
+        let mut untyped = Vec::new();
+        let mut cursor = params_node.walk();
+        for child in params_node.children(&mut cursor) {
+            let name_node = match child.kind() {
+                "identifier" => child,
+                "default_parameter" => match child.named_child(0) {
+                    Some(n) if n.kind() == "identifier" => n,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let name = ctx.node_text(name_node);
+            if self.ignore_underscore && name.starts_with('_') {
+                continue;
+            }
+            untyped.push((name.to_string(), child));
+        }
+
+        if untyped.is_empty() {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        for (name, param_node) in untyped {
+            ctx.report_node(
+                param_node,
+                self.meta.id,
+                severity,
+                format!("Parameter \"{}\" has no type annotation", name),
+            );
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        configure_require_on(&mut self.require_on, config, self.meta.id)?;
+        if let Some(ignore) = config.options.get("ignore_underscore") {
+            self.ignore_underscore = ignore
+                .as_bool()
+                .ok_or("typed-parameters-required: 'ignore_underscore' must be a boolean")?;
+        }
+        Ok(())
+    }
+}
+
+/// Signal parameters should be typed, like function parameters. Opinionated, so off
+/// by default.
+#[derive(Debug)]
+pub struct NoUntypedSignalParametersRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoUntypedSignalParametersRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-untyped-signal-parameters",
+                name: "No Untyped Signal Parameters",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "Signal parameters should have type annotations",
+            },
+        }
+    }
+}
+
+impl Rule for NoUntypedSignalParametersRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["signal_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(params_node) = node.child_by_field_name("parameters") else {
+            return;
+        };
+        let signal_name = node
+            .child_by_field_name("name")
+            .map(|n| ctx.node_text(n))
+            .unwrap_or("");
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let mut cursor = params_node.walk();
+        for child in params_node.children(&mut cursor) {
+            // Typed parameters are `typed_parameter`/`typed_identifier` nodes
+            if !matches!(child.kind(), "identifier" | "name") {
+                continue;
+            }
+            let name = ctx.node_text(child);
+            ctx.report_node(
+                child,
+                self.meta.id,
+                severity,
+                format!(
+                    "Parameter \"{}\" of signal \"{}\" has no type annotation",
+                    name, signal_name
+                ),
+            );
+        }
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct NoEmptyElseRule {
+    meta: RuleMetadata,
+    allow_commented: bool,
+}
+
+impl Default for NoEmptyElseRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-empty-else",
+                name: "No Empty Else",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "else/elif branches should not consist solely of pass",
+            },
+            allow_commented: true,
+        }
+    }
+}
+
+impl Rule for NoEmptyElseRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["else_clause", "elif_clause"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut cursor = body.walk();
+        let mut statements = body
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() != "comment");
+        let only_pass = matches!(
+            (statements.next(), statements.next()),
+            (Some(stmt), None) if stmt.kind() == "pass_statement"
+        );
+        if !only_pass {
+            return;
+        }
+
+        // Comments inside the clause (after the colon or around the pass) explain the intent
+        if self.allow_commented && has_comment(node) {
+            return;
+        }
+
+        let keyword = if node.kind() == "elif_clause" {
+            "elif"
+        } else {
+            "else"
+        };
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!("\"{}\" branch only contains pass", keyword);
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(allow) = config.options.get("allow_commented") {
+            self.allow_commented = allow
+                .as_bool()
+                .ok_or("no-empty-else: 'allow_commented' must be a boolean")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check whether any comment appears inside `node`.
+pub(crate) fn has_comment(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|c| c.kind() == "comment" || has_comment(c));
+    found
+}
+
+/// A layering boundary: scripts matching `from` may not reference paths containing `forbidden`.
+#[derive(Debug)]
+struct ImportBoundary {
+    from_glob: String,
+    from: Regex,
+    forbidden: String,
+}
+
+/// The string path argument of a `load("...")` or `preload("...")` call.
+fn load_call_target<'a>(node: Node<'a>, ctx: &LintContext<'_>) -> Option<Node<'a>> {
+    let is_load = node
+        .child(0)
+        .filter(|f| f.kind() == "identifier")
+        .is_some_and(|f| matches!(ctx.node_text(f), "load" | "preload"));
+    if !is_load {
+        return None;
+    }
+    node.child_by_field_name("arguments").and_then(|arguments| {
+        let mut cursor = arguments.walk();
+        let first_arg = arguments
+            .named_children(&mut cursor)
+            .find(|c| c.kind() != "comment");
+        first_arg.filter(|a| a.kind() == "string")
+    })
+}
+
+#[derive(Debug)]
+pub struct DisallowedImportRule {
+    meta: RuleMetadata,
+    boundaries: Vec<ImportBoundary>,
+}
+
+impl Default for DisallowedImportRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "disallowed-import",
+                name: "Disallowed Import",
+                category: RuleCategory::Design,
+                default_severity: Severity::Warning,
+                description:
+                    "Scripts should not extend or load resources across configured boundaries",
+            },
+            boundaries: Vec::new(),
+        }
+    }
+}
+
+impl Rule for DisallowedImportRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["extends_statement", "call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if self.boundaries.is_empty() {
+            return;
+        }
+
+        let target = match node.kind() {
+            "extends_statement" => {
+                let mut cursor = node.walk();
+                let target = node
+                    .named_children(&mut cursor)
+                    .find(|c| c.kind() == "string");
+                target
+            }
+            _ => load_call_target(node, ctx),
+        };
+        let Some(target) = target else {
+            return;
+        };
+        let path = ctx
+            .node_text(target)
+            .trim_matches(|c| c == '"' || c == '\'');
+
+        let file_path = ctx.file_path().to_string_lossy().replace('\\', "/");
+        let Some(boundary) = self
+            .boundaries
+            .iter()
+            .find(|b| path.contains(&b.forbidden) && glob_matches_path(&b.from, &file_path))
+        else {
+            return;
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Scripts in \"{}\" may not reference \"{}\" (found \"{}\")",
+            boundary.from_glob, boundary.forbidden, path
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        const EXPECTED: &str = "disallowed-import: 'boundaries' must be a list of \
+                                { from_glob = \"...\", forbidden_path_substring = \"...\" }";
+
+        let Some(boundaries) = config.options.get("boundaries") else {
+            return Ok(());
+        };
+        self.boundaries = boundaries
+            .as_array()
+            .ok_or(EXPECTED)?
+            .iter()
+            .map(|entry| {
+                let field = |key: &str| entry.get(key).and_then(|v| v.as_str());
+                let (Some(from_glob), Some(forbidden)) =
+                    (field("from_glob"), field("forbidden_path_substring"))
+                else {
+                    return Err(EXPECTED.to_string());
+                };
+                let from = glob_to_regex(from_glob)
+                    .ok_or_else(|| format!("disallowed-import: invalid glob '{}'", from_glob))?;
+                Ok(ImportBoundary {
+                    from_glob: from_glob.to_string(),
+                    from,
+                    forbidden: forbidden.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+}
+
+/// Flags `load`/`preload` calls whose path is the script itself.
+///
+/// Path resolution is best effort. `res://` paths are resolved against
+/// `project_root` when configured, else against the nearest directory above the
+/// script that holds `project.godot`. When neither is available, a `res://` path
+/// matches if the script's path ends with it. Other paths are taken relative to
+/// the script's directory; `uid://` paths are never resolved.
+#[derive(Debug)]
+pub struct NoRecursivePreloadRule {
+    meta: RuleMetadata,
+    project_root: Option<PathBuf>,
+}
+
+impl Default for NoRecursivePreloadRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-recursive-preload",
+                name: "No Recursive Preload",
+                category: RuleCategory::Design,
+                default_severity: Severity::Warning,
+                description: "Scripts should not load or preload themselves",
+            },
+            project_root: None,
+        }
+    }
+}
+
+/// Make a path absolute and resolve `.`/`..`, following symlinks when the path exists.
+fn normalize_path(path: &Path) -> PathBuf {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    };
+    if let Ok(canonical) = absolute.canonicalize() {
+        return canonical;
+    }
+
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// The nearest directory above `file` that contains `project.godot`.
+fn find_project_root(file: &Path) -> Option<PathBuf> {
+    normalize_path(file)
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join("project.godot").is_file())
+        .map(Path::to_path_buf)
+}
+
+impl NoRecursivePreloadRule {
+    fn refers_to_file(&self, target: &str, file: &Path) -> bool {
+        if let Some(relative) = target.strip_prefix("res://") {
+            let root = self
+                .project_root
+                .clone()
+                .or_else(|| find_project_root(file));
+            return match root {
+                Some(root) => normalize_path(&root.join(relative)) == normalize_path(file),
+                None => {
+                    let file = file.to_string_lossy().replace('\\', "/");
+                    file == relative || file.ends_with(&format!("/{}", relative))
+                }
+            };
+        }
+        if target.contains("://") {
+            return false;
+        }
+
+        let dir = file.parent().unwrap_or(Path::new(""));
+        normalize_path(&dir.join(target)) == normalize_path(file)
+    }
+}
+
+impl Rule for NoRecursivePreloadRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(target) = load_call_target(node, ctx) else {
+            return;
+        };
+        let path = ctx
+            .node_text(target)
+            .trim_matches(|c| c == '"' || c == '\'');
+        if path.is_empty() || !self.refers_to_file(path, ctx.file_path()) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Script loads its own path \"{}\", which can cause a load cycle",
+            path
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(root) = config.options.get("project_root") {
+            let root = root
+                .as_str()
+                .ok_or("no-recursive-preload: 'project_root' must be a path")?;
+            self.project_root = Some(PathBuf::from(root));
+        }
+        Ok(())
+    }
+}
+
+/// Flags `preload` calls in function bodies. They still resolve when the script is
+/// compiled, so a class-level const states the dependency where it can be seen.
+#[derive(Debug)]
+pub struct NoPreloadInFunctionBodyRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoPreloadInFunctionBodyRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-preload-in-function-body",
+                name: "No Preload In Function Body",
+                category: RuleCategory::Design,
+                default_severity: Severity::Info,
+                description: "Preload resources in class-level constants, not in functions",
+            },
+        }
+    }
+}
+
+impl Rule for NoPreloadInFunctionBodyRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["call"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        // `load` runs when called, so it legitimately belongs in functions
+        let is_preload = node
+            .child(0)
+            .filter(|f| f.kind() == "identifier")
+            .is_some_and(|f| ctx.node_text(f) == "preload");
+        if !is_preload || is_class_scope_variable(node) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let position = node.start_position();
+        let mut diagnostic = Diagnostic::new(
+            self.meta.id,
+            severity,
+            "preload in a function body, move it to a class-level const",
+        )
+        .with_location(position.row + 1, position.column + 1);
+        // `var Bullet = preload(...)` moves over as is
+        let declared_name = node
+            .parent()
+            .filter(|p| p.kind() == "variable_statement")
+            .and_then(|p| p.child_by_field_name("name"));
+        if let Some(name) = declared_name {
+            diagnostic = diagnostic.with_suggestion(format!(
+                "const {} = {}",
+                ctx.node_text(name),
+                ctx.node_text(node)
+            ));
+        }
+        ctx.report(diagnostic);
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
@@ -0,0 +1,517 @@
+//! Longer explanations for built-in rules, shown by `gdlint explain <rule-id>`.
+
+/// Rationale and example for a built-in rule, keyed by rule id.
+pub fn long_description(rule_id: &str) -> Option<&'static str> {
+    let text = match rule_id {
+        // Naming
+        "function-name" => {
+            "The GDScript style guide names functions in snake_case. A leading underscore marks \
+             a private function or a virtual engine callback.\n\n\
+             Bad:  func GetHealth():\n\
+             Good: func get_health():"
+        }
+        "class-name" => {
+            "Global class names registered with `class_name` are types, and types use PascalCase.\n\n\
+             Bad:  class_name player_controller\n\
+             Good: class_name PlayerController"
+        }
+        "signal-name" => {
+            "Signals are snake_case, usually past-tense verbs describing what happened.\n\n\
+             Bad:  signal HealthChanged\n\
+             Good: signal health_changed"
+        }
+        "signal-should-be-past-tense" => {
+            "A signal announces something that already happened, so the style guide names \
+             signals in the past tense. This is a heuristic: the last word should end in \
+             `ed` or `d`, or be a listed irregular verb such as `hit` or `shown`.\n\n\
+             Bad:  signal door_open\n\
+             Good: signal door_opened\n\n\
+             Configure the accepted endings with `suffixes` and extra names with `exceptions`."
+        }
+        "constant-name" => {
+            "Constants use CONSTANT_CASE so they stand out from variables at the use site.\n\n\
+             Bad:  const maxSpeed = 10\n\
+             Good: const MAX_SPEED = 10"
+        }
+        "enum-name" => {
+            "Named enums are types, so they use PascalCase like classes.\n\n\
+             Bad:  enum element_type { FIRE, WATER }\n\
+             Good: enum ElementType { FIRE, WATER }"
+        }
+        "enum-element-name" => {
+            "Enum members are constants and use CONSTANT_CASE.\n\n\
+             Bad:  enum State { Idle, Running }\n\
+             Good: enum State { IDLE, RUNNING }"
+        }
+        "function-argument-name" => {
+            "Parameters are local variables and follow the same snake_case convention. Prefix \
+             an intentionally unused parameter with an underscore.\n\n\
+             Bad:  func move(Direction):\n\
+             Good: func move(direction):\n\n\
+             With `unused_prefix_must_be_unused = true`, a bare `_` is accepted and a \
+             `_`-prefixed parameter that the body reads is reported at info severity, since \
+             the prefix claims it is unused."
+        }
+        "loop-variable-name" => {
+            "Loop variables are local variables and use snake_case.\n\n\
+             Bad:  for Enemy in enemies:\n\
+             Good: for enemy in enemies:"
+        }
+        "no-single-char-identifier" => {
+            "A one-letter name says nothing about what it holds. Conventional index and \
+             coordinate names (`i`, `j`, `k`, `x`, `y`, `z`) and `_` are allowed.\n\n\
+             Bad:  var v = get_velocity()\n\
+             Good: var velocity = get_velocity()\n\n\
+             Replace the allowed names with `allowed`."
+        }
+        "sub-class-name" => {
+            "Inner classes are types and use PascalCase; a leading underscore marks them private.\n\n\
+             Bad:  class hit_info:\n\
+             Good: class HitInfo:"
+        }
+        "load-constant-name" => {
+            "A constant that holds a loaded resource usually stands for a class or scene, so \
+             PascalCase reads like a type (`Bullet.instantiate()`). CONSTANT_CASE is also \
+             accepted for plain resources.\n\n\
+             Bad:  const bullet_scene = preload(\"res://bullet.tscn\")\n\
+             Good: const BulletScene = preload(\"res://bullet.tscn\")"
+        }
+        "class-variable-name" => {
+            "Member variables use snake_case; a leading underscore marks them private.\n\n\
+             Bad:  var MoveSpeed = 5.0\n\
+             Good: var move_speed = 5.0"
+        }
+        "class-load-variable-name" => {
+            "Member variables that hold a loaded resource may use PascalCase, like a type, or \
+             snake_case like any other variable.\n\n\
+             Bad:  var bullet_Scene = preload(\"res://bullet.tscn\")\n\
+             Good: var BulletScene = preload(\"res://bullet.tscn\")"
+        }
+        "function-variable-name" => {
+            "Local variables use snake_case. An ALL_CAPS local usually wants to be a `const`, \
+             and a PascalCase one is easily mistaken for a type.\n\n\
+             Bad:  var TotalDamage = 0\n\
+             Good: var total_damage = 0"
+        }
+        "function-preload-variable-name" => {
+            "Local variables holding a preloaded resource stand for a type and use PascalCase.\n\n\
+             Bad:  var bullet = preload(\"res://bullet.gd\")\n\
+             Good: var Bullet = preload(\"res://bullet.gd\")"
+        }
+        "no-camelcase-anywhere" => {
+            "A single switch against camelCase in function, variable, parameter and signal \
+             names, with one message and a snake_case suggestion. It overlaps the individual \
+             naming rules, which remain the place to configure patterns. Off by default.\n\n\
+             Bad:  func takeDamage(hitPoints):\n\
+             Good: func take_damage(hit_points):"
+        }
+
+        // Format
+        "max-line-length" => {
+            "Long lines are hard to read side by side and in diffs. Tabs count as `tab_width` \
+             columns. Break long expressions inside parentheses or with `\\`.\n\n\
+             Configure with `max` and `tab_width`."
+        }
+        "trailing-whitespace" => {
+            "Trailing spaces and tabs are invisible, add noise to diffs and are stripped by most \
+             editors anyway."
+        }
+        "mixed-tabs-spaces" => {
+            "GDScript is indentation-sensitive. Mixing tabs and spaces on one line renders \
+             differently across editors and can change which block a line belongs to."
+        }
+        "no-tabs-in-continuation" => {
+            "With tab indentation, aligning code with runs of spaces only lines up at one tab \
+             width. Use a single space, or break the line and indent it with tabs instead. \
+             Strings and comments are left alone, and with `[format] \
+             align_consecutive_assignments` the padding it adds before `=` is allowed.\n\n\
+             Bad:  \tvar x   = 1\n\
+             Good: \tvar x = 1"
+        }
+        "no-inline-tabs" => {
+            "A tab between tokens is as wide as the reader's tab setting, so the line looks \
+             different in every editor. Tabs in strings and comments are left alone.\n\n\
+             Bad:  var x =\t1\n\
+             Good: var x = 1"
+        }
+        "max-file-lines" => {
+            "Very long scripts usually hold several responsibilities. Split them into smaller \
+             scripts, inner classes or child nodes.\n\n\
+             Configure with `max`, `count_blank` and `skip_if_contains` (markers such as \
+             `# GENERATED` that exempt a file)."
+        }
+        "trailing-comma-required-in-multiline" => {
+            "When each element of an array, dictionary or call sits on its own line, a comma \
+             after the last one means adding an element later only touches one line in the \
+             diff. It also keeps `gdformat` from collapsing the literal back onto one line.\n\n\
+             Bad:  var dirs = [\n\t\tVector2.UP,\n\t\tVector2.DOWN\n\t]\n\
+             Good: var dirs = [\n\t\tVector2.UP,\n\t\tVector2.DOWN,\n\t]"
+        }
+        "operator-spacing" => {
+            "Binary operators take one space on each side, as `gdformat` writes them. Wrapped \
+             expressions may break the line next to an operator. Unary `-` and `not`, and \
+             anything inside `# fmt: off`, are not checked.\n\n\
+             Bad:  var total = base+bonus*2\n\
+             Good: var total = base + bonus * 2"
+        }
+
+        // Basic
+        "syntax-error" => {
+            "The parser recovers from malformed code by skipping or inventing tokens, so the \
+             rest of the file can still be linted. Each place it had to do that is reported, \
+             since the other rules are looking at a guess of what the code meant.\n\n\
+             Bad:  func foo(:\n\
+             Good: func foo():"
+        }
+        "unnecessary-pass" => {
+            "`pass` is only needed as a placeholder in an otherwise empty block. Next to other \
+             statements it does nothing.\n\n\
+             Bad:  func f():\n\t\tpass\n\t\tprint(1)\n\
+             Good: func f():\n\t\tprint(1)"
+        }
+        "unused-argument" => {
+            "An unused parameter often means a bug or a leftover from a refactor. If the \
+             signature is fixed (a callback or override), prefix the name with an underscore.\n\n\
+             Bad:  func _on_hit(damage):\n\t\tqueue_free()\n\
+             Good: func _on_hit(_damage):\n\t\tqueue_free()"
+        }
+        "comparison-with-itself" => {
+            "`x == x` is always true and `x != x` always false, except for NaN floats. It is \
+             almost always a typo for another variable. To test for NaN, use `is_nan(x)`.\n\n\
+             Configure with `allow_ne_self` to permit `x != x`."
+        }
+        "no-implicit-int-float-division" => {
+            "In GDScript, `/` between two integers is integer division: `5 / 2` is `2`, \
+             while `5.0 / 2` is `2.5`. Write one operand as a float literal if a fractional \
+             result is intended. Only literal operands are checked, since their types are \
+             certain.\n\n\
+             Bad:  var half = 5 / 2\n\
+             Good: var half = 5.0 / 2"
+        }
+        "duplicated-load" => {
+            "Loading the same path several times in one script repeats work and hides the \
+             dependency. Load it once into a constant and reuse it.\n\n\
+             Good: const Bullet = preload(\"res://bullet.tscn\")"
+        }
+        "no-todo-without-owner" => {
+            "A TODO with no name attached tends to outlive everyone's memory of why it was \
+             written. Naming an owner gives readers someone to ask. This rule is a project \
+             policy and only runs once enabled in the config.\n\n\
+             Bad:  # TODO: handle the empty inventory\n\
+             Good: # TODO(alice): handle the empty inventory\n\n\
+             `keywords` sets the marker words (default TODO, FIXME and HACK). `pattern` is a \
+             regex that the comment must match from the keyword onwards; the default requires \
+             an owner in parentheses right after the keyword."
+        }
+        "expression-not-assigned" => {
+            "A statement made of an expression without side effects, such as `a + b` or `x == 1`, \
+             computes a value and throws it away. It usually means a missing assignment, \
+             return or call.\n\n\
+             Bad:  health - damage\n\
+             Good: health -= damage"
+        }
+        "no-dead-store" => {
+            "A local that is assigned and then assigned again before anything reads it throws \
+             the first value away. Drop the first assignment or use the value. Only statements \
+             in the same block are compared, so stores inside branches are never reported.\n\n\
+             Bad:  var speed = 1\n\tspeed = 2\n\
+             Good: var speed = 2"
+        }
+        "no-return-value-from-void-function" => {
+            "A function declared `-> void` promises callers no value; returning one is an \
+             error in Godot 4.\n\n\
+             Bad:  func reset() -> void:\n\treturn 0\n\
+             Good: func reset() -> void:\n\treturn\n\n\
+             With `inconsistent_returns = true`, a bare `return` in a function that returns a \
+             value elsewhere is also reported, at info."
+        }
+        "no-static-func-accessing-instance-member" => {
+            "A `static func` runs without an instance, so `self` has nothing to refer to and \
+             Godot rejects it. Pass the instance as a parameter or make the function \
+             non-static. Instance members used by bare name are not detected.\n\n\
+             Bad:  static func create():\n\treturn self.duplicate()\n\
+             Good: static func create(from):\n\treturn from.duplicate()"
+        }
+        "no-yield-in-godot4" => {
+            "`yield` was removed in Godot 4. Await signals and coroutines instead.\n\n\
+             Bad:  yield(get_tree(), \"idle_frame\")\n\
+             Good: await get_tree().process_frame"
+        }
+        "no-onready-without-at" => {
+            "Godot 4 replaced the `onready` and `export` keywords with the `@onready` and \
+             `@export` annotations, and rejects the old forms. Export hints move into the \
+             annotation name, e.g. `export(int, 0, 10)` becomes `@export_range(0, 10)`.\n\n\
+             Bad:  onready var sprite = $Sprite2D\n\
+             Good: @onready var sprite = $Sprite2D"
+        }
+        "await-on-literal" => {
+            "`await` suspends until a signal fires or a coroutine returns. Awaiting a plain \
+             value such as a number or string resumes immediately, which usually means the \
+             wrong expression was copied.\n\n\
+             Bad:  await 0.5\n\
+             Good: await get_tree().create_timer(0.5).timeout"
+        }
+        "no-redundant-await" => {
+            "Awaiting the result of an await does nothing more than the first await, and \
+             `await await` is almost always a typo.\n\n\
+             Bad:  var result = await await get_result()\n\
+             Good: var result = await get_result()"
+        }
+        "no-assert-with-side-effects" => {
+            "`assert` only runs in debug builds; release exports strip the whole statement, \
+             including anything its condition does. Calls and assignments inside an assert \
+             silently stop happening in release.\n\n\
+             Bad:  assert(inventory.remove(item))\n\
+             Good: var removed = inventory.remove(item)\n\tassert(removed)"
+        }
+        "no-empty-match-arm" => {
+            "A `match` arm that only contains `pass` ignores its case, which often means the \
+             handling was never written. Handle the case, or say why it is ignored in a \
+             comment. The `_` wildcard arm is allowed by default since it is usually a \
+             deliberate catch-all; set `allow_wildcard_pass = false` to flag it too.\n\n\
+             Bad:  1:\n\t\tpass\n\
+             Good: 1:\n\t\tpass  # Handled by the parent state"
+        }
+        "no-unreachable-match-arm" => {
+            "`match` runs the first arm whose pattern matches, so arms after an unguarded `_` \
+             wildcard never run, and neither does a literal pattern that an earlier arm already \
+             lists. Either is usually a mistake in the order of the arms. Literals are compared \
+             by their text, and arms with a `when` guard don't hide later arms.\n\n\
+             Bad:  _:\n\t\treset()\n\t0:\n\t\tstart()\n\
+             Good: 0:\n\t\tstart()\n\t_:\n\t\treset()"
+        }
+        "no-duplicate-enum-value" => {
+            "Members without a value continue counting from the previous member, so an \
+             explicit value can collide with an earlier one. Two members with the same value \
+             compare equal, and `match` arms or dictionary keys for them overlap. Only integer \
+             literals are checked.\n\n\
+             Bad:  enum State { IDLE = 1, RUNNING = 0, JUMPING }\n\
+             Good: enum State { IDLE, RUNNING, JUMPING }"
+        }
+        "no-global-load-at-parse-time" => {
+            "A member variable initialized with `load()` loads the resource whenever the script \
+             is instantiated, even if it is never used. Use a `const` with `preload` for fixed \
+             paths, or `@onready` to defer the load until the node enters the tree. A \
+             `preload(...)` initializer is resolved when the script is compiled, so it is not \
+             flagged here; no-preload-variable-should-be-const reports it.\n\n\
+             Bad:  var data = load(\"res://data.tres\")\n\
+             Good: @onready var data = load(\"res://data.tres\")"
+        }
+        "no-preload-variable-should-be-const" => {
+            "`preload` is resolved when the script is compiled, so a class variable holding \
+             one never needs to be a variable. A const also makes the preloaded script or \
+             scene usable as a type.\n\n\
+             Bad:  var Bullet = preload(\"res://bullet.tscn\")\n\
+             Good: const Bullet = preload(\"res://bullet.tscn\")"
+        }
+        "no-space-in-node-names" => {
+            "An unquoted `$` path stops at the first space, so `$My Node` gets `$My` and then \
+             fails to parse or does something else. Quote the path.\n\n\
+             Bad:  $My Node\n\
+             Good: $\"My Node\""
+        }
+        "deprecated-api" => {
+            "Many Godot 3 functions were renamed or replaced in Godot 4, and calling the old \
+             name fails at runtime.\n\n\
+             Bad:  var node = scene.instance()\n\
+             Good: var node = scene.instantiate()\n\n\
+             Extend or trim the built-in list with the `renames` table."
+        }
+        "string-should-be-stringname" => {
+            "Signal, method, property, group and input action names are `StringName`s. Passing a \
+             plain String converts it on every call, and a `&\"...\"` literal avoids that. \
+             This rule is opinionated and only runs once enabled in the config.\n\n\
+             Bad:  Input.is_action_pressed(\"jump\")\n\
+             Good: Input.is_action_pressed(&\"jump\")\n\n\
+             Configure the checked methods with `apis`."
+        }
+        "no-connect-with-string-method-name" => {
+            "Godot 4 signals are objects: `button.pressed.connect(_on_pressed)` is checked by \
+             the editor, while `connect(\"pressed\", ...)` with a misspelled name only fails \
+             at runtime. Calls whose signal name is not a string literal are not reported.\n\n\
+             Bad:  button.connect(\"pressed\", _on_pressed)\n\
+             Good: button.pressed.connect(_on_pressed)"
+        }
+        "signal-connected-but-not-declared" => {
+            "A signal used on the script itself, as in `hit.emit()`, `hit.connect(...)` or \
+             `emit_signal(\"hit\")`, but declared by no `signal` statement in the file is \
+             often a typo. Signals inherited from a base class are declared elsewhere, so \
+             this is a heuristic: common engine signals such as `pressed` and `timeout` are \
+             accepted, and other inherited or autoload signals can be listed in `allowed`. \
+             PascalCase receivers and names the file declares as variables are skipped.\n\n\
+             Bad:  signal hit\n\tfunc f():\n\thti.emit()\n\
+             Good: signal hit\n\tfunc f():\n\thit.emit()"
+        }
+        "no-empty-signal-name-in-emit" => {
+            "`emit_signal(\"\")` names no signal, so it only fails when it runs. It is usually \
+             a name deleted by mistake. In Godot 4, emitting through the signal itself lets \
+             the editor check the name.\n\n\
+             Bad:  emit_signal(\"\")\n\
+             Good: died.emit()"
+        }
+        "no-string-node-path-for-get-node" => {
+            "`$Path` and `%Name` are the idiomatic ways to reach child nodes; they are shorter \
+             than `get_node(\"Path\")` and editors can complete them. Scene-unique `%` names \
+             also keep working when the node moves in the tree. Only calls with a literal path \
+             on `self` are reported; paths built at runtime are left alone.\n\n\
+             Bad:  var sprite = get_node(\"Body/Sprite2D\")\n\
+             Good: var sprite = $Body/Sprite2D\n\n\
+             Set `prefer = \"unique\"` to suggest `%Sprite2D` for `get_node(\"Sprite2D\")` \
+             instead. Paths with more than a child's name, such as `Body/Sprite2D` or \
+             `../Hud`, still get `$`."
+        }
+        "no-print-without-context" => {
+            "`print` and its variants write a bare message, so a stray line in the output gives \
+             no hint of which script produced it. `print_debug` adds the file and line, and \
+             `push_warning`/`push_error` also show up in the debugger. Calls inside an \
+             `if OS.is_debug_build():` branch are allowed. This rule is opinionated and only \
+             runs once enabled in the config.\n\n\
+             Bad:  print(\"spawned \", enemy)\n\
+             Good: print_debug(\"spawned \", enemy)\n\n\
+             Configure the reported functions with `banned`, and exempt some of them with \
+             `allowed`. `push_warning` and `push_error` are never reported."
+        }
+
+        // Design
+        "max-function-args" => {
+            "Functions with many parameters are hard to call correctly. Group related values \
+             into a Resource, Dictionary or inner class.\n\n\
+             Configure with `max`."
+        }
+        "max-returns" => {
+            "Many return points make a function hard to follow. Split it up, or compute the \
+             result in a variable and return it once.\n\n\
+             Configure with `max`."
+        }
+        "max-public-methods" => {
+            "A class with a large public API is doing too much. Move related methods into a \
+             separate class or node.\n\n\
+             Configure with `max`."
+        }
+        "maximum-class-members" => {
+            "A class that declares dozens of signals, variables and functions usually mixes \
+             several responsibilities. Every top-level member counts, including private ones; \
+             an inner class counts once for its parent and is checked on its own.\n\n\
+             Configure with `max` (default 50)."
+        }
+        "return-type-annotation-required" => {
+            "Return type annotations document the function, let the editor complete the \
+             result and make the analyzer catch mismatches.\n\n\
+             Bad:  func get_speed():\n\
+             Good: func get_speed() -> float:\n\n\
+             Configure with `require_on` (`public` or `all`)."
+        }
+        "typed-parameters-required" => {
+            "Typed parameters document the function and let the analyzer catch wrong arguments \
+             at the call site.\n\n\
+             Bad:  func take_damage(amount):\n\
+             Good: func take_damage(amount: int):\n\n\
+             Configure with `require_on`."
+        }
+        "no-untyped-signal-parameters" => {
+            "Typed signal parameters document what the signal carries and let the editor check \
+             connected handlers and `emit` arguments. Off by default.\n\n\
+             Bad:  signal hit(damage)\n\
+             Good: signal hit(damage: int)"
+        }
+        "no-empty-else" => {
+            "An `else` or `elif` branch that only contains `pass` does nothing, which often means \
+             logic was forgotten. Remove the branch, or explain why it is empty in a comment.\n\n\
+             Bad:  else:\n\t\tpass\n\
+             Good: else:\n\t\tpass  # Already handled by the signal\n\n\
+             Set `allow_commented = false` to flag commented branches too."
+        }
+        "disallowed-import" => {
+            "Keeping layers apart (for example UI code never reaching into gameplay scripts) \
+             makes a project easier to change. Each configured boundary forbids scripts \
+             matching `from_glob` from extending, loading or preloading a path that contains \
+             `forbidden_path_substring`. The rule does nothing until boundaries are set.\n\n\
+             Bad (in ui/hud.gd):  const Player = preload(\"res://gameplay/player.gd\")\n\
+             Good (in ui/hud.gd): signal health_shown(amount: int)"
+        }
+        "no-recursive-preload" => {
+            "A script that loads or preloads its own path creates a load cycle, which Godot \
+             reports as an error or resolves to a half-initialised resource. Use the class \
+             name or `get_script()` instead.\n\n\
+             Bad (in player.gd):  const Self = preload(\"res://player.gd\")\n\
+             Good (in player.gd): class_name Player\n\n\
+             Path resolution is best effort. `res://` paths are resolved against the \
+             `project_root` option, or else the nearest directory containing `project.godot`. \
+             Without either, a `res://` path matches when the script's path ends with it. \
+             Relative paths are resolved from the script's directory and `uid://` paths are \
+             ignored."
+        }
+        "no-preload-in-function-body" => {
+            "`preload` resolves when the script is compiled wherever it is written, so a call \
+             inside a function gains nothing at runtime and hides a dependency in the body. \
+             Declare it as a class-level const instead. Lambdas and `_init` count as function \
+             bodies. `load` runs when called and is not reported.\n\n\
+             Bad:  func spawn():\n\tvar Bullet = preload(\"res://bullet.tscn\")\n\
+             Good: const Bullet = preload(\"res://bullet.tscn\")"
+        }
+
+        // Style
+        "class-definitions-order" => {
+            "The style guide orders class members: signals, enums, constants, exported, public \
+             and private variables, onready variables, then methods with virtual callbacks \
+             first. A consistent order makes scripts easy to scan.\n\n\
+             `gdformat --reorder` can fix this automatically. Project lifecycle methods listed \
+             in `[format] additional_virtual_methods` are ordered like engine callbacks by both."
+        }
+        "no-elif-return" => {
+            "When the `if` branch returns, a following `elif` can be a plain `if`, which keeps \
+             the branches independent and flat.\n\n\
+             Bad:  if a:\n\t\treturn 1\n\telif b:\n\t\treturn 2\n\
+             Good: if a:\n\t\treturn 1\n\tif b:\n\t\treturn 2"
+        }
+        "no-else-return" => {
+            "When the `if` branch returns, the `else` is redundant. Dedent its body to reduce \
+             nesting. Inside a loop, the same applies to branches ending in `break` or \
+             `continue`.\n\n\
+             Bad:  if a:\n\t\treturn 1\n\telse:\n\t\treturn 2\n\
+             Good: if a:\n\t\treturn 1\n\treturn 2"
+        }
+        "no-comparison-to-bool-literal" => {
+            "Comparing a boolean with `true` or `false` is redundant. Use the value directly, \
+             or `not`.\n\n\
+             Bad:  if is_dead == true:\n\
+             Good: if is_dead:"
+        }
+        "no-comparison-chaining" => {
+            "GDScript does not expand `a < b < c` into two comparisons the way Python does; it \
+             compares the result of `a < b` with `c`. Write both comparisons and join them \
+             with `and`.\n\n\
+             Bad:  if 0 < x < 10:\n\
+             Good: if 0 < x and x < 10:"
+        }
+        "no-multiple-statements-per-line" => {
+            "Statements joined with `;` are easy to miss when reading and show up as a single \
+             change in diffs. Put each statement on its own line.\n\n\
+             Bad:  x = 1; y = 2\n\
+             Good: x = 1\n\ty = 2\n\n\
+             Set `check_single_line_bodies = true` to also flag bodies written on the same \
+             line as their `if`, `elif`, `else`, `for` or `while`, such as `if ready: start()`."
+        }
+        "const-could-be-used" => {
+            "A member variable that is initialized once and never reassigned is effectively a \
+             constant. Declaring it `const` documents that and lets it be used in constant \
+             expressions.\n\n\
+             `gdlint lint --fix` rewrites `var` to `const` when the initializer is a literal \
+             or a `preload(...)`; other initializers are left for you to review. Initializers \
+             that call anything else are skipped, and so are array and dictionary \
+             initializers: a const container is read-only, so `append` or `erase` calls on it \
+             would fail. The check only sees this file, so a public variable written by \
+             another script is still reported; set `fix_private_only = true` to only rewrite \
+             `_`-prefixed variables.\n\n\
+             Bad:  var max_speed = 10\n\
+             Good: const MAX_SPEED = 10"
+        }
+        "no-redundant-type-cast" => {
+            "Casting a literal to the type it already has does nothing.\n\n\
+             Bad:  var x = 1 as int\n\
+             Good: var x = 1"
+        }
+        _ => return None,
+    };
+    Some(text)
+}
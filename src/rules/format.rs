@@ -1,6 +1,7 @@
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
+use crate::format::{has_trailing_comma, visual_width, SkipRegions};
 use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
 
 #[derive(Debug)]
@@ -46,7 +47,7 @@ impl Rule for MaxLineLengthRule {
         let mut diagnostics = Vec::new();
 
         for (line_idx, line) in source.lines().enumerate() {
-            let visual_length = self.calculate_visual_length(line);
+            let visual_length = visual_width(line, self.tab_width);
 
             if visual_length > self.max_length {
                 let line_num = line_idx + 1;
@@ -89,20 +90,6 @@ impl Rule for MaxLineLengthRule {
     }
 }
 
-impl MaxLineLengthRule {
-    fn calculate_visual_length(&self, line: &str) -> usize {
-        let mut length = 0;
-        for c in line.chars() {
-            if c == '\t' {
-                length += self.tab_width - (length % self.tab_width);
-            } else {
-                length += 1;
-            }
-        }
-        length
-    }
-}
-
 #[derive(Debug)]
 pub struct TrailingWhitespaceRule {
     meta: RuleMetadata,
@@ -226,12 +213,238 @@ impl Rule for MixedTabsSpacesRule {
     }
 }
 
+#[derive(Debug)]
+pub struct NoTabsInContinuationRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoTabsInContinuationRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-tabs-in-continuation",
+                name: "No Alignment After Tab Indent",
+                category: RuleCategory::Format,
+                default_severity: Severity::Info,
+                description: "Tab-indented lines should not align code with runs of spaces",
+            },
+        }
+    }
+}
+
+/// Find the first run of two or more spaces that precedes code (not a comment),
+/// skipping string literals and comments. `code` starts at byte `code_start` of
+/// the file and `literals` holds the file's literal ranges, so strings spanning
+/// several lines are skipped too. With `allow_assignment_padding`, a run before
+/// `=` or `:=` is the formatter's assignment alignment and is skipped. Returns
+/// the 0-indexed byte column of the run.
+fn find_alignment_spaces(
+    code: &str,
+    code_start: usize,
+    literals: &[std::ops::Range<usize>],
+    allow_assignment_padding: bool,
+) -> Option<usize> {
+    let bytes = code.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let offset = code_start + i;
+        if bytes[i] != b' ' || literals.iter().any(|range| range.contains(&offset)) {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+        let rest = &code[i..];
+        let assignment =
+            rest.starts_with(":=") || (rest.starts_with('=') && !rest.starts_with("=="));
+        if i - start >= 2
+            && !rest.is_empty()
+            && !rest.starts_with('#')
+            && !(allow_assignment_padding && assignment)
+        {
+            return Some(start);
+        }
+    }
+
+    None
+}
+
+impl Rule for NoTabsInContinuationRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+
+        // `align_consecutive_assignments` pads before `=` on purpose
+        let allow_assignment_padding = ctx
+            .config()
+            .format
+            .align_consecutive_assignments
+            .unwrap_or(false);
+        let mut literals = Vec::new();
+        collect_literal_ranges(ctx.tree().root_node(), &mut literals);
+
+        let source = ctx.source().to_string();
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+
+        for (line_idx, line) in source.split('\n').enumerate() {
+            let line_len = line.len();
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let code = line.trim_start_matches('\t');
+            let indent_len = line.len() - code.len();
+            // Only tab-indented lines; space indentation is mixed-tabs-spaces' concern
+            let alignment = if indent_len == 0 || code.starts_with(' ') {
+                None
+            } else {
+                find_alignment_spaces(
+                    code,
+                    line_start + indent_len,
+                    &literals,
+                    allow_assignment_padding,
+                )
+            };
+
+            if let Some(column) = alignment {
+                let diagnostic = Diagnostic::new(
+                    self.meta.id,
+                    severity,
+                    "Spaces used for alignment after tab indentation",
+                )
+                .with_location(line_idx + 1, indent_len + column + 1);
+
+                diagnostics.push(diagnostic);
+            }
+            line_start += line_len + 1;
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags tabs between tokens, after a line's indentation. Leading tabs are
+/// indentation and trailing ones are `trailing-whitespace`'s concern.
+#[derive(Debug)]
+pub struct NoInlineTabsRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoInlineTabsRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-inline-tabs",
+                name: "No Inline Tabs",
+                category: RuleCategory::Format,
+                default_severity: Severity::Info,
+                description: "Tabs should only be used for indentation",
+            },
+        }
+    }
+}
+
+/// Byte ranges of strings and comments, where tabs are content rather than spacing.
+fn collect_literal_ranges(node: Node<'_>, out: &mut Vec<std::ops::Range<usize>>) {
+    if matches!(
+        node.kind(),
+        "string" | "string_name" | "node_path" | "comment"
+    ) {
+        out.push(node.start_byte()..node.end_byte());
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_literal_ranges(child, out);
+    }
+}
+
+impl Rule for NoInlineTabsRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let mut literals = Vec::new();
+        collect_literal_ranges(ctx.tree().root_node(), &mut literals);
+
+        let source = ctx.source().to_string();
+        let mut diagnostics = Vec::new();
+        let mut line_start = 0;
+        for (line_idx, line) in source.split('\n').enumerate() {
+            let code = line.trim_end();
+            let indent_len = code.len() - code.trim_start().len();
+            let tab = code
+                .bytes()
+                .enumerate()
+                .skip(indent_len)
+                .map(|(i, b)| (i, b, line_start + i))
+                .find(|&(_, b, offset)| {
+                    b == b'\t' && !literals.iter().any(|range| range.contains(&offset))
+                });
+            if let Some((column, _, _)) = tab {
+                diagnostics.push(
+                    Diagnostic::new(
+                        self.meta.id,
+                        severity,
+                        "Tab used for spacing after indentation, use a space",
+                    )
+                    .with_location(line_idx + 1, column + 1),
+                );
+            }
+            line_start += line.len() + 1;
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct MaxFileLinesRule {
     meta: RuleMetadata,
     max_lines: usize,
+    /// Files with any of these markers near the top (e.g. `# GENERATED`) are skipped.
+    skip_if_contains: Vec<String>,
+    count_blank: bool,
 }
 
+/// How many lines from the top of the file are searched for skip markers.
+const SKIP_MARKER_LINES: usize = 10;
+
 impl Default for MaxFileLinesRule {
     fn default() -> Self {
         Self {
@@ -243,6 +456,8 @@ impl Default for MaxFileLinesRule {
                 description: "Files should not exceed the maximum number of lines",
             },
             max_lines: 1000,
+            skip_if_contains: Vec::new(),
+            count_blank: true,
         }
     }
 }
@@ -259,7 +474,24 @@ impl Rule for MaxFileLinesRule {
     fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
 
     fn check_file_start(&self, ctx: &mut LintContext<'_>) {
-        let line_count = ctx.source().lines().count();
+        let is_generated = ctx.source().lines().take(SKIP_MARKER_LINES).any(|line| {
+            self.skip_if_contains
+                .iter()
+                .any(|m| line.contains(m.as_str()))
+        });
+        if is_generated {
+            return;
+        }
+
+        // Source line numbers (1-indexed) of the lines that count toward the limit
+        let counted: Vec<usize> = ctx
+            .source()
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| self.count_blank || !line.trim().is_empty())
+            .map(|(idx, _)| idx + 1)
+            .collect();
+        let line_count = counted.len();
 
         if line_count > self.max_lines {
             let severity = ctx
@@ -270,7 +502,7 @@ impl Rule for MaxFileLinesRule {
                 severity,
                 format!("File has {} lines (max {})", line_count, self.max_lines),
             )
-            .with_location(self.max_lines + 1, 1);
+            .with_location(counted[self.max_lines], 1);
 
             ctx.report(diagnostic);
         }
@@ -287,6 +519,226 @@ impl Rule for MaxFileLinesRule {
                 self.max_lines = n as usize;
             }
         }
+        if let Some(markers) = config.options.get("skip_if_contains") {
+            let markers = markers
+                .as_array()
+                .ok_or("max-file-lines: 'skip_if_contains' must be a list of strings")?;
+            self.skip_if_contains = markers
+                .iter()
+                .map(|m| {
+                    m.as_str().map(str::to_string).ok_or_else(|| {
+                        "max-file-lines: 'skip_if_contains' must be a list of strings".to_string()
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+        }
+        if let Some(count_blank) = config.options.get("count_blank") {
+            self.count_blank = count_blank
+                .as_bool()
+                .ok_or("max-file-lines: 'count_blank' must be a boolean")?;
+        }
         Ok(())
     }
 }
+
+/// Flags multiline arrays, dictionaries and call arguments whose closing bracket
+/// sits on its own line without a comma after the last element.
+///
+/// Only applies when the closing bracket is on a later line than the last element;
+/// `foo(a,\n    b)` is left alone since a trailing comma there doesn't help diffs.
+#[derive(Debug)]
+pub struct TrailingCommaRequiredRule {
+    meta: RuleMetadata,
+}
+
+impl Default for TrailingCommaRequiredRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "trailing-comma-required-in-multiline",
+                name: "Trailing Comma Required In Multiline",
+                category: RuleCategory::Format,
+                default_severity: Severity::Info,
+                description:
+                    "Multiline collections and call arguments should end with a trailing comma",
+            },
+        }
+    }
+}
+
+impl Rule for TrailingCommaRequiredRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["array", "dictionary", "arguments"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if node.start_position().row == node.end_position().row || has_trailing_comma(node) {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        let Some(close_idx) = children
+            .iter()
+            .rposition(|c| matches!(c.kind(), "]" | "}" | ")"))
+        else {
+            return;
+        };
+        let close = children[close_idx];
+
+        // A comment between the last element and the bracket hides the comma from
+        // `has_trailing_comma`, so look past it
+        let Some(last) = children[..close_idx]
+            .iter()
+            .rev()
+            .find(|c| c.kind() != "comment")
+        else {
+            return;
+        };
+        if last.kind() == "," || matches!(last.kind(), "[" | "{" | "(") {
+            return;
+        }
+        if last.end_position().row == close.start_position().row {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let what = match node.kind() {
+            "array" => "array",
+            "dictionary" => "dictionary",
+            _ => "argument list",
+        };
+        ctx.report_node(
+            close,
+            self.meta.id,
+            severity,
+            format!("Multiline {} should end with a trailing comma", what),
+        );
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags binary operators without a space on each side, like `a+b` or `x==1`,
+/// mirroring what `gdformat` would change. Keyword operators (`and`, `in`, ...) and
+/// unary operators are not checked, and `# fmt: off` regions are skipped.
+#[derive(Debug)]
+pub struct OperatorSpacingRule {
+    meta: RuleMetadata,
+}
+
+impl Default for OperatorSpacingRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "operator-spacing",
+                name: "Operator Spacing",
+                category: RuleCategory::Format,
+                default_severity: Severity::Info,
+                description: "Binary operators should have a single space on each side",
+            },
+        }
+    }
+}
+
+impl Rule for OperatorSpacingRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        // Parse `# fmt: off` regions once per file rather than per operator
+        let skip_regions = SkipRegions::parse(ctx.source());
+        let mut operators = Vec::new();
+        collect_operators(ctx.tree().root_node(), &mut operators);
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let bytes = ctx.source().as_bytes();
+        let mut diagnostics = Vec::new();
+        for op in operators {
+            let line = op.start_position().row + 1;
+            if skip_regions.is_skipped(line) {
+                continue;
+            }
+            let text = ctx.node_text(op);
+            if text.is_empty() || text.chars().any(char::is_alphabetic) {
+                continue;
+            }
+
+            // An operator starting a continuation line is preceded by its indentation,
+            // which may be tabs
+            let before = if starts_line(bytes, op.start_byte()) {
+                Some(b'\n')
+            } else {
+                op.start_byte().checked_sub(1).map(|i| bytes[i])
+            };
+            let after = bytes.get(op.end_byte()).copied();
+            let problem = if before == Some(b'\t') || after == Some(b'\t') {
+                "Use a space, not a tab, around operator"
+            } else if !before.is_some_and(is_operator_gap) || !after.is_some_and(is_operator_gap) {
+                "Missing space around operator"
+            } else {
+                continue;
+            };
+            diagnostics.push(
+                Diagnostic::new(self.meta.id, severity, format!("{} '{}'", problem, text))
+                    .with_location(line, op.start_position().column + 1),
+            );
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Whether a byte next to an operator separates it properly: a space, or a line
+/// break when the expression is wrapped.
+fn is_operator_gap(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\n' | b'\r')
+}
+
+/// Whether only indentation comes before `offset` on its line.
+fn starts_line(bytes: &[u8], offset: usize) -> bool {
+    bytes[..offset]
+        .iter()
+        .rev()
+        .take_while(|&&b| b != b'\n')
+        .all(|&b| b == b' ' || b == b'\t')
+}
+
+/// Collect the operator tokens of every binary, comparison and boolean operation.
+fn collect_operators<'a>(node: Node<'a>, operators: &mut Vec<Node<'a>>) {
+    if matches!(
+        node.kind(),
+        "binary_operator" | "comparison_operator" | "boolean_operator"
+    ) {
+        let mut cursor = node.walk();
+        operators.extend(node.children(&mut cursor).filter(|c| !c.is_named()));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_operators(child, operators);
+    }
+}
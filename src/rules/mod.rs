@@ -1,9 +1,12 @@
 pub mod basic;
+pub mod custom;
 pub mod design;
+pub mod docs;
 pub mod format;
 pub mod naming;
 pub mod style;
 
+use crate::config::Config;
 use crate::lint::Rule;
 
 pub fn all_rules() -> Vec<Box<dyn Rule>> {
@@ -12,35 +15,97 @@ pub fn all_rules() -> Vec<Box<dyn Rule>> {
         Box::new(naming::FunctionNameRule::default()),
         Box::new(naming::ClassNameRule::default()),
         Box::new(naming::SignalNameRule::default()),
+        Box::new(naming::SignalPastTenseRule::default()),
         Box::new(naming::ConstantNameRule::default()),
         Box::new(naming::EnumNameRule::default()),
         Box::new(naming::EnumElementNameRule::default()),
         Box::new(naming::FunctionArgumentNameRule::default()),
         Box::new(naming::LoopVariableNameRule::default()),
+        Box::new(naming::NoSingleCharIdentifierRule::default()),
         Box::new(naming::SubClassNameRule::default()),
         Box::new(naming::LoadConstantNameRule::default()),
         Box::new(naming::ClassVariableNameRule::default()),
         Box::new(naming::ClassLoadVariableNameRule::default()),
         Box::new(naming::FunctionVariableNameRule::default()),
         Box::new(naming::FunctionPreloadVariableNameRule::default()),
+        Box::new(naming::NoCamelCaseAnywhereRule::default()),
         // Format rules
         Box::new(format::MaxLineLengthRule::default()),
         Box::new(format::TrailingWhitespaceRule::default()),
         Box::new(format::MixedTabsSpacesRule::default()),
+        Box::new(format::NoTabsInContinuationRule::default()),
+        Box::new(format::NoInlineTabsRule::default()),
         Box::new(format::MaxFileLinesRule::default()),
+        Box::new(format::TrailingCommaRequiredRule::default()),
+        Box::new(format::OperatorSpacingRule::default()),
         // Basic rules
+        Box::new(basic::SyntaxErrorRule::default()),
         Box::new(basic::UnnecessaryPassRule::default()),
         Box::new(basic::UnusedArgumentRule::default()),
         Box::new(basic::ComparisonWithItselfRule::default()),
+        Box::new(basic::NoImplicitIntFloatDivisionRule::default()),
         Box::new(basic::DuplicatedLoadRule::default()),
+        Box::new(basic::NoTodoWithoutOwnerRule::default()),
         Box::new(basic::ExpressionNotAssignedRule::default()),
+        Box::new(basic::NoDeadStoreRule::default()),
+        Box::new(basic::NoReturnValueFromVoidFunctionRule::default()),
+        Box::new(basic::NoStaticFuncAccessingInstanceMemberRule::default()),
+        Box::new(basic::NoYieldInGodot4Rule::default()),
+        Box::new(basic::NoOnreadyWithoutAtRule::default()),
+        Box::new(basic::AwaitOnLiteralRule::default()),
+        Box::new(basic::NoRedundantAwaitRule::default()),
+        Box::new(basic::NoAssertWithSideEffectsRule::default()),
+        Box::new(basic::NoEmptyMatchArmRule::default()),
+        Box::new(basic::NoUnreachableMatchArmRule::default()),
+        Box::new(basic::NoDuplicateEnumValueRule::default()),
+        Box::new(basic::NoGlobalLoadAtParseTimeRule::default()),
+        Box::new(basic::NoPreloadVariableShouldBeConstRule::default()),
+        Box::new(basic::NoSpaceInNodeNamesRule::default()),
+        Box::new(basic::DeprecatedApiRule::default()),
+        Box::new(basic::StringShouldBeStringNameRule::default()),
+        Box::new(basic::NoPrintWithoutContextRule::default()),
+        Box::new(basic::NoStringNodePathRule::default()),
+        Box::new(basic::NoConnectWithStringRule::default()),
+        Box::new(basic::SignalConnectedButNotDeclaredRule::default()),
+        Box::new(basic::NoEmptySignalNameInEmitRule::default()),
         // Design rules
         Box::new(design::MaxFunctionArgsRule::default()),
         Box::new(design::MaxReturnsRule::default()),
         Box::new(design::MaxPublicMethodsRule::default()),
+        Box::new(design::MaximumClassMembersRule::default()),
+        Box::new(design::ReturnTypeAnnotationRequiredRule::default()),
+        Box::new(design::TypedParametersRequiredRule::default()),
+        Box::new(design::NoUntypedSignalParametersRule::default()),
+        Box::new(design::NoEmptyElseRule::default()),
+        Box::new(design::DisallowedImportRule::default()),
+        Box::new(design::NoRecursivePreloadRule::default()),
+        Box::new(design::NoPreloadInFunctionBodyRule::default()),
         // Style rules
         Box::new(style::ClassDefinitionsOrderRule::default()),
         Box::new(style::NoElifReturnRule::default()),
         Box::new(style::NoElseReturnRule::default()),
+        Box::new(style::NoComparisonToBoolLiteralRule::default()),
+        Box::new(style::NoComparisonChainingRule::default()),
+        Box::new(style::NoMultipleStatementsPerLineRule::default()),
+        Box::new(style::ConstCouldBeUsedRule::default()),
+        Box::new(style::NoRedundantTypeCastRule::default()),
     ]
 }
+
+/// Build the project-specific rules from the config's `[regex_rules]` section.
+pub fn regex_rules(config: &Config) -> Result<Vec<Box<dyn Rule>>, String> {
+    let builtin = all_rules();
+    config
+        .regex_rules
+        .iter()
+        .map(|(id, rule_config)| {
+            if builtin.iter().any(|r| r.meta().id == id) {
+                return Err(format!(
+                    "Regex rule '{}' conflicts with a built-in rule",
+                    id
+                ));
+            }
+            custom::RegexRule::from_config(id, rule_config).map(|r| Box::new(r) as Box<dyn Rule>)
+        })
+        .collect()
+}
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
-use crate::lint::{LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::rules::basic::collect_used_identifiers;
 
 static SNAKE_CASE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^_?[a-z][a-z0-9_]*$").unwrap());
 static PASCAL_CASE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Z][A-Za-z0-9]*$").unwrap());
@@ -16,6 +19,8 @@ static SIGNAL_HANDLER: Lazy<Regex> =
 static LOAD_CONSTANT: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^_?([A-Z][A-Za-z0-9]*|[A-Z][A-Z0-9_]*)$").unwrap());
 // PascalCase or snake_case (for class load variables)
+static CAMEL_CASE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^_?[a-z][a-z0-9]*[A-Z][A-Za-z0-9]*$").unwrap());
 static PASCAL_OR_SNAKE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^(_?[A-Z][A-Za-z0-9]*|_?[a-z][a-z0-9_]*)$").unwrap());
 
@@ -27,6 +32,25 @@ fn configure_pattern(pattern: &mut Regex, config: &RuleConfig) -> Result<(), Str
     Ok(())
 }
 
+/// Helper to read a list of strings option from rule config.
+pub(crate) fn configure_string_list(
+    config: &RuleConfig,
+    rule_id: &str,
+    key: &str,
+) -> Result<Option<Vec<String>>, String> {
+    let Some(value) = config.options.get(key) else {
+        return Ok(None);
+    };
+    let error = || format!("{}: '{}' must be a list of strings", rule_id, key);
+    value
+        .as_array()
+        .ok_or_else(error)?
+        .iter()
+        .map(|v| v.as_str().map(str::to_string).ok_or_else(error))
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
 /// Macro to generate simple naming rules that check a "name" field against a pattern.
 macro_rules! simple_naming_rule {
     (
@@ -250,6 +274,8 @@ impl Rule for EnumElementNameRule {
 pub struct FunctionArgumentNameRule {
     meta: RuleMetadata,
     pattern: Regex,
+    /// Accept a bare `_` and flag `_`-prefixed parameters the body reads.
+    unused_prefix_must_be_unused: bool,
 }
 
 impl Default for FunctionArgumentNameRule {
@@ -263,6 +289,7 @@ impl Default for FunctionArgumentNameRule {
                 description: "Function arguments should be snake_case",
             },
             pattern: SNAKE_CASE.clone(),
+            unused_prefix_must_be_unused: false,
         }
     }
 }
@@ -277,6 +304,9 @@ impl Rule for FunctionArgumentNameRule {
     }
 
     fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        // Only computed when needed: the names the enclosing function's body reads
+        let mut used_names = None;
+
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
             let name_node = match child.kind() {
@@ -287,7 +317,8 @@ impl Rule for FunctionArgumentNameRule {
 
             if let Some(name_node) = name_node {
                 let name = ctx.node_text(name_node);
-                if !self.pattern.is_match(name) {
+                let bare_underscore = self.unused_prefix_must_be_unused && name == "_";
+                if !bare_underscore && !self.pattern.is_match(name) {
                     let severity = ctx
                         .config()
                         .get_rule_severity(self.meta.id, self.meta.default_severity);
@@ -297,12 +328,35 @@ impl Rule for FunctionArgumentNameRule {
                         severity,
                         format!("Function argument \"{}\" should be snake_case", name),
                     );
+                    continue;
+                }
+
+                if self.unused_prefix_must_be_unused && name.starts_with('_') {
+                    let Some(func) = node.parent() else {
+                        continue;
+                    };
+                    let used =
+                        used_names.get_or_insert_with(|| collect_used_identifiers(func, ctx));
+                    if used.contains(name) {
+                        // Less severe than a naming violation unless configured otherwise
+                        let severity = ctx.config().get_rule_severity(self.meta.id, Severity::Info);
+                        let message = format!(
+                            "Function argument \"{}\" is marked unused with \"_\" but is used; drop the prefix",
+                            name
+                        );
+                        ctx.report_node(name_node, self.meta.id, severity, message);
+                    }
                 }
             }
         }
     }
 
     fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(value) = config.options.get("unused_prefix_must_be_unused") {
+            self.unused_prefix_must_be_unused = value.as_bool().ok_or(
+                "function-argument-name: 'unused_prefix_must_be_unused' must be a boolean",
+            )?;
+        }
         configure_pattern(&mut self.pattern, config)
     }
 }
@@ -363,6 +417,180 @@ impl Rule for LoopVariableNameRule {
     }
 }
 
+/// Single-letter names conventionally used for indices and coordinates.
+const SINGLE_CHAR_ALLOWED: &[&str] = &["i", "j", "k", "x", "y", "z"];
+
+#[derive(Debug)]
+pub struct NoSingleCharIdentifierRule {
+    meta: RuleMetadata,
+    allowed: HashSet<String>,
+}
+
+impl Default for NoSingleCharIdentifierRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-single-char-identifier",
+                name: "No Single Character Identifier",
+                category: RuleCategory::Naming,
+                default_severity: Severity::Hint,
+                description:
+                    "Variables, parameters and loop variables should have descriptive names",
+            },
+            allowed: SINGLE_CHAR_ALLOWED.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Rule for NoSingleCharIdentifierRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["variable_statement", "parameters", "for_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut names = Vec::new();
+        let mut cursor = node.walk();
+        match node.kind() {
+            "variable_statement" => names.extend(node.child_by_field_name("name")),
+            "parameters" => {
+                for child in node.named_children(&mut cursor) {
+                    let name_node = match child.kind() {
+                        "identifier" => Some(child),
+                        "typed_parameter" | "default_parameter" | "typed_default_parameter" => {
+                            child.named_child(0).filter(|c| c.kind() == "identifier")
+                        }
+                        _ => None,
+                    };
+                    names.extend(name_node);
+                }
+            }
+            // The first identifier is the loop variable
+            _ => names.extend(
+                node.children(&mut cursor)
+                    .find(|c| c.kind() == "identifier"),
+            ),
+        }
+
+        for name_node in names {
+            let name = ctx.node_text(name_node);
+            if name.chars().count() != 1 || name == "_" || self.allowed.contains(name) {
+                continue;
+            }
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            ctx.report_node(
+                name_node,
+                self.meta.id,
+                severity,
+                format!("\"{}\" is a single character, use a descriptive name", name),
+            );
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(allowed) = configure_string_list(config, self.meta.id, "allowed")? {
+            self.allowed = allowed.into_iter().collect();
+        }
+        Ok(())
+    }
+}
+
+/// Past-tense endings accepted by default.
+const PAST_TENSE_SUFFIXES: &[&str] = &["ed", "d"];
+
+/// Irregular past tenses and established names that do not end in a past-tense suffix.
+const PAST_TENSE_EXCEPTIONS: &[&str] = &[
+    "began", "broke", "built", "caught", "done", "drawn", "fell", "found", "got", "hidden", "hit",
+    "left", "lost", "made", "met", "ran", "sent", "set", "shot", "shown", "timeout", "won",
+];
+
+#[derive(Debug)]
+pub struct SignalPastTenseRule {
+    meta: RuleMetadata,
+    suffixes: Vec<String>,
+    exceptions: HashSet<String>,
+}
+
+impl Default for SignalPastTenseRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "signal-should-be-past-tense",
+                name: "Signal Should Be Past Tense",
+                category: RuleCategory::Naming,
+                default_severity: Severity::Info,
+                description: "Signal names should describe an event in the past tense",
+            },
+            suffixes: PAST_TENSE_SUFFIXES.iter().map(|s| s.to_string()).collect(),
+            exceptions: PAST_TENSE_EXCEPTIONS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Rule for SignalPastTenseRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["signal_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        let name = ctx.node_text(name_node);
+        // Casing is signal-name's job
+        if !SNAKE_CASE.is_match(name) {
+            return;
+        }
+
+        // Exceptions match the whole name or its last word (`tween_finished`, `area_hit`)
+        let last_word = name.rsplit('_').next().unwrap_or(name);
+        if self.exceptions.contains(name)
+            || self.exceptions.contains(last_word)
+            || self
+                .suffixes
+                .iter()
+                .any(|s| last_word.ends_with(s.as_str()))
+        {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        ctx.report_node(
+            name_node,
+            self.meta.id,
+            severity,
+            format!(
+                "Signal \"{}\" should be named in the past tense, e.g. \"health_changed\"",
+                name
+            ),
+        );
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(suffixes) = configure_string_list(config, self.meta.id, "suffixes")? {
+            self.suffixes = suffixes;
+        }
+        if let Some(exceptions) = configure_string_list(config, self.meta.id, "exceptions")? {
+            self.exceptions = exceptions.into_iter().collect();
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct SubClassNameRule {
     meta: RuleMetadata,
@@ -484,12 +712,13 @@ impl Rule for LoadConstantNameRule {
 // Variable scope-specific naming rules
 // ============================================================================
 
-/// Helper to check if a variable_statement is at class scope (not inside a function)
-fn is_class_scope_variable(node: Node<'_>) -> bool {
+/// Helper to check if a variable_statement is at class scope (not inside a
+/// function, `_init` or lambda)
+pub(crate) fn is_class_scope_variable(node: Node<'_>) -> bool {
     let mut current = node.parent();
     while let Some(parent) = current {
         match parent.kind() {
-            "function_definition" => return false,
+            "function_definition" | "constructor_definition" | "lambda" => return false,
             "source_file" | "source" => return true,
             "body" => {
                 if let Some(grandparent) = parent.parent() {
@@ -512,7 +741,7 @@ fn has_load_or_preload(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
 }
 
 /// Recursively check if a node or its descendants contain a load/preload call.
-fn contains_load_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+pub(crate) fn contains_load_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
     if node.kind() == "call" {
         // Check if the function being called is "load" or "preload"
         // The function is typically the first child (an identifier)
@@ -536,22 +765,19 @@ fn contains_load_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
     false
 }
 
-/// Recursively check if a node or its descendants contain a preload call specifically.
-fn contains_preload_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+/// Recursively check if a node or its descendants call `func_name` specifically.
+pub(crate) fn contains_call_to(node: Node<'_>, func_name: &str, ctx: &LintContext<'_>) -> bool {
     if node.kind() == "call" {
         if let Some(func) = node.child(0) {
-            if func.kind() == "identifier" {
-                let func_name = ctx.node_text(func);
-                if func_name == "preload" {
-                    return true;
-                }
+            if func.kind() == "identifier" && ctx.node_text(func) == func_name {
+                return true;
             }
         }
     }
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if contains_preload_call(child, ctx) {
+        if contains_call_to(child, func_name, ctx) {
             return true;
         }
     }
@@ -628,6 +854,10 @@ macro_rules! variable_naming_rule {
     };
 }
 
+// The variable naming rules split `variable_statement` by scope and by whether the
+// value loads a resource, so at most one of them reports a given variable; there is
+// no catch-all variable rule alongside them. `no-camelcase-anywhere` can still
+// overlap with them when enabled.
 variable_naming_rule!(
     ClassVariableNameRule,
     "class-variable-name",
@@ -648,16 +878,6 @@ variable_naming_rule!(
     "Class load variable should be PascalCase or snake_case:"
 );
 
-variable_naming_rule!(
-    FunctionVariableNameRule,
-    "function-variable-name",
-    "Function Variable Name",
-    "Function-scope variables should be snake_case",
-    SNAKE_CASE,
-    |node, ctx| !is_class_scope_variable(node) && !has_load_or_preload(node, ctx),
-    "Function variable should be snake_case:"
-);
-
 variable_naming_rule!(
     FunctionPreloadVariableNameRule,
     "function-preload-variable-name",
@@ -668,7 +888,203 @@ variable_naming_rule!(
         if is_class_scope_variable(node) {
             return false;
         }
-        contains_preload_call(node, ctx)
+        contains_call_to(node, "preload", ctx)
     },
     "Function preload variable should be PascalCase:"
 );
+
+/// Function-scope variables should be snake_case. ALL_CAPS and PascalCase names get
+/// tailored messages since they usually mean a constant or a type was intended.
+#[derive(Debug)]
+pub struct FunctionVariableNameRule {
+    meta: RuleMetadata,
+    pattern: Regex,
+}
+
+impl Default for FunctionVariableNameRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "function-variable-name",
+                name: "Function Variable Name",
+                category: RuleCategory::Naming,
+                default_severity: Severity::Warning,
+                description: "Function-scope variables should be snake_case",
+            },
+            pattern: SNAKE_CASE.clone(),
+        }
+    }
+}
+
+impl Rule for FunctionVariableNameRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["variable_statement"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if is_class_scope_variable(node) || has_load_or_preload(node, ctx) {
+            return;
+        }
+        let Some(name_node) = node.child_by_field_name("name") else {
+            return;
+        };
+        let name = ctx.node_text(name_node);
+        if self.pattern.is_match(name) {
+            return;
+        }
+
+        let (message, suggestion) = if CONSTANT_CASE.is_match(name) {
+            (
+                format!(
+                    "Function variable \"{}\" looks like a constant: use const, or rename it to snake_case",
+                    name
+                ),
+                format!("const {}", name),
+            )
+        } else if PRIVATE_PASCAL_CASE.is_match(name) {
+            (
+                format!(
+                    "Function variable \"{}\" is PascalCase: did you mean a type? Otherwise use snake_case",
+                    name
+                ),
+                to_snake_case(name),
+            )
+        } else {
+            (
+                format!("Function variable should be snake_case: \"{}\"", name),
+                to_snake_case(name),
+            )
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        ctx.report(
+            Diagnostic::new(self.meta.id, severity, message)
+                .with_location(
+                    name_node.start_position().row + 1,
+                    name_node.start_position().column + 1,
+                )
+                .with_end_location(
+                    name_node.end_position().row + 1,
+                    name_node.end_position().column + 1,
+                )
+                .with_suggestion(suggestion),
+        );
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        configure_pattern(&mut self.pattern, config)
+    }
+}
+
+/// Bans camelCase in function, variable, parameter and signal names with one switch,
+/// for projects that don't want to tune each naming rule's pattern.
+#[derive(Debug)]
+pub struct NoCamelCaseAnywhereRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoCamelCaseAnywhereRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-camelcase-anywhere",
+                name: "No camelCase Anywhere",
+                category: RuleCategory::Naming,
+                default_severity: Severity::Info,
+                description: "Declared names should not be camelCase",
+            },
+        }
+    }
+}
+
+impl Rule for NoCamelCaseAnywhereRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            "function_definition",
+            "variable_statement",
+            "signal_statement",
+            "parameters",
+        ])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let mut names = Vec::new();
+        if node.kind() == "parameters" {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                let name_node = match child.kind() {
+                    "identifier" => Some(child),
+                    "typed_parameter" | "default_parameter" | "typed_default_parameter" => {
+                        child.named_child(0).filter(|c| c.kind() == "identifier")
+                    }
+                    _ => None,
+                };
+                names.extend(name_node);
+            }
+        } else {
+            names.extend(node.child_by_field_name("name"));
+        }
+
+        for name_node in names {
+            let name = ctx.node_text(name_node);
+            if !CAMEL_CASE.is_match(name) {
+                continue;
+            }
+            let severity = ctx
+                .config()
+                .get_rule_severity(self.meta.id, self.meta.default_severity);
+            ctx.report(
+                Diagnostic::new(
+                    self.meta.id,
+                    severity,
+                    format!("\"{}\" is camelCase, use snake_case", name),
+                )
+                .with_location(
+                    name_node.start_position().row + 1,
+                    name_node.start_position().column + 1,
+                )
+                .with_end_location(
+                    name_node.end_position().row + 1,
+                    name_node.end_position().column + 1,
+                )
+                .with_suggestion(to_snake_case(name)),
+            );
+        }
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Convert `MyVar`, `myVar` or `MY_VAR` to `my_var`, keeping a leading underscore.
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+        prev = Some(c);
+    }
+    result
+}
@@ -1,190 +1,18 @@
+use std::collections::HashSet;
+
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
-use crate::lint::{Diagnostic, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum MemberKind {
-    Tool,
-    ClassName,
-    Extends,
-    DocComment,
-    Signal,
-    Enum,
-    Const,
-    StaticVar,
-    ExportVar,
-    Var,
-    OnreadyVar,
-    VirtualMethod,
-    Method,
-    InnerClass,
-}
-
-/// Extract the annotation name from an annotation node.
-/// Annotation nodes have an identifier child containing the name (e.g., "tool", "export").
-fn get_annotation_name<'a>(node: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "identifier" {
-            return child.utf8_text(source).ok();
-        }
-    }
-    None
-}
-
-impl MemberKind {
-    fn from_node(node: Node<'_>, source: &[u8]) -> Option<Self> {
-        let node_text = |n: Node<'_>| n.utf8_text(source).unwrap_or("");
-
-        match node.kind() {
-            "annotation" => match get_annotation_name(node, source) {
-                Some("tool") => Some(MemberKind::Tool),
-                Some(
-                    "export"
-                    | "export_category"
-                    | "export_group"
-                    | "export_subgroup"
-                    | "export_color_no_alpha"
-                    | "export_dir"
-                    | "export_enum"
-                    | "export_exp_easing"
-                    | "export_file"
-                    | "export_flags"
-                    | "export_flags_2d_navigation"
-                    | "export_flags_2d_physics"
-                    | "export_flags_2d_render"
-                    | "export_flags_3d_navigation"
-                    | "export_flags_3d_physics"
-                    | "export_flags_3d_render"
-                    | "export_global_dir"
-                    | "export_global_file"
-                    | "export_multiline"
-                    | "export_node_path"
-                    | "export_placeholder"
-                    | "export_range"
-                    | "export_storage"
-                    | "export_custom",
-                ) => Some(MemberKind::ExportVar),
-                Some("onready") => Some(MemberKind::OnreadyVar),
-                _ => None,
-            },
-            "class_name_statement" => Some(MemberKind::ClassName),
-            "extends_statement" => Some(MemberKind::Extends),
-            "signal_statement" => Some(MemberKind::Signal),
-            "enum_definition" => Some(MemberKind::Enum),
-            "const_statement" => Some(MemberKind::Const),
-            "variable_statement" => {
-                if let Some(parent) = node.parent() {
-                    if let Some(prev) = find_previous_sibling(parent, node) {
-                        if prev.kind() == "annotation" {
-                            match get_annotation_name(prev, source) {
-                                Some(
-                                    "export"
-                                    | "export_category"
-                                    | "export_group"
-                                    | "export_subgroup"
-                                    | "export_color_no_alpha"
-                                    | "export_dir"
-                                    | "export_enum"
-                                    | "export_exp_easing"
-                                    | "export_file"
-                                    | "export_flags"
-                                    | "export_flags_2d_navigation"
-                                    | "export_flags_2d_physics"
-                                    | "export_flags_2d_render"
-                                    | "export_flags_3d_navigation"
-                                    | "export_flags_3d_physics"
-                                    | "export_flags_3d_render"
-                                    | "export_global_dir"
-                                    | "export_global_file"
-                                    | "export_multiline"
-                                    | "export_node_path"
-                                    | "export_placeholder"
-                                    | "export_range"
-                                    | "export_storage"
-                                    | "export_custom",
-                                ) => {
-                                    return Some(MemberKind::ExportVar);
-                                }
-                                Some("onready") => return Some(MemberKind::OnreadyVar),
-                                Some("static") => return Some(MemberKind::StaticVar),
-                                _ => {}
-                            }
-                        }
-                    }
-                }
-                Some(MemberKind::Var)
-            }
-            "function_definition" => {
-                if let Some(name_node) = node.child_by_field_name("name") {
-                    let name = node_text(name_node);
-                    if is_virtual_method(name) {
-                        return Some(MemberKind::VirtualMethod);
-                    }
-                }
-                Some(MemberKind::Method)
-            }
-            "class_definition" => Some(MemberKind::InnerClass),
-            "comment" => {
-                let text = node_text(node);
-                if text.starts_with("##") {
-                    Some(MemberKind::DocComment)
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        }
-    }
-
-    fn name(&self) -> &'static str {
-        match self {
-            MemberKind::Tool => "@tool",
-            MemberKind::ClassName => "class_name",
-            MemberKind::Extends => "extends",
-            MemberKind::DocComment => "doc comment",
-            MemberKind::Signal => "signal",
-            MemberKind::Enum => "enum",
-            MemberKind::Const => "const",
-            MemberKind::StaticVar => "static var",
-            MemberKind::ExportVar => "@export var",
-            MemberKind::Var => "var",
-            MemberKind::OnreadyVar => "@onready var",
-            MemberKind::VirtualMethod => "virtual method",
-            MemberKind::Method => "method",
-            MemberKind::InnerClass => "inner class",
-        }
-    }
-}
-
-fn find_previous_sibling<'a>(parent: Node<'a>, target: Node<'a>) -> Option<Node<'a>> {
-    let mut cursor = parent.walk();
-    let mut prev = None;
-    for child in parent.children(&mut cursor) {
-        if child.id() == target.id() {
-            return prev;
-        }
-        prev = Some(child);
-    }
-    None
-}
-
-fn is_virtual_method(name: &str) -> bool {
-    matches!(
-        name,
-        "_init"
-            | "_ready"
-            | "_process"
-            | "_physics_process"
-            | "_enter_tree"
-            | "_exit_tree"
-            | "_input"
-            | "_unhandled_input"
-            | "_notification"
-            | "_draw"
-            | "_gui_input"
-    )
+use crate::format::inline_body;
+use crate::format::member_kind::classify_virtual_method;
+use crate::format::reorder::order_violations;
+use crate::lint::{Diagnostic, Fix, LintContext, Rule, RuleCategory, RuleMetadata, Severity};
+use crate::rules::basic::{has_annotation_matching, preload_variable_message};
+use crate::rules::naming::is_class_scope_variable;
+
+/// Check if a method name is an engine virtual callback, as classified for member ordering.
+pub(crate) fn is_virtual_method(name: &str) -> bool {
+    classify_virtual_method(name, &[]).is_virtual()
 }
 
 #[derive(Debug)]
@@ -222,13 +50,40 @@ impl Rule for ClassDefinitionsOrderRule {
         let severity = ctx
             .config()
             .get_rule_severity(self.meta.id, self.meta.default_severity);
-        let source = ctx.source().as_bytes();
-        let root = ctx.tree().root_node();
-
-        let diagnostics = self.collect_order_violations(root, source, severity);
 
-        for diagnostic in diagnostics {
-            ctx.report(diagnostic);
+        // Share the classification used by `gdformat --reorder`
+        let extra_virtuals = ctx
+            .config()
+            .format
+            .additional_virtual_methods
+            .as_deref()
+            .unwrap_or_default();
+        let violations = order_violations(ctx.tree().root_node(), ctx.source(), extra_virtuals);
+
+        for violation in violations {
+            let column = ctx
+                .source()
+                .lines()
+                .nth(violation.line - 1)
+                .map_or(0, |line| line.len() - line.trim_start().len())
+                + 1;
+            let message = match &violation.expected_after {
+                Some(previous) => format!(
+                    "{} ({}) should come after {}",
+                    violation.member,
+                    violation.kind.name(),
+                    previous
+                ),
+                None => format!(
+                    "{} ({}) should come first",
+                    violation.member,
+                    violation.kind.name()
+                ),
+            };
+            ctx.report(
+                Diagnostic::new(self.meta.id, severity, message)
+                    .with_location(violation.line, column),
+            );
         }
     }
 
@@ -237,46 +92,6 @@ impl Rule for ClassDefinitionsOrderRule {
     }
 }
 
-impl ClassDefinitionsOrderRule {
-    fn collect_order_violations(
-        &self,
-        class_node: Node<'_>,
-        source: &[u8],
-        severity: Severity,
-    ) -> Vec<Diagnostic> {
-        let mut diagnostics = Vec::new();
-        let mut last_kind: Option<MemberKind> = None;
-
-        let mut cursor = class_node.walk();
-        for child in class_node.children(&mut cursor) {
-            if let Some(kind) = MemberKind::from_node(child, source) {
-                if let Some(prev_kind) = last_kind {
-                    if kind < prev_kind {
-                        let line = child.start_position().row + 1;
-                        let column = child.start_position().column + 1;
-                        let diagnostic = Diagnostic::new(
-                            self.meta.id,
-                            severity,
-                            format!("{} should come before {}", kind.name(), prev_kind.name()),
-                        )
-                        .with_location(line, column);
-                        diagnostics.push(diagnostic);
-                    }
-                }
-                last_kind = Some(kind);
-            }
-
-            if child.kind() == "class_definition" {
-                if let Some(body) = child.child_by_field_name("body") {
-                    diagnostics.extend(self.collect_order_violations(body, source, severity));
-                }
-            }
-        }
-
-        diagnostics
-    }
-}
-
 #[derive(Debug)]
 pub struct NoElifReturnRule {
     meta: RuleMetadata,
@@ -309,7 +124,8 @@ impl Rule for NoElifReturnRule {
         // Check if the if branch ends with a return
         // The if body is in "body" field (first body child of if_statement)
         if let Some(body) = node.child_by_field_name("body") {
-            if !block_ends_with_return(body) {
+            // Only `return` counts here: `break`/`continue` are left to no-else-return
+            if block_ends_with_jump(body, false).is_none() {
                 return;
             }
 
@@ -349,7 +165,7 @@ impl Default for NoElseReturnRule {
                 name: "No Else After Return",
                 category: RuleCategory::Style,
                 default_severity: Severity::Warning,
-                description: "Unnecessary else after return statement",
+                description: "Unnecessary else after return, break or continue",
             },
         }
     }
@@ -365,13 +181,17 @@ impl Rule for NoElseReturnRule {
     }
 
     fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
-        // Check if the if branch (and all elif branches) end with a return
-        if let Some(body) = node.child_by_field_name("body") {
-            if !block_ends_with_return(body) {
-                return;
-            }
-        } else {
-            return;
+        // `break`/`continue` only leave the branch when inside a loop
+        let in_loop = is_inside_loop(node);
+
+        // Check if the if branch (and all elif branches) end with a jump
+        let mut jumps = Vec::new();
+        match node
+            .child_by_field_name("body")
+            .and_then(|body| block_ends_with_jump(body, in_loop))
+        {
+            Some(jump) => jumps.push(jump),
+            None => return,
         }
 
         // Check all elif branches
@@ -379,14 +199,16 @@ impl Rule for NoElseReturnRule {
         for child in node.children(&mut cursor) {
             if child.kind() == "elif_clause" {
                 if let Some(body) = child.child_by_field_name("body") {
-                    if !block_ends_with_return(body) {
-                        return;
+                    match block_ends_with_jump(body, in_loop) {
+                        Some(jump) if !jumps.contains(&jump) => jumps.push(jump),
+                        Some(_) => {}
+                        None => return,
                     }
                 }
             }
         }
 
-        // If we get here, all if/elif branches return
+        // If we get here, all if/elif branches jump
         // Check for else clause
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
@@ -394,11 +216,12 @@ impl Rule for NoElseReturnRule {
                 let severity = ctx
                     .config()
                     .get_rule_severity(self.meta.id, self.meta.default_severity);
+                let names: Vec<String> = jumps.iter().map(|j| format!("'{}'", j)).collect();
                 ctx.report_node(
                     child,
                     self.meta.id,
                     severity,
-                    "Unnecessary 'else' after 'return'",
+                    format!("Unnecessary 'else' after {}", names.join("/")),
                 );
             }
         }
@@ -409,59 +232,635 @@ impl Rule for NoElseReturnRule {
     }
 }
 
-fn block_ends_with_return(block: Node<'_>) -> bool {
-    let mut cursor = block.walk();
-    let children: Vec<_> = block.children(&mut cursor).collect();
-
-    if let Some(last) = children.last() {
-        if last.kind() == "return_statement" {
-            return true;
-        }
-        // Check if it's an if statement where all branches return
-        if last.kind() == "if_statement" {
-            return all_branches_return(*last);
+/// Whether `node` sits inside a loop body of the same function.
+fn is_inside_loop(node: Node<'_>) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        match n.kind() {
+            "for_statement" | "while_statement" => return true,
+            "function_definition" | "constructor_definition" | "lambda" => return false,
+            _ => {}
         }
+        current = n.parent();
     }
     false
 }
 
-fn all_branches_return(if_node: Node<'_>) -> bool {
-    // Check if branch
-    if let Some(body) = if_node.child_by_field_name("body") {
-        if !block_ends_with_return(body) {
-            return false;
-        }
-    } else {
-        return false;
+/// The keyword of the statement that ends `block` unconditionally: `return`,
+/// or `break`/`continue` when `in_loop`. Returns `None` if control can fall through.
+fn block_ends_with_jump(block: Node<'_>, in_loop: bool) -> Option<&'static str> {
+    let mut cursor = block.walk();
+    let last = block.children(&mut cursor).last()?;
+
+    match last.kind() {
+        "return_statement" => Some("return"),
+        "break_statement" if in_loop => Some("break"),
+        "continue_statement" if in_loop => Some("continue"),
+        // Check if it's an if statement where all branches jump
+        "if_statement" => all_branches_jump(last, in_loop),
+        _ => None,
     }
+}
+
+fn all_branches_jump(if_node: Node<'_>, in_loop: bool) -> Option<&'static str> {
+    // Check if branch
+    let jump = block_ends_with_jump(if_node.child_by_field_name("body")?, in_loop)?;
 
     let mut has_else = false;
     let mut cursor = if_node.walk();
     for child in if_node.children(&mut cursor) {
         match child.kind() {
             "elif_clause" => {
-                if let Some(body) = child.child_by_field_name("body") {
-                    if !block_ends_with_return(body) {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
+                block_ends_with_jump(child.child_by_field_name("body")?, in_loop)?;
             }
             "else_clause" => {
                 has_else = true;
-                if let Some(body) = child.child_by_field_name("body") {
-                    if !block_ends_with_return(body) {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
+                block_ends_with_jump(child.child_by_field_name("body")?, in_loop)?;
             }
             _ => {}
         }
     }
 
-    // Must have an else clause for all branches to return
-    has_else
+    // Must have an else clause for all branches to jump
+    has_else.then_some(jump)
+}
+
+#[derive(Debug)]
+pub struct NoComparisonToBoolLiteralRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoComparisonToBoolLiteralRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-comparison-to-bool-literal",
+                name: "No Comparison To Bool Literal",
+                category: RuleCategory::Style,
+                default_severity: Severity::Warning,
+                description: "Boolean values should not be compared to true or false",
+            },
+        }
+    }
+}
+
+/// Get the operator token of a binary or comparison operator node.
+pub(crate) fn operator_text<'a>(node: Node<'_>, ctx: &'a LintContext<'_>) -> Option<&'a str> {
+    if let Some(op) = node.child_by_field_name("op") {
+        return Some(ctx.node_text(op));
+    }
+    let mut cursor = node.walk();
+    let op = node
+        .children(&mut cursor)
+        .find(|c| !c.is_named())
+        .map(|c| ctx.node_text(c));
+    op
+}
+
+impl Rule for NoComparisonToBoolLiteralRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["binary_operator", "comparison_operator"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let is_equal = match operator_text(node, ctx) {
+            Some("==") => true,
+            Some("!=") => false,
+            _ => return,
+        };
+
+        let (Some(left), Some(right)) = (node.named_child(0), node.named_child(1)) else {
+            return;
+        };
+
+        // Handle both `x == true` and `true == x`
+        let (literal, operand) = match (left.kind(), right.kind()) {
+            (_, "true" | "false") => (right, left),
+            ("true" | "false", _) => (left, right),
+            _ => return,
+        };
+
+        let literal_value = literal.kind() == "true";
+        let operand_text = ctx.node_text(operand);
+        let suggestion = if literal_value == is_equal {
+            operand_text.to_string()
+        } else {
+            format!("not {}", operand_text)
+        };
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Comparison to {} literal, use \"{}\" instead of \"{}\"",
+            ctx.node_text(literal),
+            suggestion,
+            ctx.node_text(node)
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags chained comparisons such as `a < b < c`.
+///
+/// GDScript evaluates these left to right as `(a < b) < c`, comparing a bool with
+/// `c`, rather than the `a < b and b < c` readers from Python expect.
+#[derive(Debug)]
+pub struct NoComparisonChainingRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoComparisonChainingRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-comparison-chaining",
+                name: "No Comparison Chaining",
+                category: RuleCategory::Style,
+                default_severity: Severity::Warning,
+                description: "Comparisons should not be chained, combine them with and",
+            },
+        }
+    }
+}
+
+/// Check whether a node is a comparison (`<`, `<=`, `>`, `>=`, `==`, `!=`).
+fn is_comparison(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    matches!(node.kind(), "binary_operator" | "comparison_operator")
+        && matches!(
+            operator_text(node, ctx),
+            Some("<" | "<=" | ">" | ">=" | "==" | "!=")
+        )
+}
+
+impl Rule for NoComparisonChainingRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&["binary_operator", "comparison_operator"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if !is_comparison(node, ctx) {
+            return;
+        }
+
+        // The grammar may build one flat node (`a < b < c` with two operators) or
+        // nest the left comparison; parentheses make the nesting explicit and are fine
+        let mut cursor = node.walk();
+        let operators = node.children(&mut cursor).filter(|c| !c.is_named()).count();
+        let left_is_comparison = node
+            .named_child(0)
+            .is_some_and(|left| is_comparison(left, ctx));
+        if operators < 2 && !left_is_comparison {
+            return;
+        }
+
+        // Report a nested chain once, at its outermost comparison
+        if node.parent().is_some_and(|parent| {
+            is_comparison(parent, ctx) && parent.named_child(0).map(|c| c.id()) == Some(node.id())
+        }) {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Chained comparison \"{}\" compares a bool with the last operand, use \"and\" to combine comparisons",
+            ctx.node_text(node)
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Flags `;` used to put several statements on one line and, with
+/// `check_single_line_bodies`, `if`/`elif`/`else`/`for`/`while` bodies written on
+/// the header line. The body detection is shared with the formatter, which keeps
+/// single-line `if` statements verbatim.
+#[derive(Debug)]
+pub struct NoMultipleStatementsPerLineRule {
+    meta: RuleMetadata,
+    check_single_line_bodies: bool,
+}
+
+impl Default for NoMultipleStatementsPerLineRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-multiple-statements-per-line",
+                name: "No Multiple Statements Per Line",
+                category: RuleCategory::Style,
+                default_severity: Severity::Info,
+                description: "Each statement should be on its own line",
+            },
+            check_single_line_bodies: false,
+        }
+    }
+}
+
+impl Rule for NoMultipleStatementsPerLineRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        Some(&[
+            ";",
+            "if_statement",
+            "elif_clause",
+            "else_clause",
+            "for_statement",
+            "while_statement",
+        ])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+
+        if node.kind() == ";" {
+            // The separator may be the last child of the statement before it
+            let next = node
+                .next_named_sibling()
+                .or_else(|| node.parent().and_then(|p| p.next_named_sibling()));
+            let Some(next) = next.filter(|n| {
+                n.kind() != "comment" && n.start_position().row == node.end_position().row
+            }) else {
+                return;
+            };
+            ctx.report_node(
+                next,
+                self.meta.id,
+                severity,
+                "Multiple statements on one line, put each statement on its own line",
+            );
+            return;
+        }
+
+        if !self.check_single_line_bodies {
+            return;
+        }
+        let Some(body) = inline_body(node) else {
+            return;
+        };
+        let keyword = match node.kind() {
+            "if_statement" => "if",
+            "elif_clause" => "elif",
+            "else_clause" => "else",
+            "for_statement" => "for",
+            _ => "while",
+        };
+        let message = format!(
+            "\"{}\" body is on the same line, move it to its own indented line",
+            keyword
+        );
+        ctx.report_node(body, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(value) = config.options.get("check_single_line_bodies") {
+            self.check_single_line_bodies = value.as_bool().ok_or(
+                "no-multiple-statements-per-line: 'check_single_line_bodies' must be a boolean",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Suggests `const` for class variables that are never written after their declaration.
+///
+/// This is a file-local heuristic: it looks for `=`/`+=`-style assignments to the
+/// name anywhere in the file, including `self.name = ...` and writes through
+/// `name.field`/`name[i]`. It can't see writes from other scripts, mutation via
+/// method calls (`name.append(...)`), or `set("name", ...)`, and a local variable
+/// with the same name being assigned suppresses the report. Array and dictionary
+/// initializers are skipped, since a const container is read-only and in-place
+/// mutation would break. Initializers that call anything other than `preload` are
+/// skipped since they usually aren't constant.
+///
+/// `--fix` rewrites `var` to `const` when the initializer is a literal or a
+/// `preload("...")`. Set `fix_private_only` to only rewrite `_`-prefixed variables,
+/// for projects where other scripts may write public ones.
+#[derive(Debug)]
+pub struct ConstCouldBeUsedRule {
+    meta: RuleMetadata,
+    fix_private_only: bool,
+}
+
+impl Default for ConstCouldBeUsedRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "const-could-be-used",
+                name: "Const Could Be Used",
+                category: RuleCategory::Style,
+                default_severity: Severity::Info,
+                description: "Class variables that are never reassigned could be constants",
+            },
+            fix_private_only: false,
+        }
+    }
+}
+
+/// Collect the names written by every assignment in the tree.
+fn collect_assigned_names(node: Node<'_>, ctx: &LintContext<'_>, names: &mut HashSet<String>) {
+    if matches!(node.kind(), "assignment" | "augmented_assignment") {
+        if let Some(name) = node
+            .child_by_field_name("left")
+            .and_then(|left| assignment_target_name(left, ctx))
+        {
+            names.insert(name.to_string());
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_assigned_names(child, ctx, names);
+    }
+}
+
+/// Resolve the variable an assignment target writes to: `x`, `self.x`, `x.y`, `x[i]`.
+fn assignment_target_name<'a>(node: Node<'_>, ctx: &'a LintContext<'_>) -> Option<&'a str> {
+    match node.kind() {
+        "identifier" => Some(ctx.node_text(node)),
+        "attribute" | "subscript" => {
+            let base = node.named_child(0)?;
+            if base.kind() == "identifier" && ctx.node_text(base) == "self" {
+                return node
+                    .named_child(1)
+                    .and_then(|attr| assignment_target_name(attr, ctx));
+            }
+            assignment_target_name(base, ctx)
+        }
+        "attribute_subscript" => node
+            .named_child(0)
+            .and_then(|name| assignment_target_name(name, ctx)),
+        _ => None,
+    }
+}
+
+/// Check whether an initializer calls anything other than `preload`.
+fn contains_non_preload_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    match node.kind() {
+        "call" => {
+            if !is_preload_call(node, ctx) {
+                return true;
+            }
+        }
+        "attribute_call" => return true,
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|child| contains_non_preload_call(child, ctx));
+    found
+}
+
+/// Check whether a node is a `preload(...)` call.
+fn is_preload_call(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    node.kind() == "call"
+        && node
+            .named_child(0)
+            .is_some_and(|callee| ctx.node_text(callee) == "preload")
+}
+
+/// Check whether an initializer can be written as-is after `const`: a literal,
+/// a negated number, or a `preload(...)` call with string arguments.
+fn is_const_expressible(node: Node<'_>, ctx: &LintContext<'_>) -> bool {
+    match node.kind() {
+        "integer" | "float" | "string" | "string_name" | "true" | "false" | "null" => true,
+        "parenthesized_expression" => node
+            .named_child(0)
+            .is_some_and(|inner| is_const_expressible(inner, ctx)),
+        "unary_operator" => {
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+            children.len() == 2
+                && ctx.node_text(children[0]) == "-"
+                && matches!(children[1].kind(), "integer" | "float")
+        }
+        "call" => {
+            is_preload_call(node, ctx)
+                && node.child_by_field_name("arguments").is_some_and(|args| {
+                    let mut cursor = args.walk();
+                    let all_strings = args
+                        .named_children(&mut cursor)
+                        .all(|arg| arg.kind() == "string");
+                    all_strings
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Collect class-scope variables that have an initializer.
+fn collect_class_variables<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if node.kind() == "variable_statement" {
+        if node.child_by_field_name("value").is_some() && is_class_scope_variable(node) {
+            out.push(node);
+        }
+        return;
+    }
+    if node.kind() == "function_definition" {
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_class_variables(child, out);
+    }
+}
+
+impl Rule for ConstCouldBeUsedRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let root = ctx.tree().root_node();
+
+        let mut assigned = HashSet::new();
+        collect_assigned_names(root, ctx, &mut assigned);
+
+        let mut variables = Vec::new();
+        collect_class_variables(root, &mut variables);
+
+        let mut diagnostics = Vec::new();
+        for var in variables {
+            // Exported and onready variables are set by the editor or engine
+            let engine_set = has_annotation_matching(
+                var,
+                |name| name == "onready" || name.starts_with("export"),
+                ctx,
+            );
+            // Properties with accessors and statics (writable from other classes) stay vars
+            if engine_set
+                || var.child_by_field_name("setget").is_some()
+                || var.child_by_field_name("static").is_some()
+            {
+                continue;
+            }
+
+            let Some(name_node) = var.child_by_field_name("name") else {
+                continue;
+            };
+            let name = ctx.node_text(name_node).to_string();
+            if assigned.contains(&name) {
+                continue;
+            }
+
+            let Some(value) = var.child_by_field_name("value") else {
+                continue;
+            };
+            // A const array or dictionary is read-only, and `append`/`erase` calls on
+            // it can't be told apart from calls that only read
+            if matches!(value.kind(), "array" | "dictionary")
+                || contains_non_preload_call(value, ctx)
+            {
+                continue;
+            }
+
+            // Same words as no-preload-variable-should-be-const, so the two can be deduped
+            let message = if is_preload_call(value, ctx) {
+                preload_variable_message(&name)
+            } else {
+                format!(
+                    "Variable \"{}\" is never reassigned, consider making it a const",
+                    name
+                )
+            };
+            let position = name_node.start_position();
+            let mut diagnostic = Diagnostic::new(self.meta.id, severity, message)
+                .with_location(position.row + 1, position.column + 1);
+
+            // Only rewrite when the initializer is guaranteed to be a valid constant
+            // expression; anything else (arrays, constructors, other names) stays a hint
+            let fixable = !self.fix_private_only || name.starts_with('_');
+            if fixable && is_const_expressible(value, ctx) {
+                let mut cursor = var.walk();
+                let keyword = var.children(&mut cursor).find(|c| c.kind() == "var");
+                if let Some(keyword) = keyword {
+                    diagnostic = diagnostic.with_fix(Fix {
+                        start_byte: keyword.start_byte(),
+                        end_byte: keyword.end_byte(),
+                        replacement: "const".to_string(),
+                    });
+                }
+            }
+            diagnostics.push(diagnostic);
+        }
+
+        for diagnostic in diagnostics {
+            ctx.report(diagnostic);
+        }
+    }
+
+    fn configure(&mut self, config: &RuleConfig) -> Result<(), String> {
+        if let Some(value) = config.options.get("fix_private_only") {
+            self.fix_private_only = value
+                .as_bool()
+                .ok_or("const-could-be-used: 'fix_private_only' must be a boolean")?;
+        }
+        Ok(())
+    }
+}
+
+/// Flags casts of a literal to its own type (`5 as int`, `"s" as String`).
+///
+/// Without type inference this only covers literals, where the value's type is
+/// known from the syntax alone.
+#[derive(Debug)]
+pub struct NoRedundantTypeCastRule {
+    meta: RuleMetadata,
+}
+
+impl Default for NoRedundantTypeCastRule {
+    fn default() -> Self {
+        Self {
+            meta: RuleMetadata {
+                id: "no-redundant-type-cast",
+                name: "No Redundant Type Cast",
+                category: RuleCategory::Style,
+                default_severity: Severity::Info,
+                description: "Literals should not be cast to the type they already have",
+            },
+        }
+    }
+}
+
+/// The built-in type of a literal node, if it is one.
+fn literal_type(kind: &str) -> Option<&'static str> {
+    match kind {
+        "integer" => Some("int"),
+        "float" => Some("float"),
+        "string" => Some("String"),
+        "true" | "false" => Some("bool"),
+        "string_name" => Some("StringName"),
+        "node_path" => Some("NodePath"),
+        _ => None,
+    }
+}
+
+impl Rule for NoRedundantTypeCastRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn interested_node_kinds(&self) -> Option<&'static [&'static str]> {
+        // The grammar parses `x as T` as a binary operator
+        Some(&["cast", "binary_operator"])
+    }
+
+    fn check_node(&self, node: Node<'_>, ctx: &mut LintContext<'_>) {
+        if node.kind() == "binary_operator" && operator_text(node, ctx) != Some("as") {
+            return;
+        }
+
+        let (Some(value), Some(target)) = (node.named_child(0), node.named_child(1)) else {
+            return;
+        };
+        let Some(value_type) = literal_type(value.kind()) else {
+            return;
+        };
+        if ctx.node_text(target) != value_type {
+            return;
+        }
+
+        let severity = ctx
+            .config()
+            .get_rule_severity(self.meta.id, self.meta.default_severity);
+        let message = format!(
+            "Redundant cast, {} is already a {}",
+            ctx.node_text(value),
+            value_type
+        );
+        ctx.report_node(node, self.meta.id, severity, message);
+    }
+
+    fn configure(&mut self, _config: &RuleConfig) -> Result<(), String> {
+        Ok(())
+    }
 }
@@ -0,0 +1,127 @@
+//! Tests that run the `gdlint` binary.
+
+use std::process::Command;
+
+/// Run `gdlint` with `args` in a directory holding a script with a naming warning.
+fn gdlint_output(args: &[&str]) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("player.gd"), "func GetHealth():\n\tpass\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gdlint"))
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_color_never_has_no_escape_codes() {
+    let output = gdlint_output(&["lint", "--color", "never", "player.gd"]);
+    assert!(output.contains("warning: Function name \"GetHealth\""));
+    assert!(!output.contains('\x1B'));
+
+    // Piped output is not a terminal, so auto doesn't color either
+    let output = gdlint_output(&["lint", "player.gd"]);
+    assert!(!output.contains('\x1B'));
+}
+
+#[test]
+fn test_color_always_colors_severity() {
+    let output = gdlint_output(&["lint", "--color", "always", "--flat", "player.gd"]);
+    assert!(output.contains("player.gd:1:6: \x1B[33mwarning\x1B[0m: Function name"));
+}
+
+#[test]
+fn test_since_lints_only_changed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args([
+                "-c",
+                "user.name=gdlint",
+                "-c",
+                "user.email=gdlint@example.com",
+            ])
+            .args(args)
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success());
+    };
+    git(&["init", "-q"]);
+    std::fs::write(dir.path().join("old.gd"), "func OldName():\n\tpass\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+    std::fs::write(dir.path().join("new.gd"), "func NewName():\n\tpass\n").unwrap();
+    git(&["add", "new.gd"]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gdlint"))
+        .args(["lint", "--list-files", "--since", "HEAD", "."])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    let listed = String::from_utf8(output.stdout).unwrap();
+    assert!(listed.contains("new.gd"));
+    assert!(!listed.contains("old.gd"));
+}
+
+#[test]
+fn test_since_outside_git_repository_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_gdlint"))
+        .args(["lint", "--since", "main", "."])
+        .env("GIT_CEILING_DIRECTORIES", dir.path().parent().unwrap())
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("--since only works inside a git repository"));
+}
+
+#[test]
+fn test_watch_rejects_fix() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("player.gd"), "var SPEED = 5\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_gdlint"))
+        .args(["lint", "--watch", "--fix", "."])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("cannot be used with"));
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("player.gd")).unwrap(),
+        "var SPEED = 5\n"
+    );
+}
+
+#[test]
+fn test_reorder_diff_shows_moves_without_writing() {
+    let dir = tempfile::tempdir().unwrap();
+    let source = "extends Node\n\n\nfunc f():\n\tpass\n\n\nvar health = 1\n";
+    std::fs::write(dir.path().join("player.gd"), source).unwrap();
+    std::fs::write(
+        dir.path().join("sorted.gd"),
+        "extends Node\n\nvar health = 1\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gdlint"))
+        .args(["reorder", "--diff", "."])
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.contains("player.gd"));
+    assert!(diff.contains("+var health = 1"));
+    assert!(diff.contains("-var health = 1"));
+    assert!(!diff.contains("sorted.gd"));
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("player.gd")).unwrap(),
+        source
+    );
+}
@@ -0,0 +1,227 @@
+use std::path::Path;
+
+use gdtools::config::{load_config_from, ruleset_source, Config, RULESET_NAMES};
+use gdtools::lint::Severity;
+use gdtools::rules::all_rules;
+
+#[test]
+fn test_config_discovered_from_start_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let nested = dir.path().join("scenes").join("player");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        dir.path().join("gdtools.toml"),
+        "exclude = [\"addons/**\"]\n",
+    )
+    .unwrap();
+
+    let config = load_config_from(None, &nested).unwrap();
+    assert_eq!(config.exclude, vec!["addons/**".to_string()]);
+}
+
+#[test]
+fn test_explicit_config_path_wins_over_discovery() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("gdtools.toml"), "exclude = [\"a\"]\n").unwrap();
+    let explicit = dir.path().join("other.toml");
+    std::fs::write(&explicit, "exclude = [\"b\"]\n").unwrap();
+
+    let config = load_config_from(Some(&explicit), dir.path()).unwrap();
+    assert_eq!(config.exclude, vec!["b".to_string()]);
+}
+
+#[test]
+fn test_config_extends_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    let package = dir.path().join("packages").join("game");
+    std::fs::create_dir_all(&package).unwrap();
+    std::fs::write(
+        dir.path().join("gdtools.base.toml"),
+        "exclude = [\"addons/**\"]\n\n[rules.max-line-length]\nmax = 80\nseverity = \"error\"\n\n[format]\nline_length = 80\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("packages").join("gdtools.toml"),
+        "extends = \"../gdtools.base.toml\"\nexclude = [\"generated/**\"]\n\n[format]\nreorder = true\n",
+    )
+    .unwrap();
+    std::fs::write(
+        package.join("gdtools.toml"),
+        "extends = \"../gdtools.toml\"\n\n[rules.max-line-length]\nmax = 120\n",
+    )
+    .unwrap();
+
+    let config = load_config_from(None, &package).unwrap();
+    assert_eq!(
+        config.exclude,
+        vec!["addons/**".to_string(), "generated/**".to_string()]
+    );
+    // The child's rule options override the base's, and the rest of the entry is kept
+    let rule = config.get_rule_config("max-line-length").unwrap();
+    assert_eq!(rule.options["max"].as_integer(), Some(120));
+    assert_eq!(rule.severity, Some(Severity::Error));
+    assert_eq!(config.format.line_length, Some(80));
+    assert_eq!(config.format.reorder, Some(true));
+}
+
+#[test]
+fn test_config_extends_cycle_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+    std::fs::write(dir.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+
+    let error = load_config_from(Some(&dir.path().join("a.toml")), dir.path()).unwrap_err();
+    assert!(error.contains("cycle"), "{}", error);
+}
+
+#[test]
+fn test_include_globs_limit_walked_files() {
+    let root = Path::new(".");
+    let config: Config = toml::from_str("include = [\"src/**\"]\n").unwrap();
+    assert!(config.is_included(Path::new("src/player.gd"), root));
+    assert!(config.is_included(Path::new("./src/scenes/enemy.gd"), root));
+    assert!(!config.is_included(Path::new("./addons/plugin/tool.gd"), root));
+    // Anchored at the walk root: a nested `src` directory doesn't match
+    assert!(!config.is_included(Path::new("./addons/x/src/a.gd"), root));
+
+    // `**/` matches zero or more directories
+    let config: Config = toml::from_str("include = [\"src/**/*.gd\"]\n").unwrap();
+    assert!(config.is_included(Path::new("./src/player.gd"), root));
+    assert!(config.is_included(Path::new("./src/scenes/enemy.gd"), root));
+    assert!(!config.is_included(Path::new("./addons/x/src/a.gd"), root));
+
+    // Paths under another walk root are matched relative to it
+    let root = Path::new("project");
+    assert!(config.is_included(Path::new("project/src/player.gd"), root));
+    assert!(!config.is_included(Path::new("project/addons/x/src/a.gd"), root));
+
+    // No include globs: everything is linted, as before
+    assert!(Config::default().is_included(Path::new("./addons/plugin/tool.gd"), root));
+}
+
+#[test]
+fn test_config_hint_severity() {
+    let config: Config = toml::from_str("[rules.max-line-length]\nseverity = \"hint\"\n").unwrap();
+    assert_eq!(
+        config.get_rule_severity("max-line-length", Severity::Warning),
+        Severity::Hint
+    );
+    assert_eq!(Severity::Hint.to_string(), "hint");
+}
+
+#[test]
+fn test_config_category_toggles() {
+    use gdtools::lint::RuleCategory;
+
+    let config: Config = toml::from_str(
+        "[categories]\n\
+         design = false\n\
+         naming = true\n\n\
+         [rules.max-function-args]\n\
+         enabled = true\n\n\
+         [rules.max-returns]\n\
+         max = 3\n\n\
+         [rules.function-name]\n\
+         enabled = false\n",
+    )
+    .unwrap();
+
+    // An explicit per-rule setting beats the category
+    assert!(config.is_rule_enabled_in("max-function-args", RuleCategory::Design, true));
+    assert!(!config.is_rule_enabled_in("function-name", RuleCategory::Naming, true));
+    // The category beats a section that only sets options, and the default
+    assert!(!config.is_rule_enabled_in("max-returns", RuleCategory::Design, true));
+    assert!(!config.is_rule_enabled_in("max-public-methods", RuleCategory::Design, true));
+    assert!(config.is_rule_enabled_in("no-single-char-identifier", RuleCategory::Naming, false));
+    // Categories without a toggle keep the defaults
+    assert!(config.is_rule_enabled_in("trailing-whitespace", RuleCategory::Format, true));
+    assert!(!config.is_rule_enabled_in("string-should-be-stringname", RuleCategory::Basic, false));
+}
+
+#[test]
+fn test_ruleset_merged_under_config() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("gdtools.toml"),
+        "ruleset = \"strict\"\n\n[rules.operator-spacing]\nseverity = \"info\"\n",
+    )
+    .unwrap();
+
+    let config = load_config_from(None, dir.path()).unwrap();
+    // From the preset
+    assert_eq!(
+        config.get_rule_severity("typed-parameters-required", Severity::Info),
+        Severity::Warning
+    );
+    assert!(config.is_rule_enabled_or("no-print-without-context", false));
+    // The user's config wins over the preset
+    assert_eq!(
+        config.get_rule_severity("operator-spacing", Severity::Info),
+        Severity::Info
+    );
+
+    std::fs::write(dir.path().join("gdtools.toml"), "ruleset = \"nope\"\n").unwrap();
+    let error = load_config_from(None, dir.path()).unwrap_err();
+    assert!(error.contains("Unknown ruleset 'nope'"));
+}
+
+#[test]
+fn test_builtin_rulesets_parse() {
+    for name in RULESET_NAMES {
+        let source = ruleset_source(name).unwrap();
+        let config: Config = toml::from_str(source).unwrap();
+        if *name == "minimal" {
+            assert_eq!(config.categories.get("naming"), Some(&false));
+        }
+    }
+    assert!(ruleset_source("unknown").is_none());
+}
+
+#[test]
+fn test_config_validate() {
+    let metas: Vec<_> = all_rules().iter().map(|rule| rule.meta().clone()).collect();
+    assert!(Config::default().validate(&metas).is_ok());
+
+    let config: Config = toml::from_str(
+        r#"
+include = ["src/[z-a].gd"]
+
+[rules]
+disable = ["no-such-rule"]
+
+[rules.function-name]
+severity = "error"
+
+[rules.typo-rule]
+severity = "warning"
+
+[categories]
+namng = false
+
+[regex_rules.no-todo]
+pattern = "TODO("
+message = "Unfinished work"
+"#,
+    )
+    .unwrap();
+    let problems = config.validate(&metas).unwrap_err();
+    assert_eq!(problems.len(), 5);
+    assert_eq!(problems[0], "Unknown rule 'typo-rule' in [rules.typo-rule]");
+    assert_eq!(problems[1], "Unknown rule 'no-such-rule' in rules.disable");
+    assert_eq!(problems[2], "Unknown category 'namng' in [categories]");
+    assert!(problems[3].starts_with("Invalid pattern for regex rule 'no-todo':"));
+    assert!(problems[4].starts_with("Invalid include glob 'src/[z-a].gd':"));
+
+    let config: Config = toml::from_str(
+        "[format]\nmax_consecutive_blank_lines = 6\nblank_lines_top_level_functions = 5\n\
+         blank_lines_class_members = 1\n",
+    )
+    .unwrap();
+    assert_eq!(
+        config.validate(&metas).unwrap_err(),
+        vec![
+            "format.max_consecutive_blank_lines must be between 1 and 4 (got 6)",
+            "format.blank_lines_top_level_functions is 5 but at most 4 blank lines are kept in a row",
+        ]
+    );
+}
@@ -1,4 +1,7 @@
-use gdtools::format::{compare_ast_with_source, run_formatter, AstCheckResult, FormatOptions};
+use gdtools::format::{
+    compare_ast_with_source, run_formatter, run_formatter_mapped, run_formatter_range,
+    unified_diff, AstCheckResult, FormatError, FormatOptions,
+};
 use tree_sitter::Parser;
 
 fn format(source: &str) -> String {
@@ -51,6 +54,28 @@ fn test_extends_statement() {
     assert_eq!(format("extends   Node2D\n"), "extends Node2D\n");
 }
 
+#[test]
+fn test_extends_quoted_path() {
+    let input = "extends   \"res://base  dir/base.gd\"\n";
+    assert_eq!(format(input), "extends \"res://base  dir/base.gd\"\n");
+    assert_ast_equivalent(input);
+}
+
+#[test]
+fn test_extends_qualified_name() {
+    let input = "extends  Outer . Inner\n";
+    assert_eq!(format(input), "extends Outer.Inner\n");
+    assert_ast_equivalent(input);
+    assert_ast_equivalent("extends \"res://base.gd\".Inner\n");
+}
+
+#[test]
+fn test_inner_class_extends() {
+    let input = "class Foo extends  Bar:\n\tpass\n";
+    assert_eq!(format(input), "class Foo extends Bar:\n\tpass\n");
+    assert_ast_equivalent(input);
+}
+
 #[test]
 fn test_class_name_statement() {
     assert_eq!(format("class_name   MyClass\n"), "class_name MyClass\n");
@@ -205,6 +230,70 @@ fn test_fmt_off_on() {
     assert!(formatted.contains("var y = 2"));
 }
 
+#[test]
+fn test_fmt_off_inside_expression() {
+    // A region bracketing part of an expression keeps the hand alignment
+    let source = "func foo():\n\tvar m = [\n\t\t# fmt: off\n\t\t1.0,  0.0,\n\t\t0.0,  1.0,\n\t\t# fmt: on\n\t]\n\tcall([\n\t\t# fmt: off\n\t\t1,    2,\n\t\t# fmt: on\n\t])\n";
+    assert_eq!(format(source), source);
+    assert_ast_equivalent(source);
+}
+
+#[test]
+fn test_format_options_from_config() {
+    let config: gdtools::config::Config =
+        toml::from_str("[format]\nline_length = 120\nindent_style = \"spaces\"\nindent_size = 2\n")
+            .unwrap();
+    let options = FormatOptions::from_config(&config.format);
+    assert_eq!(options.max_line_length, 120);
+    assert_eq!(options.indent_style, gdtools::IndentStyle::Spaces(2));
+    assert_eq!(
+        options.blank_lines_top_level_functions,
+        FormatOptions::default().blank_lines_top_level_functions
+    );
+
+    let options = FormatOptions::from_config(&Default::default());
+    assert_eq!(options.indent_style, gdtools::IndentStyle::Tabs);
+    assert_eq!(options.max_line_length, 100);
+}
+
+#[test]
+fn test_format_options_from_editorconfig() {
+    use gdtools::config::{FormatConfig, IndentKind};
+    use gdtools::IndentStyle;
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("scripts")).unwrap();
+    let file = dir.path().join("scripts").join("player.gd");
+
+    // No .editorconfig: default tabs
+    let options = FormatOptions::from_config_for_file(&FormatConfig::default(), &file);
+    assert_eq!(options.indent_style, IndentStyle::Tabs);
+
+    std::fs::write(
+        dir.path().join(".editorconfig"),
+        "root = true\n\n[*]\nindent_style = tab\n\n[*.gd]\nindent_style = space\nindent_size = 2\n",
+    )
+    .unwrap();
+    let options = FormatOptions::from_config_for_file(&FormatConfig::default(), &file);
+    assert_eq!(options.indent_style, IndentStyle::Spaces(2));
+
+    // Explicit settings (config file or CLI flags) beat .editorconfig
+    let explicit = FormatConfig {
+        indent_style: Some(IndentKind::Spaces),
+        indent_size: Some(3),
+        ..Default::default()
+    };
+    let options = FormatOptions::from_config_for_file(&explicit, &file);
+    assert_eq!(options.indent_style, IndentStyle::Spaces(3));
+
+    let disabled = FormatConfig {
+        editorconfig: Some(false),
+        ..Default::default()
+    };
+    let options = FormatOptions::from_config_for_file(&disabled, &file);
+    assert_eq!(options.indent_style, IndentStyle::Tabs);
+}
+
 #[test]
 fn test_indent_with_spaces() {
     let source = "func foo():\n\tpass\n";
@@ -212,6 +301,46 @@ fn test_indent_with_spaces() {
     assert_eq!(formatted, "func foo():\n    pass\n");
 }
 
+#[test]
+fn test_format_options_builder() {
+    assert_eq!(FormatOptions::default(), FormatOptions::godot_default());
+    assert_eq!(
+        FormatOptions::builder().build(),
+        FormatOptions::godot_default()
+    );
+
+    let options = FormatOptions::builder()
+        .indent_spaces(4)
+        .max_line_length(120)
+        .additional_virtual_methods(["_on_spawned"])
+        .build();
+    assert_eq!(options.indent_style, gdtools::IndentStyle::Spaces(4));
+    assert_eq!(options.max_line_length, 120);
+    assert_eq!(options.additional_virtual_methods, vec!["_on_spawned"]);
+    assert!(options.trailing_newline && !options.reorder);
+    assert_eq!(
+        run_formatter("func foo():\n\tpass\n", &options).unwrap(),
+        "func foo():\n    pass\n"
+    );
+}
+
+#[test]
+fn test_run_formatter_mapped() {
+    let source = "extends Node\nvar x = 1\nfunc foo():\n\tpass\n";
+    let (formatted, mapping) = run_formatter_mapped(source, &FormatOptions::default()).unwrap();
+    assert_eq!(
+        formatted,
+        run_formatter(source, &FormatOptions::default()).unwrap()
+    );
+    assert_eq!(mapping.len(), formatted.lines().count());
+
+    let lines: Vec<&str> = formatted.lines().collect();
+    let foo = lines.iter().position(|l| *l == "func foo():").unwrap();
+    assert_eq!(mapping[0], Some(1));
+    assert_eq!(mapping[foo], Some(3));
+    assert_eq!(mapping[foo + 1], Some(4));
+}
+
 #[test]
 fn test_trailing_newline() {
     assert_eq!(format("var x = 1"), "var x = 1\n");
@@ -614,6 +743,38 @@ fn test_no_blank_lines_between_functions_adds_two() {
     assert_eq!(format(input), expected);
 }
 
+#[test]
+fn test_configured_one_blank_line_between_functions() {
+    let options = FormatOptions {
+        blank_lines_top_level_functions: 1,
+        ..Default::default()
+    };
+    let format_one = |source: &str| run_formatter(source, &options).unwrap();
+
+    let expected = "var x = 1\n\nfunc foo():\n\tpass\n\nfunc bar():\n\tpass\n";
+    assert_eq!(
+        format_one("var x = 1\nfunc foo():\n\tpass\nfunc bar():\n\tpass\n"),
+        expected
+    );
+    // Extra blank lines around functions are collapsed to the configured count
+    assert_eq!(
+        format_one("var x = 1\n\n\nfunc foo():\n\tpass\n\n\nfunc bar():\n\tpass\n"),
+        expected
+    );
+}
+
+#[test]
+fn test_blank_line_counts_are_clamped() {
+    let options = FormatOptions {
+        blank_lines_top_level_functions: 10,
+        max_consecutive_blank_lines: 3,
+        ..Default::default()
+    };
+    let input = "func foo():\n\tpass\nfunc bar():\n\tpass\n";
+    let expected = "func foo():\n\tpass\n\n\n\nfunc bar():\n\tpass\n";
+    assert_eq!(run_formatter(input, &options).unwrap(), expected);
+}
+
 // -----------------------------------------------------------------------------
 // Rule: One blank line within functions to separate logical sections
 // -----------------------------------------------------------------------------
@@ -633,6 +794,15 @@ fn test_multiple_blank_lines_within_function_collapsed() {
     assert_eq!(format(input), expected);
 }
 
+#[test]
+fn test_blank_lines_at_block_edges_removed() {
+    assert_eq!(format("func f():\n\n\tpass\n"), "func f():\n\tpass\n");
+    // Nested blocks lose theirs too; the blank line between statements stays
+    let input = "func f():\n\tif x:\n\n\t\ta()\n\n\tb()\n";
+    let expected = "func f():\n\tif x:\n\t\ta()\n\n\tb()\n";
+    assert_eq!(format(input), expected);
+}
+
 // -----------------------------------------------------------------------------
 // Rule: Blank lines between declaration sections
 // -----------------------------------------------------------------------------
@@ -733,6 +903,71 @@ fn test_inline_comment_two_spaces() {
     assert_eq!(format("var x = 1 # comment\n"), "var x = 1  # comment\n");
 }
 
+#[test]
+fn test_run_formatter_range() {
+    let options = FormatOptions::default();
+    let input = "var a=1\nvar b=2\n\n\nfunc f():\n\tvar c=3\n\tvar d=4\n\tvar e=5\n";
+
+    // Only the statement on line 7 is reformatted
+    assert_eq!(
+        run_formatter_range(input, &options, 7, 7).unwrap(),
+        "var a=1\nvar b=2\n\n\nfunc f():\n\tvar c=3\n\tvar d = 4\n\tvar e=5\n"
+    );
+    // A range touching a top-level declaration reformats all of it
+    assert_eq!(
+        run_formatter_range(input, &options, 2, 5).unwrap(),
+        "var a=1\nvar b = 2\n\n\nfunc f():\n\tvar c = 3\n\tvar d = 4\n\tvar e = 5\n"
+    );
+    // Blank lines only: nothing to format
+    assert_eq!(run_formatter_range(input, &options, 3, 4).unwrap(), input);
+    // The whole file matches a full format
+    assert_eq!(
+        run_formatter_range(input, &options, 1, 8).unwrap(),
+        run_formatter(input, &options).unwrap()
+    );
+}
+
+#[test]
+fn test_reflow_comments() {
+    let options = FormatOptions {
+        max_line_length: 30,
+        reflow_comments: true,
+        ..Default::default()
+    };
+    let input = "\
+# Moves the player towards the target every frame
+func f():
+\t# Keep going until the target has been reached
+\tpass  # an inline comment that is far too long to fit
+
+
+# var speed = compute_speed(delta) * acceleration
+## A doc comment that is also longer than the limit
+var x = 1
+";
+    // Off by default
+    let unwrapped = format(input);
+    assert!(unwrapped.contains("# Moves the player towards the target every frame\n"));
+
+    let expected = unwrapped
+        .replace(
+            "# Moves the player towards the target every frame\n",
+            "# Moves the player towards the\n# target every frame\n",
+        )
+        .replace(
+            "\t# Keep going until the target has been reached\n",
+            "\t# Keep going until the\n\t# target has been reached\n",
+        );
+    let formatted = run_formatter(input, &options).unwrap();
+    assert_eq!(formatted, expected);
+    assert!(formatted.contains("\tpass  # an inline comment that is far too long to fit\n"));
+    assert!(formatted.contains("# var speed = compute_speed(delta) * acceleration\n"));
+    assert!(formatted.contains("## A doc comment that is also longer than the limit\n"));
+
+    // Wrapped comments are stable
+    assert_eq!(run_formatter(&formatted, &options).unwrap(), formatted);
+}
+
 // -----------------------------------------------------------------------------
 // Complete class formatting (based on style guide example)
 // -----------------------------------------------------------------------------
@@ -968,6 +1203,23 @@ fn test_single_line_enum_without_trailing_comma() {
     assert_eq!(format(input), input);
 }
 
+#[test]
+fn test_long_enum_expands_one_member_per_line() {
+    let input = "enum Element { FIRE_DAMAGE = 3, WATER_DAMAGE, EARTH_DAMAGE, WIND_DAMAGE, LIGHTNING_DAMAGE, SHADOW_DAMAGE }\n";
+    let expected = r#"enum Element {
+	FIRE_DAMAGE = 3,
+	WATER_DAMAGE,
+	EARTH_DAMAGE,
+	WIND_DAMAGE,
+	LIGHTNING_DAMAGE,
+	SHADOW_DAMAGE,
+}
+"#;
+    assert_eq!(format(input), expected);
+    assert_eq!(format(expected), expected);
+    assert_ast_equivalent(input);
+}
+
 // --- Function Call Trailing Comma Tests ---
 
 #[test]
@@ -1046,3 +1298,157 @@ fn test_continuation_indent_dict() {
 "#;
     assert_eq!(format(input), input);
 }
+
+// =============================================================================
+// Method Chain Tests
+// =============================================================================
+
+#[test]
+fn test_long_method_chain_wraps_one_call_per_line() {
+    let input = "func _ready():\n\tget_tree().create_tween().tween_property(self, \"position\", target_position, duration).set_trans(Tween.TRANS_SINE).set_ease(Tween.EASE_IN_OUT)\n";
+    let expected = "func _ready():\n\tget_tree() \\\n\t\t.create_tween() \\\n\t\t.tween_property(self, \"position\", target_position, duration) \\\n\t\t.set_trans(Tween.TRANS_SINE) \\\n\t\t.set_ease(Tween.EASE_IN_OUT)\n";
+    let formatted = format(input);
+    assert_eq!(formatted, expected);
+    assert_eq!(format(&formatted), expected);
+    assert_ast_equivalent(input);
+}
+
+#[test]
+fn test_short_method_chain_stays_inline() {
+    let input = "func _ready():\n\ttween.set_trans(1).set_ease(2)\n";
+    assert_eq!(format(input), input);
+}
+
+#[test]
+fn test_method_chain_width_includes_statement_prefix() {
+    // 93 columns alone, 107 once `var result := ` is in front of it
+    let chain = format!("tween.set_trans(1).set_ease({})", "x".repeat(60));
+    let input = format!("func _ready():\n\t{}\n", chain);
+    assert_eq!(format(&input), input);
+
+    let input = format!("func _ready():\n\tvar result := {}\n", chain);
+    let expected = format!(
+        "func _ready():\n\tvar result := tween \\\n\t\t.set_trans(1) \\\n\t\t.set_ease({})\n",
+        "x".repeat(60)
+    );
+    let formatted = format(&input);
+    assert_eq!(formatted, expected);
+    assert_eq!(format(&formatted), expected);
+    assert_ast_equivalent(&input);
+}
+
+#[test]
+fn test_method_chain_width_expands_tabs_to_tab_width() {
+    // 96 characters after one tab: fits in 100 columns at tab width 4, not at 8
+    let input = format!(
+        "func _ready():\n\ttween.set_trans(1).set_ease({})\n",
+        "x".repeat(67)
+    );
+    assert_eq!(format(&input), input);
+
+    let options = FormatOptions {
+        tab_width: 8,
+        ..Default::default()
+    };
+    let formatted = run_formatter(&input, &options).unwrap();
+    assert_ne!(formatted, input);
+    assert!(formatted.contains(" \\\n"));
+
+    assert_eq!(options.indent_style.width(options.tab_width), 8);
+    assert_eq!(gdtools::IndentStyle::Spaces(2).width(options.tab_width), 2);
+}
+
+#[test]
+fn test_wrapped_method_chain_rejoins_when_short() {
+    let input = "func _ready():\n\ttween.set_trans(1) \\\n\t\t.set_ease(2)\n";
+    assert_eq!(
+        format(input),
+        "func _ready():\n\ttween.set_trans(1).set_ease(2)\n"
+    );
+    assert_ast_equivalent(input);
+}
+
+#[test]
+fn test_unified_diff() {
+    let original = "extends Node\n\nvar x=1\n";
+    let formatted = format(original);
+
+    let diff = unified_diff("player.gd", original, &formatted);
+    assert!(diff.starts_with("--- player.gd\n+++ player.gd\n@@ "));
+    assert!(diff.contains("\n-var x=1\n+var x = 1\n"));
+
+    assert_eq!(unified_diff("player.gd", &formatted, &formatted), "");
+}
+
+#[test]
+fn test_space_inside_braces() {
+    let input = "var d = {\"a\": 1, \"b\": 2}\nvar empty = {}\n";
+    assert_eq!(
+        format(input),
+        "var d = { \"a\": 1, \"b\": 2 }\nvar empty = {}\n"
+    );
+
+    let options = FormatOptions {
+        space_inside_braces: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        run_formatter(input, &options).unwrap(),
+        "var d = {\"a\": 1, \"b\": 2}\nvar empty = {}\n"
+    );
+}
+
+#[test]
+fn test_space_inside_brackets() {
+    let input = "var a = [1, 2, [3]]\nvar empty = []\n";
+    assert_eq!(format(input), input);
+
+    let options = FormatOptions {
+        space_inside_brackets: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        run_formatter(input, &options).unwrap(),
+        "var a = [ 1, 2, [ 3 ] ]\nvar empty = []\n"
+    );
+}
+
+#[test]
+fn test_align_consecutive_assignments() {
+    let input =
+        "func f():\n\tvar speed = 1\n\tvar max_health: int = 10\n\thealth = 5\n\n\tvar x = 3\n";
+    // Off by default
+    assert_eq!(format(input), input);
+
+    let options = FormatOptions {
+        align_consecutive_assignments: true,
+        ..Default::default()
+    };
+    let expected =
+        "func f():\n\tvar speed           = 1\n\tvar max_health: int = 10\n\thealth              = 5\n\n\tvar x = 3\n";
+    let aligned = run_formatter(input, &options).unwrap();
+    assert_eq!(aligned, expected);
+    assert_eq!(run_formatter(&aligned, &options).unwrap(), expected);
+}
+
+#[test]
+fn test_verify_ast() {
+    let options = FormatOptions {
+        verify_ast: true,
+        ..Default::default()
+    };
+    let input = "func f(x):\n    if x>1:\n        return [1,2]\n    return {\"a\":x}\n";
+    // Equivalent output is returned unchanged by the check
+    assert_eq!(run_formatter(input, &options).unwrap(), format(input));
+    let formatted = format(input);
+    assert_eq!(run_formatter(&formatted, &options).unwrap(), formatted);
+
+    let error = FormatError::AstChanged {
+        path: "source.function_definition[0]".to_string(),
+        difference: "missing node".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "AST changed after formatting\nPath: source.function_definition[0]\nDifference: missing node"
+    );
+}
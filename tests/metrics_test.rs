@@ -0,0 +1,59 @@
+use gdtools::lint::compute_metrics;
+
+#[test]
+fn test_compute_metrics() {
+    let source = "\
+extends Node
+
+func _ready():
+\tpass
+
+func update(delta):
+\tfor i in range(3):
+\t\tif i > 1:
+\t\t\twhile true:
+\t\t\t\tbreak
+\t\telse:
+\t\t\tpass
+
+class Inner:
+\tfunc helper():
+\t\tif true:
+\t\t\tpass
+";
+    let metrics = compute_metrics(source).unwrap();
+    assert_eq!(metrics.lines, 17);
+    assert_eq!(metrics.functions, 3);
+    assert_eq!(metrics.public_methods, 2);
+    assert_eq!(metrics.max_nesting_depth, 3);
+    // 2 + 7 + 3 lines
+    assert!((metrics.average_function_length - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_compute_metrics_counts_init() {
+    let source = "\
+func _init():
+\tif true:
+\t\tfor i in 3:
+\t\t\tpass
+
+func run():
+\tpass
+";
+    let metrics = compute_metrics(source).unwrap();
+    assert_eq!(metrics.functions, 2);
+    assert_eq!(metrics.public_methods, 1);
+    assert_eq!(metrics.max_nesting_depth, 2);
+    // 4 + 2 lines
+    assert!((metrics.average_function_length - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_compute_metrics_without_functions() {
+    let metrics = compute_metrics("var x = 1\n").unwrap();
+    assert_eq!(metrics.lines, 1);
+    assert_eq!(metrics.functions, 0);
+    assert_eq!(metrics.max_nesting_depth, 0);
+    assert_eq!(metrics.average_function_length, 0.0);
+}
@@ -2,7 +2,10 @@
 //!
 //! Following TDD approach: these tests are written first, then the implementation.
 
-use gdtools::format::{reorder_source, run_formatter, FormatOptions};
+use gdtools::format::{
+    check_order, check_order_with_options, reorder_source, reorder_source_with_options,
+    run_formatter, FormatOptions,
+};
 
 /// Helper to format then reorder.
 fn reorder(source: &str) -> String {
@@ -366,6 +369,43 @@ var acceleration: float = 5.0
     assert!(subgroup_pos < var_pos);
 }
 
+#[test]
+fn test_export_groups_stay_together_when_reordered() {
+    // `@onready @export` sorts after plain exports, but must not leave its group
+    let input = r#"extends Node
+
+@export_group("Movement")
+@export var speed: float = 1.0
+@onready @export var target: Node
+@export_group("Combat")
+@export var damage: int = 2
+@onready @export var weapon: Node
+var health: int = 3
+
+signal died
+"#;
+    let result = reorder(input);
+    let positions: Vec<usize> = [
+        "signal died",
+        "@export_group(\"Movement\")",
+        "var speed",
+        "var target",
+        "@export_group(\"Combat\")",
+        "var damage",
+        "var weapon",
+        "var health",
+    ]
+    .iter()
+    .map(|needle| result.find(needle).unwrap())
+    .collect();
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "groups interleaved:\n{}",
+        result
+    );
+    assert!(check_order(&result).unwrap().is_empty());
+}
+
 #[test]
 fn test_multiple_export_annotations_preserved() {
     // Multiple export annotations stacked should all be preserved
@@ -1168,6 +1208,53 @@ var opp_transition_to: String = ""  # Used for grabs
     assert_eq!(result.matches("var opp_transition_to").count(), 1);
 }
 
+#[test]
+fn test_inline_comment_on_export_annotation_moves_once() {
+    // The inline comment trails the annotation line, not the variable line
+    let input = r#"extends Node
+
+func _ready():
+	pass
+
+@export_range(0, 100, 1)  # percent
+var health: int = 100
+"#;
+    let expected = r#"extends Node
+
+@export_range(0, 100, 1)  # percent
+var health: int = 100
+
+
+func _ready():
+	pass
+"#;
+    let result = reorder_source(input).unwrap();
+    assert_eq!(result, expected);
+    assert_eq!(reorder_source(&result).unwrap(), expected);
+}
+
+#[test]
+fn test_inline_comment_in_multiline_export_range_moves_once() {
+    let input = r#"extends Node
+
+func _ready():
+	pass
+
+@export_range(0, 100, 1,  # percent
+	"suffix:%")
+var health: int = 100  # current
+var armor: int = 0
+"#;
+    let result = reorder_source(input).unwrap();
+    assert_eq!(result.matches("# percent").count(), 1);
+    assert_eq!(result.matches("# current").count(), 1);
+    assert!(result.starts_with(
+        "extends Node\n\n@export_range(0, 100, 1,  # percent\n\t\"suffix:%\")\nvar health: int = 100  # current\n"
+    ));
+    assert!(result.ends_with("func _ready():\n\tpass\n"));
+    assert_eq!(result.matches("var armor").count(), 1);
+}
+
 #[test]
 fn test_orphaned_export_category_preserved() {
     // @export_category at end of file with no following variable should be preserved
@@ -1183,3 +1270,190 @@ class_name TriggerClearHitboxes
         "Orphaned @export_category should be preserved"
     );
 }
+
+// ============================================================================
+// Check Mode Tests
+// ============================================================================
+
+#[test]
+fn test_check_order_reports_misplaced_members() {
+    let input = r#"extends Node
+
+func foo():
+	pass
+
+var x = 1
+signal died
+"#;
+    let violations = check_order(input).expect("check should succeed");
+    let summary: Vec<_> = violations
+        .iter()
+        .map(|v| (v.member.as_str(), v.line, v.expected_after.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("var x", 6, Some("signal died")),
+            ("signal died", 7, Some("extends Node")),
+        ]
+    );
+}
+
+#[test]
+fn test_check_order_ordered_file_is_clean() {
+    let input = r#"extends Node
+
+signal died
+
+var x = 1
+
+
+func foo():
+	pass
+"#;
+    assert!(check_order(input).expect("check should succeed").is_empty());
+}
+
+#[test]
+fn test_check_order_inner_class() {
+    let input = r#"extends Node
+
+
+class Inner:
+	func bar():
+		pass
+
+	var y = 2
+"#;
+    let violations = check_order(input).expect("check should succeed");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].member, "var y");
+    assert_eq!(violations[0].line, 8);
+    assert_eq!(violations[0].expected_after, None);
+}
+
+#[test]
+fn test_additional_virtual_methods() {
+    let input = r#"extends Node
+
+
+func _private_helper():
+	pass
+
+
+func enter_state():
+	pass
+
+
+func _ready():
+	pass
+"#;
+    let order = |options: &FormatOptions| -> Vec<usize> {
+        let formatted = run_formatter(input, options).expect("formatting should succeed");
+        let output =
+            reorder_source_with_options(&formatted, options).expect("reordering should succeed");
+        ["func _ready", "func enter_state", "func _private_helper"]
+            .iter()
+            .map(|f| output.find(f).expect("method should be kept"))
+            .collect()
+    };
+
+    // By default a custom method is a regular method, after private helpers
+    let default = order(&FormatOptions::default());
+    assert!(default[0] < default[2] && default[2] < default[1]);
+
+    let options = FormatOptions {
+        additional_virtual_methods: vec!["enter_state".to_string()],
+        ..FormatOptions::default()
+    };
+    let custom = order(&options);
+    assert!(custom[0] < custom[1] && custom[1] < custom[2]);
+
+    assert!(check_order_with_options(input, &options)
+        .expect("check should succeed")
+        .iter()
+        .any(|v| v.member == "func enter_state"));
+}
+
+// ============================================================================
+// Alphabetical Sorting Tests
+// ============================================================================
+
+/// Helper to format then reorder with alphabetical sorting options.
+fn reorder_sorted(source: &str, sort_enum_members: bool, sort_constants: bool) -> String {
+    let options = FormatOptions {
+        sort_enum_members,
+        sort_constants,
+        ..FormatOptions::default()
+    };
+    let formatted = run_formatter(source, &options).expect("formatting should succeed");
+    reorder_source_with_options(&formatted, &options).expect("reordering should succeed")
+}
+
+#[test]
+fn test_sort_enum_members() {
+    let input = r#"extends Node
+
+enum State { RUNNING, IDLE, JUMPING }
+enum Dir {
+	UP,
+	DOWN,
+	LEFT,
+}
+"#;
+    let expected = r#"extends Node
+
+enum State { IDLE, JUMPING, RUNNING }
+enum Dir {
+	DOWN,
+	LEFT,
+	UP,
+}
+"#;
+    assert_eq!(reorder_sorted(input, true, false), expected);
+    // Off by default
+    assert_eq!(reorder_sorted(input, false, false), input);
+}
+
+#[test]
+fn test_sort_enum_members_keeps_explicit_values() {
+    // Moving any member would change the values assigned after an explicit one
+    let input = r#"extends Node
+
+enum Flags { B = 2, A = 1 }
+enum Mixed { SECOND, FIRST, THIRD = 10 }
+"#;
+    assert_eq!(reorder_sorted(input, true, false), input);
+}
+
+#[test]
+fn test_sort_constants() {
+    let input = r#"extends Node
+
+const SPEED = 10
+const ACCEL = 2
+
+var x = 1
+
+const MAX = 5
+"#;
+    let expected = r#"extends Node
+
+const ACCEL = 2
+const MAX = 5
+const SPEED = 10
+
+var x = 1
+"#;
+    assert_eq!(reorder_sorted(input, false, true), expected);
+
+    let unsorted = r#"extends Node
+
+const SPEED = 10
+const ACCEL = 2
+const MAX = 5
+
+var x = 1
+"#;
+    assert_eq!(reorder_sorted(input, false, false), unsorted);
+}
@@ -1,8 +1,13 @@
 use std::path::PathBuf;
 
 use gdtools::config::Config;
-use gdtools::lint::run_linter;
-use gdtools::rules::all_rules;
+use gdtools::format::{check_order, reorder_source};
+use gdtools::lint::{
+    apply_fixes, class_name_collisions, find_class_name, run_linter, run_linter_profiled,
+    LintContext, Rule, RuleCategory, RuleMetadata, RuleProfile, Severity,
+};
+use gdtools::rules::{all_rules, regex_rules};
+use tree_sitter::Node;
 
 fn lint_code(source: &str) -> Vec<(String, String)> {
     let config = Config::default();
@@ -20,6 +25,187 @@ fn has_rule_violation(source: &str, rule_id: &str) -> bool {
     lint_code(source).iter().any(|(id, _)| id == rule_id)
 }
 
+// ============================================================================
+// Runner Tests
+// ============================================================================
+
+/// Test-only rule that reports the path of the file being linted.
+struct ReportFilePathRule {
+    meta: RuleMetadata,
+}
+
+impl Rule for ReportFilePathRule {
+    fn meta(&self) -> &RuleMetadata {
+        &self.meta
+    }
+
+    fn check_node(&self, _node: Node<'_>, _ctx: &mut LintContext<'_>) {}
+
+    fn check_file_start(&self, ctx: &mut LintContext<'_>) {
+        let message = ctx.file_path().display().to_string();
+        ctx.report(
+            gdtools::Diagnostic::new(self.meta.id, Severity::Info, message).with_location(1, 1),
+        );
+    }
+}
+
+#[test]
+fn test_file_path_is_available_to_rules() {
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(ReportFilePathRule {
+        meta: RuleMetadata {
+            id: "report-file-path",
+            name: "Report File Path",
+            category: RuleCategory::Basic,
+            default_severity: Severity::Info,
+            description: "Reports the linted file path",
+        },
+    })];
+    let path = PathBuf::from("scenes/player.gd");
+
+    let diagnostics = run_linter("var x = 1", &path, &rules, &Config::default()).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "scenes/player.gd");
+    assert_eq!(diagnostics[0].file_path, path);
+}
+
+#[test]
+fn test_run_linter_profiled() {
+    let config = Config::default();
+    let rules = all_rules();
+    let path = PathBuf::from("test.gd");
+    let source = "func f():\n    var MyVar = 1\n    print(MyVar)\n";
+
+    let mut profile = RuleProfile::default();
+    let profiled = run_linter_profiled(source, &path, &rules, &config, Some(&mut profile)).unwrap();
+    let plain = run_linter(source, &path, &rules, &config).unwrap();
+    assert_eq!(profiled.len(), plain.len());
+
+    // Every rule is timed, slowest first
+    let timings = profile.sorted();
+    assert_eq!(timings.len(), rules.len());
+    assert!(timings.windows(2).all(|w| w[0].1 >= w[1].1));
+    let sum: std::time::Duration = timings.iter().map(|(_, d)| *d).sum();
+    assert_eq!(profile.total(), sum);
+}
+
+#[test]
+fn test_every_rule_has_long_description() {
+    use gdtools::rules::docs::long_description;
+
+    for rule in all_rules() {
+        let id = rule.meta().id;
+        assert!(long_description(id).is_some(), "{} has no explanation", id);
+    }
+    assert!(long_description("no-such-rule").is_none());
+}
+#[test]
+fn test_fail_on_thresholds() {
+    let all = [
+        Severity::Error,
+        Severity::Warning,
+        Severity::Info,
+        Severity::Hint,
+    ];
+    let failing = |threshold| {
+        all.iter()
+            .filter(|s| s.fails_run(threshold))
+            .copied()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(failing(Severity::Error), vec![Severity::Error]);
+    assert_eq!(
+        failing(Severity::Warning),
+        vec![Severity::Error, Severity::Warning]
+    );
+    assert_eq!(
+        failing(Severity::Info),
+        vec![Severity::Error, Severity::Warning, Severity::Info]
+    );
+    // Hints never fail a run
+    assert_eq!(failing(Severity::Hint), failing(Severity::Info));
+}
+
+#[test]
+fn test_misnamed_class_variable_reported_once() {
+    let rules: Vec<_> = all_rules()
+        .into_iter()
+        .filter(|r| r.enabled_by_default() && r.meta().category == RuleCategory::Naming)
+        .collect();
+    let diagnostics = run_linter(
+        "var myVar = 1",
+        &PathBuf::from("test.gd"),
+        &rules,
+        &Config::default(),
+    )
+    .unwrap();
+    let at_name: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| (d.line, d.column) == (1, 5))
+        .map(|d| d.rule_id.as_str())
+        .collect();
+    assert_eq!(at_name, vec!["class-variable-name"]);
+}
+
+#[test]
+fn test_class_name_collisions_across_files() {
+    let scripts = [
+        ("player.gd", "class_name Player\nextends Node\n"),
+        ("enemy.gd", "extends Node\n"),
+        ("old/player.gd", "extends Node\nclass_name Player\n"),
+        ("hud.gd", "class_name Hud\n"),
+    ];
+    let declarations: Vec<_> = scripts
+        .iter()
+        .filter_map(|(path, source)| find_class_name(source, &PathBuf::from(path)).unwrap())
+        .collect();
+    assert_eq!(declarations.len(), 3);
+
+    let diagnostics = class_name_collisions(&declarations, &Config::default());
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics
+        .iter()
+        .all(|d| d.rule_id == "no-global-class-name-collision" && d.severity == Severity::Error));
+    assert_eq!(diagnostics[0].file_path, PathBuf::from("player.gd"));
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (1, 12));
+    assert!(diagnostics[0].message.contains("old/player.gd:2"));
+    assert_eq!(diagnostics[1].file_path, PathBuf::from("old/player.gd"));
+    assert!(diagnostics[1].message.contains("player.gd:1"));
+}
+
+#[test]
+fn test_duplicate_diagnostics_deduped() {
+    let config = r#"
+[regex_rules.no-print]
+pattern = 'print\('
+message = "Use the logger"
+
+[regex_rules.no-print-call]
+pattern = 'print\('
+message = "Use the logger."
+severity = "error"
+"#;
+    // Only merged when asked for
+    let diagnostics = lint_with_config("func f():\n\tprint(1)", config).unwrap();
+    assert_eq!(diagnostics.len(), 2);
+
+    let deduped = format!("dedupe_diagnostics = true\n{}", config);
+    let diagnostics = lint_with_config("func f():\n\tprint(1)", &deduped).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule_id, "no-print-call");
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(
+        diagnostics[0].message,
+        "Use the logger. (also reported by no-print)"
+    );
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 2));
+
+    // Different messages at the same position are both kept
+    let deduped = deduped.replace("\"Use the logger.\"", "\"Avoid print\"");
+    let diagnostics = lint_with_config("func f():\n\tprint(1)", &deduped).unwrap();
+    assert_eq!(diagnostics.len(), 2);
+}
+
 // ============================================================================
 // Naming Rules Tests
 // ============================================================================
@@ -122,6 +308,41 @@ fn test_function_variable_name_snake_case() {
     ));
 }
 
+#[test]
+fn test_function_variable_name_messages() {
+    let find = |source: &str| {
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &all_rules(),
+            &Config::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .find(|d| d.rule_id == "function-variable-name")
+        .expect("expected function-variable-name violation")
+    };
+
+    // ALL_CAPS locals should probably be constants
+    let constant = find("func f():\n    var MAX_SPEED = 10");
+    assert!(constant
+        .message
+        .contains("looks like a constant: use const"));
+    assert_eq!(constant.suggestion.as_deref(), Some("const MAX_SPEED"));
+
+    // PascalCase locals look like types
+    let pascal = find("func f():\n    var PlayerState = 0");
+    assert!(pascal.message.contains("did you mean a type?"));
+    assert_eq!(pascal.suggestion.as_deref(), Some("player_state"));
+
+    let camel = find("func f():\n    var playerState = 0");
+    assert_eq!(
+        camel.message,
+        "Function variable should be snake_case: \"playerState\""
+    );
+    assert_eq!(camel.suggestion.as_deref(), Some("player_state"));
+}
+
 #[test]
 fn test_class_load_variable_name() {
     // Class load/preload variables should be PascalCase or snake_case
@@ -195,6 +416,35 @@ fn test_function_argument_name() {
         "func f(MyArg):\n    pass",
         "function-argument-name"
     ));
+    // Using an underscore-prefixed argument is only flagged when opted in
+    assert!(!has_rule_violation(
+        "func f(_delta):\n    print(_delta)",
+        "function-argument-name"
+    ));
+}
+
+#[test]
+fn test_function_argument_name_unused_prefix_must_be_unused() {
+    use gdtools::rules::naming::FunctionArgumentNameRule;
+
+    let config: Config =
+        toml::from_str("[rules.function-argument-name]\nunused_prefix_must_be_unused = true\n")
+            .unwrap();
+    let mut rule = FunctionArgumentNameRule::default();
+    rule.configure(config.get_rule_config("function-argument-name").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let lint =
+        |source: &str| run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+
+    let diagnostics = lint("func f(_delta: float, speed):\n    position.x += speed * _delta");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Info);
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (1, 8));
+
+    assert!(lint("func f(_delta, _):\n    pass").is_empty());
+    // Only reads count: a member access with the same name is something else
+    assert!(lint("func f(_delta):\n    self._delta = 1").is_empty());
 }
 
 #[test]
@@ -213,6 +463,134 @@ fn test_loop_variable_name() {
     ));
 }
 
+#[test]
+fn test_signal_should_be_past_tense() {
+    assert!(!has_rule_violation(
+        "signal health_changed",
+        "signal-should-be-past-tense"
+    ));
+    assert!(!has_rule_violation(
+        "signal died\nsignal area_hit\nsignal timeout",
+        "signal-should-be-past-tense"
+    ));
+    assert!(has_rule_violation(
+        "signal door_open",
+        "signal-should-be-past-tense"
+    ));
+    // Casing problems are left to signal-name
+    assert!(!has_rule_violation(
+        "signal DoorOpen",
+        "signal-should-be-past-tense"
+    ));
+}
+
+#[test]
+fn test_signal_should_be_past_tense_configure() {
+    use gdtools::rules::naming::SignalPastTenseRule;
+
+    let config: Config = toml::from_str(
+        "[rules.signal-should-be-past-tense]\nsuffixes = [\"ed\", \"ing\"]\nexceptions = [\"door_open\"]\n",
+    )
+    .unwrap();
+    let mut rule = SignalPastTenseRule::default();
+    rule.configure(
+        config
+            .get_rule_config("signal-should-be-past-tense")
+            .unwrap(),
+    )
+    .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "signal door_open\nsignal tree_exiting\nsignal area_hit";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    let lines: Vec<_> = diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![3]);
+}
+
+#[test]
+fn test_no_single_char_identifier() {
+    let flagged = |source: &str| -> Vec<String> {
+        lint_code(source)
+            .into_iter()
+            .filter(|(id, _)| id == "no-single-char-identifier")
+            .map(|(_, message)| message)
+            .collect()
+    };
+
+    assert_eq!(flagged("var v = 1").len(), 1);
+    assert_eq!(flagged("func f(a, b: int, c = 1):\n    pass").len(), 3);
+    assert_eq!(flagged("for e in enemies:\n    pass").len(), 1);
+    assert!(flagged("for i in range(10):\n    var x = i").is_empty());
+    assert!(flagged("func f(_, velocity):\n    var _unused = velocity").is_empty());
+    assert!(flagged("var v = 1")[0].contains("\"v\""));
+
+    let diagnostics = run_linter(
+        "var v = 1",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostic = diagnostics
+        .iter()
+        .find(|d| d.rule_id == "no-single-char-identifier")
+        .unwrap();
+    assert_eq!(diagnostic.severity, Severity::Hint);
+}
+
+#[test]
+fn test_no_single_char_identifier_configure() {
+    use gdtools::rules::naming::NoSingleCharIdentifierRule;
+
+    let config: Config =
+        toml::from_str("[rules.no-single-char-identifier]\nallowed = [\"n\"]\n").unwrap();
+    let mut rule = NoSingleCharIdentifierRule::default();
+    rule.configure(config.get_rule_config("no-single-char-identifier").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "func f(n):\n    for i in range(n):\n        pass";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    let lines: Vec<_> = diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![2]);
+}
+
+#[test]
+fn test_no_camelcase_anywhere() {
+    use gdtools::rules::naming::NoCamelCaseAnywhereRule;
+
+    let rule = NoCamelCaseAnywhereRule::default();
+    assert!(!rule.enabled_by_default());
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "signal healthChanged\nvar maxSpeed = 1\nvar Player = null\n\nfunc takeDamage(hitPoints: int, _on_Button = 0):\n    var total = hitPoints\n\nfunc _on_Button_pressed():\n    pass";
+    let diagnostics = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &rules,
+        &Config::default(),
+    )
+    .unwrap();
+    let found: Vec<_> = diagnostics
+        .iter()
+        .map(|d| (d.line, d.suggestion.as_deref().unwrap()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, "health_changed"),
+            (2, "max_speed"),
+            (5, "take_damage"),
+            (5, "hit_points"),
+        ]
+    );
+    assert_eq!(diagnostics[0].severity, Severity::Info);
+    assert_eq!(
+        diagnostics[0].message,
+        "\"healthChanged\" is camelCase, use snake_case"
+    );
+}
+
 #[test]
 fn test_sub_class_name() {
     // Inner classes should be PascalCase
@@ -253,6 +631,24 @@ fn test_max_line_length() {
     assert!(has_rule_violation(&long_line, "max-line-length"));
 }
 
+#[test]
+fn test_max_line_length_expands_tabs() {
+    use gdtools::rules::format::MaxLineLengthRule;
+
+    // 97 characters, but 103 columns with the default tab width of 4
+    let source = format!("func f():\n\t\tvar s = \"{}\"", "a".repeat(85));
+    assert_eq!(source.lines().nth(1).unwrap().chars().count(), 97);
+    assert!(has_rule_violation(&source, "max-line-length"));
+
+    let config: Config = toml::from_str("[rules.max-line-length]\ntab_width = 1\n").unwrap();
+    let mut rule = MaxLineLengthRule::default();
+    rule.configure(config.get_rule_config("max-line-length").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let diagnostics = run_linter(&source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn test_trailing_whitespace() {
     assert!(!has_rule_violation("var x = 1", "trailing-whitespace"));
@@ -268,6 +664,78 @@ fn test_mixed_tabs_spaces() {
     assert!(has_rule_violation(" \tvar x = 1", "mixed-tabs-spaces"));
 }
 
+#[test]
+fn test_no_tabs_in_continuation() {
+    let rule = "no-tabs-in-continuation";
+    assert!(has_rule_violation("func f():\n\tfoo   = 1", rule));
+    assert!(has_rule_violation("func f():\n\tcall(a,  b)", rule));
+
+    assert!(!has_rule_violation("func f():\n\tfoo = 1", rule));
+    assert!(!has_rule_violation(
+        "func f():\n\tfoo = 1   # aligned comment",
+        rule
+    ));
+    assert!(!has_rule_violation("func f():\n\tfoo = \"a   b\"", rule));
+    // Space-indented lines are left to other rules
+    assert!(!has_rule_violation("func f():\n    foo   = 1", rule));
+
+    let diagnostics: Vec<_> = lint_code("func f():\n\tfoo   = 1")
+        .into_iter()
+        .filter(|(id, _)| id == rule)
+        .collect();
+    assert_eq!(diagnostics.len(), 1);
+
+    // Spaces inside a multiline string are content, not alignment
+    assert!(!has_rule_violation(
+        "func f():\n\tvar s = \"\"\"\n\tkeep   these\n\t\"\"\"",
+        rule
+    ));
+}
+
+#[test]
+fn test_no_tabs_in_continuation_allows_aligned_assignments() {
+    let rule = "no-tabs-in-continuation";
+    // What the formatter writes with `align_consecutive_assignments`
+    let source =
+        "func f():\n\tvar speed           = 1\n\tvar max_health: int = 10\n\tif a  == b:\n\t\tpass\n";
+    let lines_flagged = |config: &Config| -> Vec<usize> {
+        run_linter(source, &PathBuf::from("test.gd"), &all_rules(), config)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.rule_id == rule)
+            .map(|d| d.line)
+            .collect()
+    };
+    assert_eq!(lines_flagged(&Config::default()), vec![2, 4]);
+
+    let config: Config =
+        toml::from_str("[format]\nalign_consecutive_assignments = true\n").unwrap();
+    assert_eq!(lines_flagged(&config), vec![4]);
+}
+
+#[test]
+fn test_no_inline_tabs() {
+    let rule = "no-inline-tabs";
+    let diagnostics = run_linter(
+        "func f(a):\n\tfoo(\ta)",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let locations: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule)
+        .map(|d| (d.line, d.column))
+        .collect();
+    assert_eq!(locations, vec![(2, 6)]);
+    assert!(has_rule_violation("var x =\t1", rule));
+
+    assert!(!has_rule_violation("func f():\n\t\tpass", rule));
+    assert!(!has_rule_violation("var s = \"a\tb\"", rule));
+    assert!(!has_rule_violation("var x = 1 #\tnote", rule));
+}
+
 #[test]
 fn test_max_file_lines() {
     let short_file = "var x = 1\nvar y = 2";
@@ -281,10 +749,185 @@ fn test_max_file_lines() {
     assert!(has_rule_violation(&long_file, "max-file-lines"));
 }
 
+#[test]
+fn test_max_file_lines_options() {
+    use gdtools::rules::format::MaxFileLinesRule;
+
+    let lint = |config_toml: &str, source: &str| {
+        let config: Config = toml::from_str(config_toml).unwrap();
+        let mut rule = MaxFileLinesRule::default();
+        rule.configure(config.get_rule_config("max-file-lines").unwrap())
+            .unwrap();
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap()
+    };
+    let body = (0..5)
+        .map(|i| format!("var v{} = {}", i, i))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let generated = format!("# GENERATED - do not edit\n{}", body);
+    let skipping = "[rules.max-file-lines]\nmax = 3\nskip_if_contains = [\"# GENERATED\"]\n";
+    assert!(lint(skipping, &generated).is_empty());
+    assert_eq!(lint(skipping, &body).len(), 1);
+
+    // 9 lines in total, 5 of them non-blank
+    let blank_aware = "[rules.max-file-lines]\nmax = 5\ncount_blank = false\n";
+    assert!(lint(blank_aware, &body).is_empty());
+    let diagnostics = lint(
+        "[rules.max-file-lines]\nmax = 4\ncount_blank = false\n",
+        &body,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "File has 5 lines (max 4)");
+    assert_eq!(diagnostics[0].line, 9);
+    assert_eq!(lint("[rules.max-file-lines]\nmax = 5\n", &body).len(), 1);
+
+    let config: Config = toml::from_str("[rules.max-file-lines]\ncount_blank = \"no\"\n").unwrap();
+    let err = MaxFileLinesRule::default()
+        .configure(config.get_rule_config("max-file-lines").unwrap())
+        .unwrap_err();
+    assert_eq!(err, "max-file-lines: 'count_blank' must be a boolean");
+}
+
+#[test]
+fn test_trailing_comma_required_in_multiline() {
+    let rule = "trailing-comma-required-in-multiline";
+
+    assert!(has_rule_violation("var a = [\n\t1,\n\t2\n]", rule));
+    assert!(has_rule_violation("var d = {\n\t\"a\": 1\n}", rule));
+    assert!(has_rule_violation(
+        "func f():\n\tfoo(\n\t\t1,\n\t\t2\n\t)",
+        rule
+    ));
+
+    // Reported at the closing bracket
+    let diagnostics = run_linter(
+        "var a = [\n\t1,\n\t2\n]",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostic = diagnostics.iter().find(|d| d.rule_id == rule).unwrap();
+    assert_eq!((diagnostic.line, diagnostic.column), (4, 1));
+
+    assert!(!has_rule_violation("var a = [\n\t1,\n\t2,\n]", rule));
+    assert!(!has_rule_violation(
+        "var a = [\n\t1,\n\t2,  # last\n]",
+        rule
+    ));
+    assert!(!has_rule_violation("var a = [1, 2]", rule));
+    assert!(!has_rule_violation("var a = [\n]", rule));
+    // Closing bracket hugs the last element
+    assert!(!has_rule_violation("func f():\n\tfoo(1,\n\t\t2)", rule));
+}
+
+#[test]
+fn test_operator_spacing() {
+    let rule = "operator-spacing";
+    let flagged = |source: &str| -> Vec<(usize, usize, String)> {
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &all_rules(),
+            &Config::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .filter(|d| d.rule_id == rule)
+        .map(|d| (d.line, d.column, d.message))
+        .collect()
+    };
+
+    assert_eq!(
+        flagged(
+            "var a = b+c
+var d = e == f"
+        ),
+        vec![(1, 10, "Missing space around operator '+'".to_string())]
+    );
+    assert_eq!(
+        flagged(
+            "func f(x):
+	if x==1 and x	< 2:
+		pass"
+        ),
+        vec![
+            (2, 6, "Missing space around operator '=='".to_string()),
+            (
+                2,
+                16,
+                "Use a space, not a tab, around operator '<'".to_string()
+            ),
+        ]
+    );
+
+    // Unary operators, keyword operators and wrapped lines are fine
+    assert!(flagged(
+        "var a = -b
+var c = not d
+var e = f in g"
+    )
+    .is_empty());
+    assert!(flagged(
+        "var a = (b +
+	c)"
+    )
+    .is_empty());
+    // A continuation line may start with the operator after its tab indentation
+    assert!(flagged(
+        "func f(a, b):
+	var c = a \\
+		+ b
+	return c"
+    )
+    .is_empty());
+    assert_eq!(
+        flagged(
+            "func f(a, b):
+	var c = a \\
+		+	b"
+        ),
+        vec![(
+            3,
+            3,
+            "Use a space, not a tab, around operator '+'".to_string()
+        )]
+    );
+    // `# fmt: off` regions are skipped
+    assert!(flagged(
+        "# fmt: off
+var a = b+c
+# fmt: on"
+    )
+    .is_empty());
+}
+
 // ============================================================================
 // Basic Rules Tests
 // ============================================================================
 
+#[test]
+fn test_syntax_error() {
+    let diagnostics = run_linter(
+        "func foo(:\n    pass",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let errors: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == "syntax-error")
+        .collect();
+    assert!(!errors.is_empty());
+    assert!(errors.iter().all(|d| d.severity == Severity::Error));
+    assert_eq!(errors[0].line, 1);
+
+    assert!(!has_rule_violation("func foo():\n    pass", "syntax-error"));
+}
+
 #[test]
 fn test_unnecessary_pass() {
     // Pass alone is fine
@@ -336,6 +979,46 @@ fn test_unused_argument_typed() {
     ));
 }
 
+#[test]
+fn test_unused_argument_member_access() {
+    let rule = "unused-argument";
+    // Member names after a dot are not uses of the argument
+    assert!(has_rule_violation("func f(name):\n    obj.name = 1", rule));
+    assert!(has_rule_violation("func f(name):\n    obj.name()", rule));
+    assert!(has_rule_violation(
+        "func f(name):\n    obj.name[0] = 1",
+        rule
+    ));
+    // ...but the receiver and call arguments are
+    assert!(!has_rule_violation("func f(obj):\n    obj.name = 1", rule));
+    assert!(!has_rule_violation(
+        "func f(name):\n    obj.foo(name)",
+        rule
+    ));
+}
+
+#[test]
+fn test_unused_argument_shadowed_by_local() {
+    let rule = "unused-argument";
+    assert!(has_rule_violation(
+        "func f(item):\n    for item in list:\n        print(item)",
+        rule
+    ));
+    assert!(has_rule_violation(
+        "func f(value):\n    var value = 1\n    print(value)",
+        rule
+    ));
+    // The iterable and the initializer still read the argument
+    assert!(!has_rule_violation(
+        "func f(items):\n    for items in items:\n        pass",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "func f(value):\n    var value = value + 1\n    print(value)",
+        rule
+    ));
+}
+
 #[test]
 fn test_comparison_with_itself() {
     assert!(!has_rule_violation(
@@ -352,6 +1035,70 @@ fn test_comparison_with_itself() {
     ));
 }
 
+#[test]
+fn test_no_implicit_int_float_division() {
+    let rule_id = "no-implicit-int-float-division";
+    let diagnostics = run_linter(
+        "func f():\n    var half = 5 / 2",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostic = diagnostics.iter().find(|d| d.rule_id == rule_id).unwrap();
+    assert_eq!((diagnostic.line, diagnostic.column), (2, 16));
+    assert_eq!(diagnostic.severity, Severity::Info);
+    assert_eq!(diagnostic.suggestion.as_deref(), Some("5.0 / 2"));
+
+    for source in [
+        "func f():\n    var half = 5.0 / 2",
+        "func f():\n    var half = 5 / 2.0",
+        "func f(n):\n    var half = n / 2",
+        "func f():\n    var product = 5 * 2",
+    ] {
+        assert!(!has_rule_violation(source, rule_id), "{}", source);
+    }
+}
+
+#[test]
+fn test_comparison_with_itself_not_equal() {
+    let rule = "comparison-with-itself";
+    let messages = |source: &str| -> Vec<String> {
+        lint_code(source)
+            .into_iter()
+            .filter(|(id, _)| id == rule)
+            .map(|(_, message)| message)
+            .collect()
+    };
+
+    let found = messages("if x != x:\n    pass");
+    assert_eq!(found.len(), 1);
+    assert!(found[0].contains("is_nan(x)"));
+
+    // NaN checks on floats are allowed
+    assert!(messages("var x: float = 0.0\nfunc f():\n    if x != x:\n        pass").is_empty());
+    assert!(messages("func f(x: float):\n    if x != x:\n        pass").is_empty());
+    assert!(messages("func f():\n    var x := 1.0\n    if x != x:\n        pass").is_empty());
+    // ...but equality with itself is still flagged
+    assert_eq!(
+        messages("func f(x: float):\n    if x == x:\n        pass").len(),
+        1
+    );
+    assert!(messages("if a < b:\n    pass").is_empty());
+
+    let config: Config =
+        toml::from_str("[rules.comparison-with-itself]\nallow_ne_self = true\n").unwrap();
+    let mut rule_impl = gdtools::rules::basic::ComparisonWithItselfRule::default();
+    rule_impl
+        .configure(config.get_rule_config(rule).unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule_impl)];
+    let source = "if x != x:\n    pass\nif y == y:\n    pass";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 3);
+}
+
 #[test]
 fn test_duplicated_load() {
     let no_dup = r#"
@@ -388,87 +1135,1236 @@ fn test_expression_not_assigned() {
     ));
 }
 
-// ============================================================================
-// Design Rules Tests
-// ============================================================================
-
 #[test]
-fn test_max_function_args() {
-    let few_args = "func f(a, b, c):\n    pass";
-    assert!(!has_rule_violation(few_args, "max-function-args"));
+fn test_no_dead_store() {
+    let bad = "func f():\n    var x = 1\n    x = 2\n    print(x)";
+    let diagnostics = run_linter(
+        bad,
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == "no-dead-store")
+        .map(|d| d.line)
+        .collect();
+    assert_eq!(lines, vec![2]);
+    assert!(has_rule_violation(
+        "func f():\n    var x = 1; x = 2\n    print(x)",
+        "no-dead-store"
+    ));
+    assert!(has_rule_violation(
+        "func f():\n    var x\n    x = 1\n    x = 2\n    print(x)",
+        "no-dead-store"
+    ));
 
-    // Default is 10
-    let many_args = "func f(a, b, c, d, e, f, g, h, i, j, k):\n    pass";
-    assert!(has_rule_violation(many_args, "max-function-args"));
+    // Read in between, including by the new value
+    assert!(!has_rule_violation(
+        "func f():\n    var x = 1\n    print(x)\n    x = 2\n    print(x)",
+        "no-dead-store"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    var x = 1\n    x = x + 1\n    print(x)",
+        "no-dead-store"
+    ));
+    // Branches are not analysed
+    assert!(!has_rule_violation(
+        "func f(c):\n    var x = 1\n    if c:\n        x = 2\n    x = 3\n    print(x)",
+        "no-dead-store"
+    ));
+    // Members may have setters, so only locals are tracked
+    assert!(!has_rule_violation(
+        "var health = 0\n\nfunc f():\n    health = 1\n    health = 2",
+        "no-dead-store"
+    ));
 }
 
 #[test]
-fn test_max_returns() {
-    let few_returns = r#"
-func f(x):
-    if x > 0:
-        return 1
-    return 0
-"#;
-    assert!(!has_rule_violation(few_returns, "max-returns"));
+fn test_no_return_value_from_void_function() {
+    use gdtools::rules::basic::NoReturnValueFromVoidFunctionRule;
 
-    // Default is 6
-    let many_returns = r#"
-func f(x):
-    if x == 1: return 1
-    if x == 2: return 2
-    if x == 3: return 3
-    if x == 4: return 4
-    if x == 5: return 5
-    if x == 6: return 6
-    return 0
-"#;
-    assert!(has_rule_violation(many_returns, "max-returns"));
-}
+    let rule_id = "no-return-value-from-void-function";
+    let source =
+        "func f(c) -> void:\n    if c:\n        return 1\n    var g = func(): return 2\n    return";
+    let diagnostics = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let found: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule_id)
+        .map(|d| (d.line, d.severity))
+        .collect();
+    assert_eq!(found, vec![(3, Severity::Error)]);
 
-#[test]
-fn test_max_public_methods() {
-    let few_methods = r#"
-func a(): pass
-func b(): pass
-func c(): pass
-"#;
-    assert!(!has_rule_violation(few_methods, "max-public-methods"));
-}
+    let mixed = "func f(c):\n    if c:\n        return\n    return 1";
+    assert!(!has_rule_violation(mixed, rule_id));
 
-// ============================================================================
-// Style Rules Tests
-// ============================================================================
+    let config: Config =
+        toml::from_str("[rules.no-return-value-from-void-function]\ninconsistent_returns = true\n")
+            .unwrap();
+    let mut rule = NoReturnValueFromVoidFunctionRule::default();
+    rule.configure(config.get_rule_config(rule_id).unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let diagnostics = run_linter(mixed, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    let found: Vec<_> = diagnostics.iter().map(|d| (d.line, d.severity)).collect();
+    assert_eq!(found, vec![(3, Severity::Info)]);
+}
 
 #[test]
-fn test_no_elif_return() {
-    // No elif after return - good
-    let good = r#"
-func f(x):
-    if x > 0:
-        return 1
-    if x < 0:
-        return -1
-    return 0
-"#;
-    assert!(!has_rule_violation(good, "no-elif-return"));
+fn test_no_static_func_accessing_instance_member() {
+    let rule_id = "no-static-func-accessing-instance-member";
+    let source = "static func create():\n    var copy = self.duplicate()\n    var f = func(): return self\n    return copy";
+    let diagnostics = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let positions: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule_id)
+        .map(|d| (d.line, d.column, d.severity))
+        .collect();
+    assert_eq!(
+        positions,
+        vec![(2, 16, Severity::Error), (3, 28, Severity::Error)]
+    );
 
-    // elif after return - bad
-    let bad = r#"
-func f(x):
-    if x > 0:
-        return 1
-    elif x < 0:
-        return -1
-    return 0
-"#;
-    assert!(has_rule_violation(bad, "no-elif-return"));
+    assert!(!has_rule_violation(
+        "func copy():\n    return self.duplicate()",
+        rule_id
+    ));
+    assert!(!has_rule_violation(
+        "static func create(from):\n    return from.duplicate()",
+        rule_id
+    ));
 }
 
 #[test]
-fn test_no_else_return() {
-    // No unnecessary else
-    let good = r#"
+fn test_no_yield_in_godot4() {
+    assert!(has_rule_violation(
+        "func f():\n    yield(get_tree(), \"idle_frame\")",
+        "no-yield-in-godot4"
+    ));
+    assert!(has_rule_violation(
+        "func f():\n    var x = yield(timer, \"timeout\")",
+        "no-yield-in-godot4"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    await get_tree().process_frame",
+        "no-yield-in-godot4"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    var yield_count = 1",
+        "no-yield-in-godot4"
+    ));
+}
+
+#[test]
+fn test_no_onready_without_at() {
+    let rule = "no-onready-without-at";
+
+    let diagnostics = run_linter(
+        "extends Node\n\nonready var sprite = $Sprite2D\nexport(int) var speed = 10",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostics: Vec<_> = diagnostics.iter().filter(|d| d.rule_id == rule).collect();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].suggestion.as_deref(), Some("@onready"));
+    assert_eq!(diagnostics[1].suggestion.as_deref(), Some("@export"));
+
+    assert!(has_rule_violation("export var speed = 10", rule));
+    assert!(!has_rule_violation("@onready var sprite = $Sprite2D", rule));
+    assert!(!has_rule_violation("@export var speed = 10", rule));
+    assert!(!has_rule_violation(
+        "# onready var sprite = $Sprite2D",
+        rule
+    ));
+    assert!(!has_rule_violation("var onready_done = false", rule));
+}
+
+#[test]
+fn test_no_redundant_await() {
+    let count = |source: &str| {
+        lint_code(source)
+            .iter()
+            .filter(|(id, _)| id == "no-redundant-await")
+            .count()
+    };
+    assert_eq!(count("func f():\n    var x = await await get_result()"), 1);
+    assert_eq!(count("func f():\n    await await await get_result()"), 1);
+    assert_eq!(count("func f():\n    var x = await get_result()"), 0);
+}
+
+#[test]
+fn test_no_assert_with_side_effects() {
+    assert!(has_rule_violation(
+        "func f():\n    assert(load_level())",
+        "no-assert-with-side-effects"
+    ));
+    assert!(has_rule_violation(
+        "func f():\n    assert(inventory.remove(item))",
+        "no-assert-with-side-effects"
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    assert(x > 0 and x != 3)",
+        "no-assert-with-side-effects"
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    assert(x, \"x must be set\")",
+        "no-assert-with-side-effects"
+    ));
+}
+
+#[test]
+fn test_no_empty_match_arm() {
+    let rule = "no-empty-match-arm";
+    let source =
+        "func f(x):\n    match x:\n        1:\n            pass\n        _:\n            pass";
+    let diagnostics: Vec<_> = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap()
+    .into_iter()
+    .filter(|d| d.rule_id == rule)
+    .collect();
+    // Only the numeric arm: `_: pass` is allowed by default
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (3, 9));
+
+    assert!(has_rule_violation(
+        "func f(x):\n    match x:\n        1, 2: pass",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    match x:\n        1:\n            pass # handled by the parent",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    match x:\n        1:\n            print(x)",
+        rule
+    ));
+}
+
+#[test]
+fn test_no_unreachable_match_arm() {
+    let messages = |source: &str| -> Vec<String> {
+        lint_code(source)
+            .into_iter()
+            .filter(|(id, _)| id == "no-unreachable-match-arm")
+            .map(|(_, message)| message)
+            .collect()
+    };
+
+    let source = "func f(x):\n    match x:\n        1:\n            a()\n        \"a\", 1:\n            b()\n        _:\n            c()\n        2:\n            d()";
+    assert_eq!(
+        messages(source),
+        vec![
+            "match pattern 1 is unreachable, it is already handled on line 3",
+            "match arm \"2\" is unreachable after the \"_\" arm on line 7",
+        ]
+    );
+
+    // Guarded arms may fall through to later arms
+    let guarded = "func f(x, y):\n    match x:\n        1 when y:\n            a()\n        1:\n            b()\n        _ when y:\n            c()\n        2:\n            d()";
+    assert!(messages(guarded).is_empty());
+
+    assert!(messages(
+        "func f(x):\n    match x:\n        1:\n            a()\n        _:\n            b()"
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_no_empty_match_arm_wildcard() {
+    use gdtools::rules::basic::NoEmptyMatchArmRule;
+
+    let config: Config =
+        toml::from_str("[rules.no-empty-match-arm]\nallow_wildcard_pass = false\n").unwrap();
+    let mut rule = NoEmptyMatchArmRule::default();
+    rule.configure(config.get_rule_config("no-empty-match-arm").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source =
+        "func f(x):\n    match x:\n        1:\n            print(x)\n        _:\n            pass";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 5);
+
+    let config: Config =
+        toml::from_str("[rules.no-empty-match-arm]\nallow_wildcard_pass = \"no\"\n").unwrap();
+    let err = NoEmptyMatchArmRule::default()
+        .configure(config.get_rule_config("no-empty-match-arm").unwrap())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "no-empty-match-arm: 'allow_wildcard_pass' must be a boolean"
+    );
+}
+
+#[test]
+fn test_no_duplicate_enum_value() {
+    let rule = "no-duplicate-enum-value";
+
+    let diagnostics = run_linter(
+        "enum State { IDLE = 1, RUNNING = 0, JUMPING }",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostics: Vec<_> = diagnostics.iter().filter(|d| d.rule_id == rule).collect();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "Enum member \"JUMPING\" has the same value (1) as \"IDLE\""
+    );
+
+    assert!(has_rule_violation("enum { A = 0x10, B = 16 }", rule));
+    assert!(has_rule_violation("enum E { A = -1, B = -1 }", rule));
+    assert!(!has_rule_violation("enum E { A, B, C }", rule));
+    assert!(!has_rule_violation("enum E { A = 1, B = 2, C = 4 }", rule));
+    // Values after a non-literal are unknown
+    assert!(!has_rule_violation("enum E { A = 1, B = A - 1, C }", rule));
+}
+
+#[test]
+fn test_await_on_literal() {
+    assert!(has_rule_violation(
+        "func f():\n    await 0.5",
+        "await-on-literal"
+    ));
+    assert!(has_rule_violation(
+        "func f():\n    await \"timeout\"",
+        "await-on-literal"
+    ));
+    assert!(has_rule_violation(
+        "func f():\n    var x = await (1)",
+        "await-on-literal"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    await get_tree().create_timer(0.5).timeout",
+        "await-on-literal"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    await load_level()",
+        "await-on-literal"
+    ));
+}
+
+#[test]
+fn test_no_global_load_at_parse_time() {
+    let diagnostics = run_linter(
+        "var scene = load(\"res://scene.tscn\")",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostic = diagnostics
+        .iter()
+        .find(|d| d.rule_id == "no-global-load-at-parse-time")
+        .unwrap();
+    assert_eq!(
+        diagnostic.message,
+        "Class variable \"scene\" loads a resource at parse time, use const with preload, or @onready"
+    );
+    assert!(!has_rule_violation(
+        "@onready var scene = load(\"res://scene.tscn\")",
+        "no-global-load-at-parse-time"
+    ));
+    assert!(!has_rule_violation(
+        "@onready\nvar scene = load(\"res://scene.tscn\")",
+        "no-global-load-at-parse-time"
+    ));
+    assert!(!has_rule_violation(
+        "const Scene = preload(\"res://scene.tscn\")",
+        "no-global-load-at-parse-time"
+    ));
+    // Preload initializers are reported by no-preload-variable-should-be-const instead
+    for source in [
+        "var Scene = preload(\"res://scene.tscn\")",
+        "var scene = preload(\"res://scene.tscn\").instantiate()",
+    ] {
+        assert!(
+            !has_rule_violation(source, "no-global-load-at-parse-time"),
+            "{}",
+            source
+        );
+    }
+    assert!(has_rule_violation(
+        "var scenes = [preload(\"res://a.tscn\"), load(\"res://b.tscn\")]",
+        "no-global-load-at-parse-time"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    var scene = load(\"res://scene.tscn\")",
+        "no-global-load-at-parse-time"
+    ));
+}
+
+#[test]
+fn test_no_preload_variable_should_be_const() {
+    let rule_id = "no-preload-variable-should-be-const";
+    let diagnostics = run_linter(
+        "var Bullet = preload(\"res://bullet.tscn\")",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let diagnostic = diagnostics.iter().find(|d| d.rule_id == rule_id).unwrap();
+    assert_eq!((diagnostic.line, diagnostic.column), (1, 5));
+    assert_eq!(diagnostic.severity, Severity::Info);
+    assert_eq!(
+        diagnostic.suggestion.as_deref(),
+        Some("const Bullet = preload(\"res://bullet.tscn\")")
+    );
+
+    // const-could-be-used reports the same words, so dedupe merges them into one
+    // diagnostic that keeps its fix; no other class-variable rule reports it
+    let config: Config = toml::from_str("dedupe_diagnostics = true\n").unwrap();
+    let source = "var Bullet = preload(\"res://bullet.tscn\")\n";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &all_rules(), &config).unwrap();
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Class variable \"Bullet\" holds a preload, use const (also reported by "));
+    assert_eq!(
+        apply_fixes(source, &diagnostics),
+        "const Bullet = preload(\"res://bullet.tscn\")\n"
+    );
+
+    for source in [
+        "const Bullet = preload(\"res://bullet.tscn\")",
+        "var bullet = load(\"res://bullet.tscn\")",
+        "@export var bullet = preload(\"res://bullet.tscn\")",
+        "@onready var bullet = preload(\"res://bullet.tscn\")",
+        "var bullet = preload(\"res://bullet.tscn\").instantiate()",
+        "var bullet = preload(PATH)",
+        "func f():\n    var Bullet = preload(\"res://bullet.tscn\")",
+    ] {
+        assert!(!has_rule_violation(source, rule_id), "{}", source);
+    }
+}
+
+#[test]
+fn test_no_space_in_node_names() {
+    assert!(has_rule_violation(
+        "func f():\n    var n = $My Node",
+        "no-space-in-node-names"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    var n = $\"My Node\"",
+        "no-space-in-node-names"
+    ));
+    assert!(!has_rule_violation(
+        "func f():\n    var n = $Path/To/Node",
+        "no-space-in-node-names"
+    ));
+}
+
+#[test]
+fn test_deprecated_api() {
+    let messages = |source: &str| -> Vec<String> {
+        lint_code(source)
+            .into_iter()
+            .filter(|(id, _)| id == "deprecated-api")
+            .map(|(_, message)| message)
+            .collect()
+    };
+
+    let found = messages("func f():\n    var x = scene.instance()");
+    assert_eq!(found.len(), 1);
+    assert!(found[0].contains("use \"instantiate\""));
+
+    let found = messages("func f():\n    var t = OS.get_ticks_msec()");
+    assert_eq!(found.len(), 1);
+    assert!(found[0].contains("\"OS.get_ticks_msec\" is deprecated, use \"Time.get_ticks_msec\""));
+
+    assert_eq!(messages("func f():\n    var x = rand_range(0, 1)").len(), 1);
+    assert!(messages("func f():\n    var t = Time.get_ticks_msec()").is_empty());
+    assert!(messages("func f():\n    var x = scene.instantiate()").is_empty());
+    // Dotted keys only match through that receiver
+    assert!(messages("func f():\n    var t = get_ticks_msec()").is_empty());
+}
+
+#[test]
+fn test_deprecated_api_configure() {
+    use gdtools::rules::basic::DeprecatedApiRule;
+
+    let config: Config = toml::from_str(
+        "[rules.deprecated-api.renames]\nold_helper = \"new_helper\"\ninstance = \"\"\n",
+    )
+    .unwrap();
+    let mut rule = DeprecatedApiRule::default();
+    rule.configure(config.get_rule_config("deprecated-api").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "func f():\n    old_helper()\n    scene.instance()\n    stepify(1.0, 0.5)";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    let lines: Vec<_> = diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![2, 4]);
+
+    let bad: Config = toml::from_str("[rules.deprecated-api]\nrenames = 3\n").unwrap();
+    assert!(DeprecatedApiRule::default()
+        .configure(bad.get_rule_config("deprecated-api").unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_string_should_be_stringname() {
+    let violations: Vec<_> = lint_code(
+        "func f():\n    connect(\"pressed\", g)\n    Input.is_action_pressed(\"jump\")\n    call(&\"x\")\n    print(\"hi\")",
+    )
+    .into_iter()
+    .filter(|(id, _)| id == "string-should-be-stringname")
+    .collect();
+    assert_eq!(violations.len(), 2);
+    assert!(violations[0].1.contains("&\"pressed\""));
+
+    // Off unless the config mentions it
+    let rule = gdtools::rules::basic::StringShouldBeStringNameRule::default();
+    assert!(!Config::default().is_rule_enabled_or(rule.meta().id, rule.enabled_by_default()));
+    let config: Config = toml::from_str("[rules.string-should-be-stringname]\n").unwrap();
+    assert!(config.is_rule_enabled_or(rule.meta().id, rule.enabled_by_default()));
+}
+
+#[test]
+fn test_string_should_be_stringname_configure() {
+    use gdtools::rules::basic::StringShouldBeStringNameRule;
+
+    let config: Config =
+        toml::from_str("[rules.string-should-be-stringname]\napis = [\"play\"]\n").unwrap();
+    let mut rule = StringShouldBeStringNameRule::default();
+    rule.configure(
+        config
+            .get_rule_config("string-should-be-stringname")
+            .unwrap(),
+    )
+    .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "func f():\n    connect(\"pressed\", g)\n    $Anim.play(\"idle\")";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    let lines: Vec<_> = diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![3]);
+}
+
+#[test]
+fn test_no_todo_without_owner() {
+    use gdtools::rules::basic::NoTodoWithoutOwnerRule;
+
+    let source = r#"# TODO: handle the empty inventory
+# TODO(alice): cache this
+var label = "TODO: not a comment"
+func f():
+    pass  # FIXME remove once the server is fixed
+# NOTE: fine
+# HACK(bob) ok
+# see TODO #42
+"#;
+    let lint = |rule: NoTodoWithoutOwnerRule| -> Vec<(usize, usize)> {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &rules,
+            &Config::default(),
+        )
+        .unwrap()
+        .iter()
+        .map(|d| (d.line, d.column))
+        .collect()
+    };
+    assert_eq!(
+        lint(NoTodoWithoutOwnerRule::default()),
+        vec![(1, 3), (5, 13), (8, 7)]
+    );
+
+    // Ticket references instead of owners, and only TODO counts
+    let config: Config = toml::from_str(
+        "[rules.no-todo-without-owner]\nkeywords = [\"TODO\"]\npattern = '^TODO #\\d+'\n",
+    )
+    .unwrap();
+    let mut rule = NoTodoWithoutOwnerRule::default();
+    rule.configure(config.get_rule_config("no-todo-without-owner").unwrap())
+        .unwrap();
+    assert_eq!(lint(rule), vec![(1, 3), (2, 3)]);
+
+    let bad: Config = toml::from_str("[rules.no-todo-without-owner]\npattern = '('\n").unwrap();
+    assert!(NoTodoWithoutOwnerRule::default()
+        .configure(bad.get_rule_config("no-todo-without-owner").unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_no_connect_with_string_method_name() {
+    let source = r#"func f(signal_name):
+    button.connect("pressed", _on_pressed)
+    connect("died", _on_died)
+    $Timer.connect("timeout", self, "_on_timeout")
+    button.pressed.connect(_on_pressed)
+    connect(signal_name, _on_died)
+"#;
+    let diagnostics = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let suggestions: Vec<_> = diagnostics
+        .into_iter()
+        .filter(|d| d.rule_id == "no-connect-with-string-method-name")
+        .map(|d| (d.line, d.suggestion.unwrap_or_default()))
+        .collect();
+    assert_eq!(
+        suggestions,
+        vec![
+            (2, "button.pressed.connect(_on_pressed)".to_string()),
+            (3, "died.connect(_on_died)".to_string()),
+            (4, "$Timer.timeout.connect(_on_timeout)".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_signal_connected_but_not_declared() {
+    use gdtools::rules::basic::SignalConnectedButNotDeclaredRule;
+
+    let rule_id = "signal-connected-but-not-declared";
+    let source = r#"signal hit
+var health_changed_signal
+
+func f(button):
+    hit.emit()
+    hti.emit()
+    self.died.connect(_on_died)
+    emit_signal("hti")
+    pressed.connect(_on_pressed)
+    health_changed_signal.emit()
+    button.connect("pressed", self, "_on_pressed")
+    Events.score_changed.emit()
+    leveled_up.emit()
+"#;
+    let lint = |rule: SignalConnectedButNotDeclaredRule| -> Vec<(usize, usize, String)> {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &rules,
+            &Config::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|d| (d.line, d.column, d.message))
+        .collect()
+    };
+    let found = lint(SignalConnectedButNotDeclaredRule::default());
+    assert_eq!(
+        found,
+        vec![
+            (
+                6,
+                5,
+                "Signal \"hti\" is not declared in this file".to_string()
+            ),
+            (
+                7,
+                10,
+                "Signal \"died\" is not declared in this file".to_string()
+            ),
+            (
+                8,
+                17,
+                "Signal \"hti\" is not declared in this file".to_string()
+            ),
+            (
+                13,
+                5,
+                "Signal \"leveled_up\" is not declared in this file".to_string()
+            ),
+        ]
+    );
+
+    let config: Config =
+        toml::from_str("[rules.signal-connected-but-not-declared]\nallowed = [\"leveled_up\"]\n")
+            .unwrap();
+    let mut rule = SignalConnectedButNotDeclaredRule::default();
+    rule.configure(config.get_rule_config(rule_id).unwrap())
+        .unwrap();
+    assert_eq!(lint(rule).len(), 3);
+
+    let rule = SignalConnectedButNotDeclaredRule::default();
+    assert_eq!(rule.meta().default_severity, Severity::Info);
+    assert!(Config::default().is_rule_enabled_or(rule_id, rule.enabled_by_default()));
+}
+
+#[test]
+fn test_no_empty_signal_name_in_emit() {
+    let rule_id = "no-empty-signal-name-in-emit";
+    let diagnostics = run_linter(
+        "func f():\n    emit_signal(\"\")\n    self.emit_signal('', 1)",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let found: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule_id)
+        .map(|d| (d.line, d.column, d.severity))
+        .collect();
+    assert_eq!(
+        found,
+        vec![(2, 5, Severity::Warning), (3, 10, Severity::Warning)]
+    );
+
+    for source in [
+        "func f():\n    emit_signal(\"died\")",
+        "func f(name):\n    emit_signal(name)",
+        "func f():\n    print(\"\")",
+    ] {
+        assert!(!has_rule_violation(source, rule_id), "{}", source);
+    }
+}
+
+#[test]
+fn test_no_string_node_path_for_get_node() {
+    use gdtools::rules::basic::NoStringNodePathRule;
+
+    let source = r#"func f(path, other):
+    var a = get_node("Body/Sprite2D")
+    var b = self.get_node("../Hud")
+    var c = get_node("%Health")
+    var d = get_node(path)
+    var e = other.get_node("Body")
+    var g = get_node("Hud")
+"#;
+    let suggestions = |rule: NoStringNodePathRule| -> Vec<(usize, String)> {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &rules,
+            &Config::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|d| (d.line, d.suggestion.unwrap_or_default()))
+        .collect()
+    };
+
+    let expected = |child: &str| {
+        vec![
+            (2, "$Body/Sprite2D".to_string()),
+            (3, "$\"../Hud\"".to_string()),
+            (4, "%Health".to_string()),
+            (7, child.to_string()),
+        ]
+    };
+    assert_eq!(
+        suggestions(NoStringNodePathRule::default()),
+        expected("$Hud")
+    );
+
+    let config: Config =
+        toml::from_str("[rules.no-string-node-path-for-get-node]\nprefer = \"unique\"\n").unwrap();
+    let mut rule = NoStringNodePathRule::default();
+    rule.configure(
+        config
+            .get_rule_config("no-string-node-path-for-get-node")
+            .unwrap(),
+    )
+    .unwrap();
+    // Only a plain child name becomes a unique name
+    assert_eq!(suggestions(rule), expected("%Hud"));
+
+    let bad: Config =
+        toml::from_str("[rules.no-string-node-path-for-get-node]\nprefer = \"path\"\n").unwrap();
+    assert!(NoStringNodePathRule::default()
+        .configure(
+            bad.get_rule_config("no-string-node-path-for-get-node")
+                .unwrap()
+        )
+        .is_err());
+}
+
+#[test]
+fn test_no_print_without_context() {
+    use gdtools::rules::basic::NoPrintWithoutContextRule;
+
+    let source = r#"func f():
+    print("a")
+    print_debug("b")
+    push_warning("c")
+    printerr("d")
+    if OS.is_debug_build():
+        print("e")
+    else:
+        print("f")
+"#;
+    let lines = |rule: NoPrintWithoutContextRule| -> Vec<usize> {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &rules,
+            &Config::default(),
+        )
+        .unwrap()
+        .iter()
+        .map(|d| d.line)
+        .collect()
+    };
+    assert_eq!(lines(NoPrintWithoutContextRule::default()), vec![2, 5, 9]);
+
+    // The allowlist wins over the banned list, and push_* can't be banned
+    let config: Config = toml::from_str(
+        "[rules.no-print-without-context]\n\
+         banned = [\"print\", \"printerr\", \"push_warning\"]\n\
+         allowed = [\"printerr\"]\n",
+    )
+    .unwrap();
+    let mut rule = NoPrintWithoutContextRule::default();
+    rule.configure(config.get_rule_config("no-print-without-context").unwrap())
+        .unwrap();
+    assert_eq!(lines(rule), vec![2, 9]);
+
+    let rule = NoPrintWithoutContextRule::default();
+    assert!(!Config::default().is_rule_enabled_or(rule.meta().id, rule.enabled_by_default()));
+}
+
+// ============================================================================
+// Design Rules Tests
+// ============================================================================
+
+#[test]
+fn test_max_function_args() {
+    let few_args = "func f(a, b, c):\n    pass";
+    assert!(!has_rule_violation(few_args, "max-function-args"));
+
+    // Default is 10
+    let many_args = "func f(a, b, c, d, e, f, g, h, i, j, k):\n    pass";
+    assert!(has_rule_violation(many_args, "max-function-args"));
+}
+
+#[test]
+fn test_max_returns() {
+    let few_returns = r#"
+func f(x):
+    if x > 0:
+        return 1
+    return 0
+"#;
+    assert!(!has_rule_violation(few_returns, "max-returns"));
+
+    // Default is 6
+    let many_returns = r#"
+func f(x):
+    if x == 1: return 1
+    if x == 2: return 2
+    if x == 3: return 3
+    if x == 4: return 4
+    if x == 5: return 5
+    if x == 6: return 6
+    return 0
+"#;
+    assert!(has_rule_violation(many_returns, "max-returns"));
+}
+
+#[test]
+fn test_max_public_methods() {
+    let few_methods = r#"
+func a(): pass
+func b(): pass
+func c(): pass
+"#;
+    assert!(!has_rule_violation(few_methods, "max-public-methods"));
+}
+
+#[test]
+fn test_maximum_class_members() {
+    use gdtools::rules::design::MaximumClassMembersRule;
+
+    let config: Config = toml::from_str("[rules.maximum-class-members]\nmax = 3\n").unwrap();
+    let mut rule = MaximumClassMembersRule::default();
+    rule.configure(config.get_rule_config("maximum-class-members").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let lint =
+        |source: &str| run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+
+    let diagnostics = lint("signal died\nconst A = 1\nvar b = 2\nfunc c():\n    pass");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "Class \"<module>\" has 4 members (max 3)"
+    );
+
+    // The inner class counts once for the module and is checked separately
+    let diagnostics =
+        lint("var a = 1\nclass Inner:\n    var b = 2\n    var c = 3\n    var d = 4\n    var e = 5");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "Class \"Inner\" has 4 members (max 3)"
+    );
+
+    assert!(lint("var a = 1\nvar b = 2\nvar c = 3").is_empty());
+
+    for value in ["0", "-1", "\"10\"", "2.5"] {
+        let config: Config =
+            toml::from_str(&format!("[rules.maximum-class-members]\nmax = {}\n", value)).unwrap();
+        let err = MaximumClassMembersRule::default()
+            .configure(config.get_rule_config("maximum-class-members").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            err, "maximum-class-members: 'max' must be an integer of at least 1",
+            "{}",
+            value
+        );
+    }
+}
+
+#[test]
+fn test_return_type_annotation_required() {
+    assert!(has_rule_violation(
+        "func get_speed():\n    return 1",
+        "return-type-annotation-required"
+    ));
+    assert!(!has_rule_violation(
+        "func get_speed() -> int:\n    return 1",
+        "return-type-annotation-required"
+    ));
+    // Virtual methods and private functions are exempt by default
+    assert!(!has_rule_violation(
+        "func _ready():\n    pass",
+        "return-type-annotation-required"
+    ));
+    assert!(!has_rule_violation(
+        "func _helper():\n    pass",
+        "return-type-annotation-required"
+    ));
+}
+
+#[test]
+fn test_typed_parameters_required() {
+    let violations: Vec<_> = lint_code("func f(a: int, b):\n    pass")
+        .into_iter()
+        .filter(|(id, _)| id == "typed-parameters-required")
+        .collect();
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].1.contains("\"b\""));
+
+    assert!(has_rule_violation(
+        "func f(a = 1):\n    pass",
+        "typed-parameters-required"
+    ));
+    assert!(!has_rule_violation(
+        "func f(a: int = 1, _unused):\n    pass",
+        "typed-parameters-required"
+    ));
+}
+
+#[test]
+fn test_typing_rule_options_are_validated() {
+    use gdtools::rules::design::{ReturnTypeAnnotationRequiredRule, TypedParametersRequiredRule};
+
+    let configure = |mut rule: Box<dyn Rule>, toml_source: &str| {
+        let config: Config = toml::from_str(toml_source).unwrap();
+        let id = rule.meta().id;
+        rule.configure(config.get_rule_config(id).unwrap())
+    };
+
+    assert!(configure(
+        Box::new(ReturnTypeAnnotationRequiredRule::default()),
+        "[rules.return-type-annotation-required]\nrequire_on = \"all\"\n"
+    )
+    .is_ok());
+    assert_eq!(
+        configure(
+            Box::new(ReturnTypeAnnotationRequiredRule::default()),
+            "[rules.return-type-annotation-required]\nrequire_on = \"private\"\n"
+        ),
+        Err(
+            "return-type-annotation-required: 'require_on' must be \"public\" or \"all\""
+                .to_string()
+        )
+    );
+    assert_eq!(
+        configure(
+            Box::new(TypedParametersRequiredRule::default()),
+            "[rules.typed-parameters-required]\nrequire_on = true\n"
+        ),
+        Err("typed-parameters-required: 'require_on' must be \"public\" or \"all\"".to_string())
+    );
+    assert_eq!(
+        configure(
+            Box::new(TypedParametersRequiredRule::default()),
+            "[rules.typed-parameters-required]\nignore_underscore = \"yes\"\n"
+        ),
+        Err("typed-parameters-required: 'ignore_underscore' must be a boolean".to_string())
+    );
+}
+
+#[test]
+fn test_no_untyped_signal_parameters() {
+    let diagnostics = run_linter(
+        "signal foo(a, b: int)\nsignal bar\nsignal baz(c: String)",
+        &PathBuf::from("test.gd"),
+        &all_rules(),
+        &Config::default(),
+    )
+    .unwrap();
+    let found: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == "no-untyped-signal-parameters")
+        .collect();
+    assert_eq!(found.len(), 1);
+    assert_eq!((found[0].line, found[0].column), (1, 12));
+    assert_eq!(found[0].severity, Severity::Info);
+    assert!(found[0].message.contains("\"a\""));
+}
+
+#[test]
+fn test_no_empty_else() {
+    assert!(has_rule_violation(
+        "func f(x):\n    if x:\n        return\n    else:\n        pass",
+        "no-empty-else"
+    ));
+    assert!(has_rule_violation(
+        "func f(x):\n    if x:\n        return\n    elif x > 1:\n        pass",
+        "no-empty-else"
+    ));
+    // Empty then-branch guards are fine
+    assert!(!has_rule_violation(
+        "func f(x):\n    if x:\n        pass\n    else:\n        print(x)",
+        "no-empty-else"
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    if x:\n        return\n    else:\n        # nothing to do\n        pass",
+        "no-empty-else"
+    ));
+    assert!(!has_rule_violation(
+        "func f(x):\n    if x:\n        return\n    else: # nothing to do\n        pass",
+        "no-empty-else"
+    ));
+}
+
+#[test]
+fn test_no_empty_else_disallow_commented() {
+    use gdtools::rules::design::NoEmptyElseRule;
+
+    let config: Config =
+        toml::from_str("[rules.no-empty-else]\nallow_commented = false\n").unwrap();
+    let mut rule = NoEmptyElseRule::default();
+    rule.configure(config.get_rule_config("no-empty-else").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = "func f(x):\n    if x:\n        return\n    else:\n        pass # nothing to do";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 4);
+
+    let config: Config = toml::from_str("[rules.no-empty-else]\nallow_commented = 0\n").unwrap();
+    let err = NoEmptyElseRule::default()
+        .configure(config.get_rule_config("no-empty-else").unwrap())
+        .unwrap_err();
+    assert_eq!(err, "no-empty-else: 'allow_commented' must be a boolean");
+}
+
+#[test]
+fn test_disallowed_import() {
+    use gdtools::rules::design::DisallowedImportRule;
+
+    let config: Config = toml::from_str(
+        "[rules.disallowed-import]\n\
+         boundaries = [{ from_glob = \"ui/**\", forbidden_path_substring = \"gameplay/\" }]\n",
+    )
+    .unwrap();
+    let mut rule = DisallowedImportRule::default();
+    rule.configure(config.get_rule_config("disallowed-import").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+
+    let source = r#"extends "res://gameplay/base.gd"
+const Icon = preload("res://ui/icon.png")
+const Player = preload("res://gameplay/player.gd")
+func f():
+    var enemy = load("res://gameplay/enemy.tscn")
+"#;
+    let lines = |path: &str| -> Vec<usize> {
+        run_linter(source, &PathBuf::from(path), &rules, &config)
+            .unwrap()
+            .iter()
+            .map(|d| d.line)
+            .collect()
+    };
+    assert_eq!(lines("ui/hud.gd"), vec![1, 3, 5]);
+    assert_eq!(lines("/home/dev/project/ui/menu/main.gd"), vec![1, 3, 5]);
+    assert!(lines("gameplay/player.gd").is_empty());
+
+    // No boundaries configured: the rule is a no-op
+    assert!(!has_rule_violation(source, "disallowed-import"));
+
+    let bad: Config =
+        toml::from_str("[rules.disallowed-import]\nboundaries = [{ from_glob = \"ui/**\" }]\n")
+            .unwrap();
+    assert!(DisallowedImportRule::default()
+        .configure(bad.get_rule_config("disallowed-import").unwrap())
+        .is_err());
+}
+
+#[test]
+fn test_no_recursive_preload() {
+    use gdtools::rules::design::NoRecursivePreloadRule;
+
+    let config = Config::default();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(NoRecursivePreloadRule::default())];
+    let source = r#"const Self = preload("res://scenes/player.gd")
+const Enemy = preload("res://scenes/enemy.gd")
+func f():
+    var me = load("player.gd")
+    var other = load("../player.gd")
+"#;
+    let lines = |path: &str| -> Vec<usize> {
+        run_linter(source, &PathBuf::from(path), &rules, &config)
+            .unwrap()
+            .iter()
+            .map(|d| d.line)
+            .collect()
+    };
+    assert_eq!(lines("scenes/player.gd"), vec![1, 4]);
+    assert!(lines("scenes/enemy_spawner.gd").is_empty());
+
+    // With a project root, res:// paths resolve against it
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("scenes").join("player.gd");
+    let config: Config = toml::from_str(&format!(
+        "[rules.no-recursive-preload]\nproject_root = {:?}\n",
+        dir.path().to_string_lossy()
+    ))
+    .unwrap();
+    let mut rule = NoRecursivePreloadRule::default();
+    rule.configure(config.get_rule_config("no-recursive-preload").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let diagnostics = run_linter(source, &script, &rules, &config).unwrap();
+    assert_eq!(
+        diagnostics.iter().map(|d| d.line).collect::<Vec<_>>(),
+        vec![1, 4]
+    );
+    let other = dir.path().join("other").join("player.gd");
+    let diagnostics = run_linter(source, &other, &rules, &config).unwrap();
+    assert_eq!(
+        diagnostics.iter().map(|d| d.line).collect::<Vec<_>>(),
+        vec![4]
+    );
+}
+
+#[test]
+fn test_no_preload_in_function_body() {
+    let rule_id = "no-preload-in-function-body";
+    let source = "func spawn():\n    var Bullet = preload(\"res://bullet.tscn\")\n    add_child(preload(\"res://fx.tscn\").instantiate())";
+    // Runs with a default config
+    let rules: Vec<_> = all_rules()
+        .into_iter()
+        .filter(|r| r.enabled_by_default())
+        .collect();
+    let diagnostics = run_linter(
+        source,
+        &PathBuf::from("test.gd"),
+        &rules,
+        &Config::default(),
+    )
+    .unwrap();
+    let found: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule_id)
+        .map(|d| (d.line, d.column, d.suggestion.as_deref()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (2, 18, Some("const Bullet = preload(\"res://bullet.tscn\")")),
+            (3, 15, None),
+        ]
+    );
+    assert!(diagnostics
+        .iter()
+        .filter(|d| d.rule_id == rule_id)
+        .all(|d| d.severity == Severity::Info));
+
+    for source in [
+        "const Bullet = preload(\"res://bullet.tscn\")",
+        "var bullet = preload(\"res://bullet.tscn\")",
+        "func spawn():\n    var bullet = load(\"res://bullet.tscn\")",
+    ] {
+        assert!(!has_rule_violation(source, rule_id), "{}", source);
+    }
+
+    // Lambdas and `_init` are function bodies too, even in a class-level var
+    for source in [
+        "var make_bullet = func(): return preload(\"res://bullet.tscn\").instantiate()",
+        "func _init():\n    var Bullet = preload(\"res://bullet.tscn\")",
+    ] {
+        assert!(has_rule_violation(source, rule_id), "{}", source);
+    }
+}
+
+// ============================================================================
+// Style Rules Tests
+// ============================================================================
+
+#[test]
+fn test_no_elif_return() {
+    // No elif after return - good
+    let good = r#"
+func f(x):
+    if x > 0:
+        return 1
+    if x < 0:
+        return -1
+    return 0
+"#;
+    assert!(!has_rule_violation(good, "no-elif-return"));
+
+    // elif after return - bad
+    let bad = r#"
+func f(x):
+    if x > 0:
+        return 1
+    elif x < 0:
+        return -1
+    return 0
+"#;
+    assert!(has_rule_violation(bad, "no-elif-return"));
+}
+
+#[test]
+fn test_no_else_return() {
+    // No unnecessary else
+    let good = r#"
 func f(x):
     if x > 0:
         return 1
@@ -487,6 +2383,61 @@ func f(x):
     assert!(has_rule_violation(bad, "no-else-return"));
 }
 
+#[test]
+fn test_no_else_return_after_continue() {
+    let bad = r#"
+func f(items):
+    for item in items:
+        if item == null:
+            continue
+        else:
+            print(item)
+"#;
+    assert!(lint_code(bad).contains(&(
+        "no-else-return".to_string(),
+        "Unnecessary 'else' after 'continue'".to_string()
+    )));
+
+    // Mixed jumps are all named
+    let mixed = r#"
+func f(items):
+    for item in items:
+        if item == null:
+            continue
+        elif item < 0:
+            break
+        else:
+            print(item)
+"#;
+    assert!(lint_code(mixed).contains(&(
+        "no-else-return".to_string(),
+        "Unnecessary 'else' after 'continue'/'break'".to_string()
+    )));
+
+    // A lambda inside the loop is not a loop context
+    let lambda = r#"
+func f(items):
+    for item in items:
+        var cb = func():
+            if item:
+                continue
+            else:
+                print(item)
+"#;
+    assert!(!has_rule_violation(lambda, "no-else-return"));
+
+    // The branch may fall through without a jump
+    let good = r#"
+func f(items):
+    for item in items:
+        if item == null:
+            print("none")
+        else:
+            print(item)
+"#;
+    assert!(!has_rule_violation(good, "no-else-return"));
+}
+
 #[test]
 fn test_class_definitions_order() {
     // Good order
@@ -509,3 +2460,368 @@ signal my_signal
 "#;
     assert!(has_rule_violation(bad, "class-definitions-order"));
 }
+
+#[test]
+fn test_class_definitions_order_agrees_with_reorder() {
+    let source = r#"extends Node
+
+@onready var label = $Label
+var speed = 10
+
+
+func _ready():
+	pass
+
+
+func _init():
+	pass
+
+
+static func _static_init():
+	pass
+
+
+class Inner:
+	func helper():
+		pass
+
+	var count = 0
+"#;
+    let config = Config::default();
+    let rules = all_rules();
+    let path = PathBuf::from("test.gd");
+    let lint_lines = |source: &str| -> Vec<usize> {
+        run_linter(source, &path, &rules, &config)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.rule_id == "class-definitions-order")
+            .map(|d| d.line)
+            .collect()
+    };
+
+    let expected: Vec<usize> = check_order(source)
+        .unwrap()
+        .into_iter()
+        .map(|v| v.line)
+        .collect();
+    assert_eq!(expected, vec![4, 11, 15, 23]);
+    assert_eq!(lint_lines(source), expected);
+
+    // Once reordered, the lint rule has nothing left to report
+    let reordered = reorder_source(source).unwrap();
+    assert!(lint_lines(&reordered).is_empty());
+}
+
+#[test]
+fn test_class_definitions_order_additional_virtual_methods() {
+    let source = r#"extends Node
+
+
+func _private_helper():
+    pass
+
+
+func enter_state():
+    pass
+"#;
+    let rules = all_rules();
+    let path = PathBuf::from("test.gd");
+    let order_lines = |config: &Config| -> Vec<usize> {
+        run_linter(source, &path, &rules, config)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.rule_id == "class-definitions-order")
+            .map(|d| d.line)
+            .collect()
+    };
+
+    assert!(order_lines(&Config::default()).is_empty());
+
+    let config: Config =
+        toml::from_str("[format]\nadditional_virtual_methods = [\"enter_state\"]\n").unwrap();
+    assert_eq!(order_lines(&config), vec![8]);
+}
+
+#[test]
+fn test_no_comparison_chaining() {
+    let count = |source: &str| {
+        lint_code(source)
+            .iter()
+            .filter(|(id, _)| id == "no-comparison-chaining")
+            .count()
+    };
+
+    assert_eq!(
+        count("func f(a, b, c):\n    if a < b < c:\n        pass"),
+        1
+    );
+    assert_eq!(
+        count("func f(a, b, c, d):\n    if a == b != c < d:\n        pass"),
+        1
+    );
+    assert_eq!(
+        count("func f(a, b, c):\n    if a < b and b < c:\n        pass"),
+        0
+    );
+    assert_eq!(
+        count("func f(a, b, c):\n    if (a < b) == c:\n        pass"),
+        0
+    );
+    assert_eq!(count("func f(a, b):\n    if a + 1 < b:\n        pass"), 0);
+}
+
+#[test]
+fn test_no_multiple_statements_per_line() {
+    use gdtools::rules::style::NoMultipleStatementsPerLineRule;
+
+    let source = "func f(c):\n    var x = 1; var y = 2\n    x = 3;\n    if c: x = 4\n    for i in range(3): y += i\n    if c:\n        pass\n    else: pass\n";
+    let lines = |rule: NoMultipleStatementsPerLineRule, config: &Config| -> Vec<(usize, usize)> {
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+        run_linter(source, &PathBuf::from("test.gd"), &rules, config)
+            .unwrap()
+            .iter()
+            .map(|d| (d.line, d.column))
+            .collect()
+    };
+
+    // A trailing `;` with nothing after it is fine
+    assert_eq!(
+        lines(
+            NoMultipleStatementsPerLineRule::default(),
+            &Config::default()
+        ),
+        vec![(2, 16)]
+    );
+
+    let config: Config = toml::from_str(
+        "[rules.no-multiple-statements-per-line]\ncheck_single_line_bodies = true\n",
+    )
+    .unwrap();
+    let mut rule = NoMultipleStatementsPerLineRule::default();
+    rule.configure(
+        config
+            .get_rule_config("no-multiple-statements-per-line")
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        lines(rule, &config),
+        vec![(2, 16), (4, 11), (5, 24), (8, 11)]
+    );
+}
+
+#[test]
+fn test_no_comparison_to_bool_literal() {
+    let messages = |source: &str| -> Vec<String> {
+        lint_code(source)
+            .into_iter()
+            .filter(|(id, _)| id == "no-comparison-to-bool-literal")
+            .map(|(_, message)| message)
+            .collect()
+    };
+
+    let found = messages("if flag == true:\n    pass");
+    assert_eq!(found.len(), 1);
+    assert!(found[0].contains("use \"flag\""));
+
+    let found = messages("if flag == false:\n    pass");
+    assert!(found[0].contains("use \"not flag\""));
+
+    let found = messages("if true != flag:\n    pass");
+    assert!(found[0].contains("use \"not flag\""));
+
+    let found = messages("if false != flag:\n    pass");
+    assert!(found[0].contains("use \"flag\""));
+
+    assert!(messages("if flag:\n    pass").is_empty());
+    assert!(messages("if count == 1:\n    pass").is_empty());
+}
+
+#[test]
+fn test_const_could_be_used() {
+    let rule = "const-could-be-used";
+
+    assert!(has_rule_violation("var speed = 10", rule));
+    assert!(has_rule_violation(
+        "var scene = preload(\"res://a.tscn\")",
+        rule
+    ));
+    assert!(has_rule_violation("class Inner:\n    var limit = 3", rule));
+
+    // Reassigned anywhere in the file
+    assert!(!has_rule_violation(
+        "var speed = 10\nfunc f():\n    speed = 20",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "var speed = 10\nfunc f():\n    self.speed += 1",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "var data = {}\nfunc f():\n    data[\"a\"] = 1",
+        rule
+    ));
+
+    // Set by the editor or engine
+    assert!(!has_rule_violation("@export var speed = 10", rule));
+    assert!(!has_rule_violation(
+        "@export_range(0, 10) var speed = 10",
+        rule
+    ));
+    assert!(!has_rule_violation("@onready var speed = 10", rule));
+
+    // Not candidates
+    assert!(!has_rule_violation("var speed", rule));
+    assert!(!has_rule_violation("var timer = Timer.new()", rule));
+    // Containers are often only mutated through method calls, which const forbids
+    assert!(!has_rule_violation(
+        "var items = []\nfunc f():\n    items.append(1)",
+        rule
+    ));
+    assert!(!has_rule_violation(
+        "var seen = {}\nfunc f(k):\n    seen.erase(k)",
+        rule
+    ));
+    assert!(!has_rule_violation("var DIRS: Array[int] = [1, 2]", rule));
+    assert!(!has_rule_violation("static var count = 0", rule));
+    assert!(!has_rule_violation("func f():\n    var local = 1", rule));
+}
+
+#[test]
+fn test_const_could_be_used_fix() {
+    fn fix(source: &str) -> String {
+        let diagnostics = run_linter(
+            source,
+            &PathBuf::from("test.gd"),
+            &all_rules(),
+            &Config::default(),
+        )
+        .unwrap();
+        apply_fixes(source, &diagnostics)
+    }
+
+    assert_eq!(fix("var SPEED = 5\n"), "const SPEED = 5\n");
+    assert_eq!(fix("var SPEED: int = -5\n"), "const SPEED: int = -5\n");
+    assert_eq!(
+        fix("var SCENE = preload(\"res://a.tscn\")\n"),
+        "const SCENE = preload(\"res://a.tscn\")\n"
+    );
+    assert_eq!(
+        fix("class Inner:\n    var LIMIT = 3\n"),
+        "class Inner:\n    const LIMIT = 3\n"
+    );
+
+    // Reported but left as a suggestion: not a literal or preload
+    let source = "var ORIGIN = Vector2.ZERO\n";
+    assert!(has_rule_violation(source, "const-could-be-used"));
+    assert_eq!(fix(source), source);
+
+    // Reassigned vars are neither reported nor fixed
+    let source = "var SPEED = 5\nfunc f():\n    SPEED = 6\n";
+    assert_eq!(fix(source), source);
+
+    // With `fix_private_only`, public vars are left as suggestions
+    let config: Config =
+        toml::from_str("[rules.const-could-be-used]\nfix_private_only = true\n").unwrap();
+    let mut rule = gdtools::rules::style::ConstCouldBeUsedRule::default();
+    rule.configure(config.get_rule_config("const-could-be-used").unwrap())
+        .unwrap();
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(rule)];
+    let source = "var SPEED = 5\nvar _LIMIT = 3\n";
+    let diagnostics = run_linter(source, &PathBuf::from("test.gd"), &rules, &config).unwrap();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        apply_fixes(source, &diagnostics),
+        "var SPEED = 5\nconst _LIMIT = 3\n"
+    );
+}
+
+#[test]
+fn test_no_redundant_type_cast() {
+    let rule = "no-redundant-type-cast";
+    assert!(has_rule_violation("var a = 5 as int", rule));
+    assert!(has_rule_violation("var b = \"s\" as String", rule));
+    assert!(has_rule_violation("var c = true as bool", rule));
+    assert!(has_rule_violation("var d = 1.5 as float", rule));
+    assert!(has_rule_violation("var e = &\"name\" as StringName", rule));
+    assert!(has_rule_violation("var p = ^\"a/b\" as NodePath", rule));
+
+    // Conversions and non-literals are left alone
+    assert!(!has_rule_violation("var e = 5 as float", rule));
+    assert!(!has_rule_violation("var f = x as int", rule));
+    assert!(!has_rule_violation("var g = \"s\" as StringName", rule));
+    assert!(!has_rule_violation("var h = 5 + 1", rule));
+}
+
+// ============================================================================
+// Custom Rules Tests
+// ============================================================================
+
+fn lint_with_config(source: &str, config_toml: &str) -> Result<Vec<gdtools::Diagnostic>, String> {
+    let config: Config = toml::from_str(config_toml).unwrap();
+    let rules = regex_rules(&config)?;
+    let path = PathBuf::from("test.gd");
+    run_linter(source, &path, &rules, &config)
+}
+
+#[test]
+fn test_regex_rule_matches_lines() {
+    let config = r#"
+[regex_rules.no-print]
+pattern = 'print\('
+message = "Use the logger"
+severity = "error"
+"#;
+    let diagnostics = lint_with_config("func f():\n\tprint(1)\n\tprint(2)", config).unwrap();
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].rule_id, "no-print");
+    assert_eq!(diagnostics[0].message, "Use the logger");
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 2));
+}
+
+#[test]
+fn test_regex_rule_with_node_kind() {
+    let config = r#"
+[regex_rules.no-todo-strings]
+pattern = "TODO"
+message = "TODO in string"
+node_kind = "string"
+"#;
+    let diagnostics =
+        lint_with_config("# TODO: later\nvar a = \"x TODO\"\nvar b = \"ok\"", config).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 12));
+}
+
+#[test]
+fn test_regex_rule_nested_node_kind_reported_once() {
+    let config = r#"
+[regex_rules.no-get-node]
+pattern = 'get_node\('
+message = "Use $ instead"
+node_kind = "call"
+"#;
+    let diagnostics = lint_with_config("func f():\n\tprint(get_node(\"A\"))", config).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (2, 8));
+
+    // Sibling nodes still report separately
+    let diagnostics =
+        lint_with_config("func f():\n\tget_node(\"A\")\n\tget_node(\"B\")", config).unwrap();
+    assert_eq!(diagnostics.len(), 2);
+}
+
+#[test]
+fn test_regex_rule_invalid_pattern() {
+    let config = "[regex_rules.bad]\npattern = \"(\"\nmessage = \"x\"\n";
+    let err = lint_with_config("var a = 1", config).unwrap_err();
+    assert!(err.contains("Invalid pattern for regex rule 'bad'"));
+}
+
+#[test]
+fn test_regex_rule_cannot_shadow_builtin() {
+    let config = "[regex_rules.max-line-length]\npattern = \"x\"\nmessage = \"x\"\n";
+    assert!(lint_with_config("var a = 1", config).is_err());
+}